@@ -2,4 +2,4 @@
 
 mod token;
 
-pub use token::{Token, TokenManager};
+pub use token::{Token, TokenClaims, TokenManager};
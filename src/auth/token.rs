@@ -10,6 +10,8 @@ use tokio::sync::RwLock;
 
 use crate::config::Config;
 use crate::error::{ApiErrorResponse, Error, Result};
+use crate::http::{HttpBackend, ReqwestBackend};
+use crate::metrics::Counter;
 
 /// Authentication token from the Airwallex API.
 #[derive(Debug, Clone)]
@@ -34,6 +36,18 @@ impl Token {
         format!("Bearer {}", self.value.expose_secret())
     }
 
+    /// Decode the token's JWT claims.
+    ///
+    /// The signature is not verified; the claims are only used for client-side
+    /// sanity checks. Returns `None` if the token is not a decodable JWT.
+    pub fn claims(&self) -> Option<TokenClaims> {
+        use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+
+        let payload = self.value.expose_secret().split('.').nth(1)?;
+        let bytes = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
     /// Check if the token is expired or will expire within the given buffer.
     pub fn is_expired_with_buffer(&self, buffer: Duration) -> bool {
         let buffer_chrono = chrono::Duration::from_std(buffer).unwrap_or(chrono::Duration::zero());
@@ -41,6 +55,32 @@ impl Token {
     }
 }
 
+/// Claims carried by an access token.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct TokenClaims {
+    /// Subject the token was issued to.
+    pub sub: Option<String>,
+    /// Account the token was issued for, if scoped to one.
+    pub account_id: Option<String>,
+    /// Accounts the token may act on, if the token lists them.
+    pub accounts: Option<Vec<String>>,
+    /// Expiry as a Unix timestamp in seconds.
+    pub exp: Option<i64>,
+}
+
+impl TokenClaims {
+    /// Whether the token can act on behalf of the given account.
+    ///
+    /// Returns `Some(false)` only when the token lists its accounts and the
+    /// given one is not among them. Returns `None` when the claims don't say.
+    pub fn permits_account(&self, account_id: &str) -> Option<bool> {
+        self.accounts
+            .as_ref()
+            .map(|accounts| accounts.iter().any(|a| a == account_id))
+    }
+}
+
 /// Response from the authentication endpoint.
 #[derive(Debug, Deserialize)]
 pub struct LoginResponse {
@@ -54,15 +94,29 @@ pub struct LoginResponse {
 pub struct TokenManager {
     config: Config,
     http_client: reqwest::Client,
+    backend: Arc<dyn HttpBackend>,
     token: Arc<RwLock<Option<Token>>>,
 }
 
 impl TokenManager {
     /// Create a new token manager.
     pub fn new(config: Config, http_client: reqwest::Client) -> Self {
+        let backend = Arc::new(ReqwestBackend::new(http_client.clone()));
+        Self::with_backend(config, http_client, backend)
+    }
+
+    /// Create a token manager that logs in through the given backend.
+    ///
+    /// `http_client` is only used to build requests.
+    pub(crate) fn with_backend(
+        config: Config,
+        http_client: reqwest::Client,
+        backend: Arc<dyn HttpBackend>,
+    ) -> Self {
         Self {
             config,
             http_client,
+            backend,
             token: Arc::new(RwLock::new(None)),
         }
     }
@@ -70,6 +124,13 @@ impl TokenManager {
     /// Get a valid token, refreshing if necessary.
     #[allow(clippy::collapsible_if)]
     pub async fn get_token(&self) -> Result<Token> {
+        if let Some(token) = &self.config.static_token {
+            return Ok(Token::new(
+                token.expose_secret().to_string(),
+                DateTime::<Utc>::MAX_UTC,
+            ));
+        }
+
         // Check if we have a valid token
         {
             let token_guard = self.token.read().await;
@@ -92,6 +153,7 @@ impl TokenManager {
 
         // Perform login
         let new_token = self.login().await?;
+        self.config.metrics.increment(Counter::AuthRefresh);
         *token_guard = Some(new_token.clone());
         Ok(new_token)
     }
@@ -113,30 +175,32 @@ impl TokenManager {
             request = request.header("x-login-as", account_id);
         }
 
-        let response = request.body("").send().await?;
+        let request = request.body("").build()?;
+        let response = self.backend.execute(request).await?;
 
-        let status = response.status();
+        let status = response.status;
 
         if status.is_success() {
-            let login_response: LoginResponse = response.json().await?;
+            let login_response: LoginResponse = serde_json::from_slice(&response.body)?;
             Ok(Token::new(login_response.token, login_response.expires_at))
         } else if status == reqwest::StatusCode::UNAUTHORIZED {
-            let error_body = response.text().await.unwrap_or_default();
             Err(Error::Authentication(format!(
                 "Invalid credentials: {}",
-                error_body
+                response.text()
             )))
         } else if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
             Err(Error::RateLimited { retry_after: None })
         } else {
             // Try to parse as API error
-            let error_text = response.text().await.unwrap_or_default();
+            let error_text = response.text();
             match serde_json::from_str::<ApiErrorResponse>(&error_text) {
                 Ok(api_error) => Err(Error::from_api_response(api_error)),
-                Err(_) => Err(Error::Authentication(format!(
-                    "Authentication failed with status {}: {}",
-                    status, error_text
-                ))),
+                Err(_) => Err(Error::Api {
+                    code: status.as_str().to_string(),
+                    message: error_text,
+                    trace_id: None,
+                    details: None,
+                }),
             }
         }
     }
@@ -176,6 +240,33 @@ mod tests {
         assert!(token.is_expired_with_buffer(Duration::from_secs(7200)));
     }
 
+    fn jwt(claims: serde_json::Value) -> String {
+        use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+
+        format!(
+            "eyJhbGciOiJIUzI1NiJ9.{}.signature",
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        )
+    }
+
+    #[test]
+    fn test_token_claims() {
+        let token = Token::new(
+            jwt(serde_json::json!({"sub": "client_1", "accounts": ["acct_a"]})),
+            Utc::now(),
+        );
+        let claims = token.claims().unwrap();
+        assert_eq!(claims.sub.as_deref(), Some("client_1"));
+        assert_eq!(claims.permits_account("acct_a"), Some(true));
+        assert_eq!(claims.permits_account("acct_b"), Some(false));
+
+        let token = Token::new(jwt(serde_json::json!({"sub": "client_1"})), Utc::now());
+        assert_eq!(token.claims().unwrap().permits_account("acct_b"), None);
+
+        let token = Token::new("opaque".to_string(), Utc::now());
+        assert!(token.claims().is_none());
+    }
+
     #[test]
     fn test_token_bearer_value() {
         let token = Token::new("abc123".to_string(), Utc::now());
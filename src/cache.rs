@@ -0,0 +1,69 @@
+//! Short-lived in-memory response caching.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Whether a value came from a client-side cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheStatus {
+    /// Served from the cache without an API call.
+    Hit,
+    /// Not cached (or expired); fetched from the API and stored.
+    Miss,
+    /// Caching is disabled; fetched from the API.
+    Bypass,
+}
+
+/// A map whose entries expire a fixed time after insertion.
+#[derive(Debug)]
+pub(crate) struct TtlCache<V> {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, V)>>,
+}
+
+impl<V: Clone> TtlCache<V> {
+    /// Create an empty cache.
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get a value if it has not expired.
+    pub(crate) fn get(&self, key: &str) -> Option<V> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.get(key) {
+            Some((inserted, value)) if inserted.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Store a value, dropping any expired entries.
+    pub(crate) fn insert(&self, key: String, value: V) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.retain(|_, (inserted, _)| inserted.elapsed() < self.ttl);
+        entries.insert(key, (Instant::now(), value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_expire() {
+        let cache = TtlCache::new(Duration::from_millis(20));
+        cache.insert("a".to_string(), 1);
+        assert_eq!(cache.get("a"), Some(1));
+        assert_eq!(cache.get("b"), None);
+
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(cache.get("a"), None);
+    }
+}
@@ -1,22 +1,201 @@
 //! The main Airwallex API client.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
-use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use reqwest::header::{
+    AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue, IntoHeaderName,
+};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 
 use crate::auth::TokenManager;
-use crate::config::Config;
+use crate::cache::TtlCache;
+use crate::config::{Config, ConfigBuilder, validate_api_version};
 use crate::error::{ApiErrorResponse, Error, Result};
+use crate::healthcheck::{self, ScopeStatus};
+use crate::http::{HttpBackend, RawResponse, ReqwestBackend};
+use crate::interceptor::{RequestParts, ResponseMeta};
+use crate::metrics::{self, Counter};
+use crate::models::FxRate;
 use crate::resources;
+use crate::retry::{self, RetryEvent, RetryReason};
+
+/// Header carrying a per-request identifier for endpoints that expect it outside the body.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Header carrying the idempotency key of a request.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Headers managed by the client that extra headers never replace.
+const MANAGED_HEADERS: [&str; 7] = [
+    "authorization",
+    "x-api-version",
+    "x-on-behalf-of",
+    REQUEST_ID_HEADER,
+    IDEMPOTENCY_KEY_HEADER,
+    "content-type",
+    "content-length",
+];
+
+/// Per-request options for [`Client::request_with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    request_id: Option<String>,
+    request_id_required: bool,
+    idempotency_key: Option<String>,
+}
+
+impl RequestOptions {
+    /// Create empty request options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send the given `x-request-id` header.
+    ///
+    /// Airwallex treats a repeated request ID as the same operation, so reuse
+    /// the ID when retrying a request that may already have been applied, and
+    /// use a fresh one for a genuinely new operation.
+    pub fn request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// Always send an `x-request-id` header, generating a UUID if none was
+    /// supplied.
+    ///
+    /// For resource methods whose endpoint requires the header. A generated
+    /// ID is reused by the client's own retries of the call, but every call
+    /// gets a new one, so a caller retrying the call itself is only
+    /// idempotent if it supplies the ID with
+    /// [`request_id`](Self::request_id).
+    pub fn require_request_id(mut self) -> Self {
+        self.request_id_required = true;
+        self
+    }
+
+    /// The request ID that will be sent, if any.
+    ///
+    /// `None` if the ID will be generated when the request is sent.
+    pub fn get_request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    /// Send the given `idempotency-key` header.
+    ///
+    /// Retries of the request reuse the key, so a retried create is applied
+    /// once. Reusing a key for a request with a different body fails with
+    /// [`Error::IdempotencyConflict`].
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// The idempotency key that will be sent, if any.
+    pub fn get_idempotency_key(&self) -> Option<&str> {
+        self.idempotency_key.as_deref()
+    }
+}
+
+/// Settings for a scoped client; see [`Client::scope`].
+///
+/// Unset settings keep the value of the client the scope is created from.
+#[derive(Debug, Clone, Default)]
+pub struct ClientScope {
+    api_version: Option<String>,
+    on_behalf_of: Option<String>,
+    headers: HeaderMap,
+    timeout: Option<Duration>,
+}
+
+impl ClientScope {
+    /// Send requests with a different API version.
+    ///
+    /// See [`Client::with_api_version`].
+    pub fn api_version(mut self, version: impl Into<String>) -> Self {
+        self.api_version = Some(version.into());
+        self
+    }
+
+    /// Act on behalf of a connected account.
+    ///
+    /// See [`Client::with_on_behalf_of`].
+    pub fn on_behalf_of(mut self, account_id: impl Into<String>) -> Self {
+        self.on_behalf_of = Some(account_id.into());
+        self
+    }
+
+    /// Send an additional header, replacing an earlier value for the same name.
+    ///
+    /// See [`Client::with_headers`] for the headers that cannot be set this way.
+    pub fn header(mut self, name: impl IntoHeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Use a different timeout for each request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+/// A successful API response with its metadata.
+///
+/// Returned by [`Client::request_with_meta`] and [`Client::get_with_meta`].
+#[derive(Debug, Clone)]
+pub struct ApiResponse<T> {
+    /// The response body.
+    pub data: T,
+    /// HTTP status.
+    pub status: reqwest::StatusCode,
+    /// The trace ID Airwallex assigned to the request, if it sent one.
+    pub trace_id: Option<String>,
+}
+
+/// Body of an outgoing request.
+enum RequestBody {
+    /// No body.
+    None,
+    /// An explicitly empty JSON body.
+    Empty,
+    /// A serialized JSON body.
+    Json(Vec<u8>),
+    /// A serialized JSON Merge Patch (RFC 7396) body.
+    MergePatch(Vec<u8>),
+}
+
+impl RequestBody {
+    /// Serialize a JSON body.
+    fn json<B: Serialize + ?Sized>(body: &B) -> Result<Self> {
+        Ok(RequestBody::Json(serde_json::to_vec(body)?))
+    }
+
+    /// Whether the body carries a `request_id`, making a resend safe.
+    fn has_request_id(&self) -> bool {
+        match self {
+            RequestBody::Json(bytes) | RequestBody::MergePatch(bytes) => {
+                serde_json::from_slice::<serde_json::Value>(bytes)
+                    .is_ok_and(|body| body.get("request_id").is_some_and(|id| !id.is_null()))
+            }
+            RequestBody::None | RequestBody::Empty => false,
+        }
+    }
+}
 
 /// The main Airwallex API client.
 ///
 /// This client handles authentication, request building, and response parsing
 /// for all Airwallex API operations.
 ///
+/// `Client` is cheap to clone: clones share the connection pool and the cached
+/// access token. Connections are released when the last clone is dropped, so
+/// no explicit shutdown is needed; see [`Client::shutdown`] for stopping every
+/// clone at once.
+///
 /// # Example
 ///
 /// ```no_run
@@ -40,7 +219,11 @@ use crate::resources;
 pub struct Client {
     config: Config,
     http_client: reqwest::Client,
+    backend: Arc<dyn HttpBackend>,
     token_manager: Arc<TokenManager>,
+    extra_headers: HeaderMap,
+    rate_cache: Option<Arc<TtlCache<FxRate>>>,
+    closed: Arc<AtomicBool>,
 }
 
 impl Client {
@@ -48,16 +231,51 @@ impl Client {
     pub fn new(config: Config) -> Result<Self> {
         let http_client = reqwest::Client::builder()
             .timeout(config.timeout)
+            .gzip(config.compression)
+            .deflate(config.compression)
+            .brotli(config.compression)
             .build()
             .map_err(Error::Http)?;
+        let backend = Arc::new(ReqwestBackend::new(http_client.clone()));
+
+        Ok(Self::from_parts(config, http_client, backend))
+    }
+
+    /// Create a client that executes requests through the given backend.
+    ///
+    /// Intended for unit tests: with a [`MockBackend`](crate::testing::MockBackend)
+    /// no network access happens at all. Logins also go through the backend,
+    /// so either script the login endpoint or configure a
+    /// [`static_token`](crate::ConfigBuilder::static_token).
+    #[cfg(any(test, feature = "testing"))]
+    pub fn with_backend(config: Config, backend: impl HttpBackend + 'static) -> Self {
+        Self::from_parts(config, reqwest::Client::new(), Arc::new(backend))
+    }
 
-        let token_manager = Arc::new(TokenManager::new(config.clone(), http_client.clone()));
+    /// Assemble a client from its parts.
+    fn from_parts(
+        config: Config,
+        http_client: reqwest::Client,
+        backend: Arc<dyn HttpBackend>,
+    ) -> Self {
+        let token_manager = Arc::new(TokenManager::with_backend(
+            config.clone(),
+            http_client.clone(),
+            Arc::clone(&backend),
+        ));
+        let rate_cache = config
+            .rate_cache_ttl
+            .map(|ttl| Arc::new(TtlCache::new(ttl)));
 
-        Ok(Self {
+        Self {
             config,
             http_client,
+            backend,
             token_manager,
-        })
+            extra_headers: HeaderMap::new(),
+            rate_cache,
+            closed: Arc::new(AtomicBool::new(false)),
+        }
     }
 
     /// Create a new client from environment variables.
@@ -68,11 +286,169 @@ impl Client {
         Self::new(config)
     }
 
+    /// Create a new client from environment variables with settings
+    /// overridden in code.
+    ///
+    /// See [`Config::from_env_with_overrides`].
+    pub fn from_env_with_overrides(
+        overrides: impl FnOnce(ConfigBuilder) -> ConfigBuilder,
+    ) -> Result<Self> {
+        let config = Config::from_env_with_overrides(overrides)?;
+        Self::new(config)
+    }
+
+    /// Stop this client and every clone of it from sending requests.
+    ///
+    /// Later requests through any of them fail with [`Error::Shutdown`]
+    /// without touching the network. Requests already in flight are not
+    /// interrupted. Idle connections are closed once the last clone is
+    /// dropped. Calling this more than once has no further effect.
+    pub fn shutdown(&self) {
+        self.closed.store(true, Ordering::Release);
+    }
+
+    /// Create a handle that sends additional headers with every request.
+    ///
+    /// The returned client shares this client's connection pool and access
+    /// token. Headers are merged with any set by earlier calls, with later
+    /// values replacing earlier ones. Headers the client manages itself
+    /// (`authorization`, `x-api-version`, `x-on-behalf-of`, `x-request-id`,
+    /// `idempotency-key`, `content-type` and `content-length`) are never
+    /// overwritten; configure
+    /// those through [`Config`] or [`RequestOptions`] instead.
+    ///
+    /// ```no_run
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    ///
+    /// # fn example(client: &airwallex_rs::Client) {
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-feature-flag", HeaderValue::from_static("new-flow"));
+    /// let flagged = client.with_headers(headers);
+    /// # }
+    /// ```
+    pub fn with_headers(&self, headers: HeaderMap) -> Self {
+        let mut client = self.clone();
+        client.extra_headers.extend(headers);
+        client
+    }
+
+    /// Create a handle that sends requests with a different API version.
+    ///
+    /// The returned client shares this client's connection pool and access
+    /// token. Useful when a single endpoint requires a newer (or older)
+    /// version than the rest of the integration; see
+    /// [`Error::is_unsupported_api_version`].
+    ///
+    /// A version that is not a `YYYY-MM-DD` date is logged as a warning,
+    /// since this method cannot fail; [`ConfigBuilder::api_version`] rejects
+    /// it instead.
+    pub fn with_api_version(&self, version: impl Into<String>) -> Self {
+        let mut client = self.clone();
+        client.config.api_version = version.into();
+        if let Err(e) = validate_api_version(&client.config.api_version) {
+            tracing::warn!(error = %e, "invalid API version");
+        }
+        client
+    }
+
+    /// Create a handle that acts on behalf of a connected account.
+    ///
+    /// The returned client shares this client's connection pool and access
+    /// token, and sends `x-on-behalf-of: account_id` with every request,
+    /// replacing any account set with
+    /// [`ConfigBuilder::on_behalf_of`](crate::ConfigBuilder::on_behalf_of).
+    pub fn with_on_behalf_of(&self, account_id: impl Into<String>) -> Self {
+        let mut client = self.clone();
+        client.config.on_behalf_of = Some(account_id.into());
+        client
+    }
+
+    /// Create a handle with several settings changed at once.
+    ///
+    /// The returned client shares this client's connection pool and access
+    /// token. Each setting behaves like the matching `with_*` method; the
+    /// timeout applies to API requests but not to logins.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use reqwest::header::HeaderValue;
+    ///
+    /// # fn example(client: &airwallex_rs::Client) {
+    /// let scoped = client.scope(|s| {
+    ///     s.on_behalf_of("acct_123")
+    ///         .api_version("2025-02-14")
+    ///         .header("x-feature-flag", HeaderValue::from_static("new-flow"))
+    ///         .timeout(Duration::from_secs(5))
+    /// });
+    /// # }
+    /// ```
+    pub fn scope(&self, f: impl FnOnce(ClientScope) -> ClientScope) -> Self {
+        let scope = f(ClientScope::default());
+        let mut client = self.with_headers(scope.headers);
+        if let Some(version) = scope.api_version {
+            client = client.with_api_version(version);
+        }
+        if let Some(account_id) = scope.on_behalf_of {
+            client.config.on_behalf_of = Some(account_id);
+        }
+        if let Some(timeout) = scope.timeout {
+            client.config.timeout = timeout;
+        }
+        client
+    }
+
+    /// The FX rate cache, if enabled.
+    pub(crate) fn rate_cache(&self) -> Option<&TtlCache<FxRate>> {
+        self.rate_cache.as_deref()
+    }
+
+    /// The rate cache key for `params`.
+    ///
+    /// Scoped handles share the cache, so the key includes the API version
+    /// and the account the request acts on behalf of.
+    pub(crate) fn rate_cache_key<Q: Serialize + ?Sized>(&self, params: &Q) -> Result<String> {
+        Ok(format!(
+            "{}|{}|{}",
+            self.config.api_version,
+            self.config.on_behalf_of.as_deref().unwrap_or_default(),
+            serde_json::to_string(params)?
+        ))
+    }
+
     /// Get the base URL for the API.
     pub fn base_url(&self) -> &str {
         self.config.base_url()
     }
 
+    /// The full URL a request to `path` with `query` is sent to.
+    ///
+    /// The query is encoded exactly as for a request, so the result can be
+    /// logged, signed or used as a test expectation.
+    ///
+    /// ```
+    /// # fn example(client: &airwallex_rs::Client) -> airwallex_rs::Result<()> {
+    /// use airwallex_rs::models::ListTransfersParams;
+    ///
+    /// let params = ListTransfersParams::new().status("PAID").page_size(50);
+    /// let url = client.build_url("/api/v1/transfers", Some(&params))?;
+    /// assert!(url.ends_with("/api/v1/transfers?status=PAID&page_size=50"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build_url<Q: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        query: Option<&Q>,
+    ) -> Result<String> {
+        let url = format!("{}{}", self.config.base_url(), path);
+        let mut request = self.http_client.get(url);
+        if let Some(query) = query {
+            request = request.query(query);
+        }
+        Ok(request.build()?.url().to_string())
+    }
+
     /// Get the configured API version.
     pub fn api_version(&self) -> &str {
         &self.config.api_version
@@ -85,26 +461,28 @@ impl Client {
     }
 
     /// Make a GET request with query parameters.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "airwallex.request",
+            skip_all,
+            fields(method = "GET", path = %path, status, attempt, trace_id, error)
+        )
+    )]
     pub async fn get_with_query<T: DeserializeOwned, Q: Serialize>(
         &self,
         path: &str,
         query: &Q,
     ) -> Result<T> {
-        let token = self.token_manager.get_token().await?;
-        let url = format!("{}{}", self.config.base_url(), path);
-
-        let mut request = self
-            .http_client
-            .get(&url)
-            .query(query)
-            .header(AUTHORIZATION, token.bearer_value())
-            .header("x-api-version", &self.config.api_version);
-
-        if let Some(account_id) = &self.config.on_behalf_of {
-            request = request.header("x-on-behalf-of", account_id);
-        }
-
-        let response = request.send().await?;
+        let response = self
+            .send(
+                reqwest::Method::GET,
+                path,
+                Some(query),
+                RequestBody::None,
+                &RequestOptions::default(),
+            )
+            .await?;
         self.handle_response(response).await
     }
 
@@ -113,69 +491,335 @@ impl Client {
         self.request(reqwest::Method::POST, path, Some(body)).await
     }
 
-    /// Make a POST request with an empty body.
-    pub async fn post_empty<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
-        let token = self.token_manager.get_token().await?;
-        let url = format!("{}{}", self.config.base_url(), path);
-
-        let mut request = self
-            .http_client
-            .post(&url)
-            .header(AUTHORIZATION, token.bearer_value())
-            .header(CONTENT_TYPE, "application/json")
-            .header("x-api-version", &self.config.api_version)
-            .header("Content-Length", "0")
-            .body("");
+    /// Make a POST request with per-request options, such as an
+    /// [idempotency key](RequestOptions::idempotency_key).
+    pub async fn post_with_options<T: DeserializeOwned, B: Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        self.request_with_options(reqwest::Method::POST, path, Some(body), options)
+            .await
+    }
 
-        if let Some(account_id) = &self.config.on_behalf_of {
-            request = request.header("x-on-behalf-of", account_id);
-        }
+    /// Make a PATCH request with a JSON Merge Patch body.
+    ///
+    /// The body is sent as `application/merge-patch+json`: fields left out
+    /// are unchanged and `null` clears a field. Use
+    /// [`Patch`](crate::models::Patch) for fields that can be cleared. The
+    /// Airwallex endpoints modelled by this crate update with `POST`; this is
+    /// for endpoints that accept `PATCH`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "airwallex.request",
+            skip_all,
+            fields(method = "PATCH", path = %path, status, attempt, trace_id, error)
+        )
+    )]
+    pub async fn patch<T: DeserializeOwned, B: Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T> {
+        let response = self
+            .send_without_query(
+                reqwest::Method::PATCH,
+                path,
+                RequestBody::MergePatch(serde_json::to_vec(body)?),
+                &RequestOptions::default(),
+            )
+            .await?;
+        self.handle_response(response).await
+    }
 
-        let response = request.send().await?;
+    /// Make a POST request with an empty body.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "airwallex.request",
+            skip_all,
+            fields(method = "POST", path = %path, status, attempt, trace_id, error)
+        )
+    )]
+    pub async fn post_empty<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let response = self
+            .send_without_query(
+                reqwest::Method::POST,
+                path,
+                RequestBody::Empty,
+                &RequestOptions::default(),
+            )
+            .await?;
         self.handle_response(response).await
     }
 
     /// Make a POST request with empty body and no response body.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "airwallex.request",
+            skip_all,
+            fields(method = "POST", path = %path, status, attempt, trace_id, error)
+        )
+    )]
     pub async fn post_empty_no_response(&self, path: &str) -> Result<()> {
-        let token = self.token_manager.get_token().await?;
-        let url = format!("{}{}", self.config.base_url(), path);
+        let response = self
+            .send_without_query(
+                reqwest::Method::POST,
+                path,
+                RequestBody::Empty,
+                &RequestOptions::default(),
+            )
+            .await?;
+        self.handle_empty_response(response).await
+    }
 
-        let mut request = self
-            .http_client
-            .post(&url)
-            .header(AUTHORIZATION, token.bearer_value())
-            .header(CONTENT_TYPE, "application/json")
-            .header("x-api-version", &self.config.api_version)
-            .header("Content-Length", "0")
-            .body("");
+    /// Make a POST request without expecting a response body.
+    pub async fn post_no_response<B: Serialize>(&self, path: &str, body: &B) -> Result<()> {
+        self.post_no_response_with_options(path, body, &RequestOptions::default())
+            .await
+    }
 
-        if let Some(account_id) = &self.config.on_behalf_of {
-            request = request.header("x-on-behalf-of", account_id);
-        }
+    /// Make a POST request with per-request options, without expecting a
+    /// response body.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "airwallex.request",
+            skip_all,
+            fields(method = "POST", path = %path, status, attempt, trace_id, error)
+        )
+    )]
+    pub async fn post_no_response_with_options<B: Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+        options: &RequestOptions,
+    ) -> Result<()> {
+        let response = self
+            .send_without_query(
+                reqwest::Method::POST,
+                path,
+                RequestBody::json(body)?,
+                options,
+            )
+            .await?;
+        self.handle_empty_response(response).await
+    }
 
-        let response = request.send().await?;
+    /// Make a DELETE request to the API.
+    ///
+    /// Airwallex deletes resources with `POST .../delete`, which the resource
+    /// methods use; this is for endpoints that take an HTTP `DELETE`.
+    pub async fn delete<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self.request(reqwest::Method::DELETE, path, Option::<&()>::None)
+            .await
+    }
+
+    /// Make a DELETE request without expecting a response body.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "airwallex.request",
+            skip_all,
+            fields(method = "DELETE", path = %path, status, attempt, trace_id, error)
+        )
+    )]
+    pub async fn delete_no_response(&self, path: &str) -> Result<()> {
+        let response = self
+            .send_without_query(
+                reqwest::Method::DELETE,
+                path,
+                RequestBody::None,
+                &RequestOptions::default(),
+            )
+            .await?;
         self.handle_empty_response(response).await
     }
 
-    /// Make a POST request without expecting a response body.
-    pub async fn post_no_response<B: Serialize>(&self, path: &str, body: &B) -> Result<()> {
-        let token = self.token_manager.get_token().await?;
-        let url = format!("{}{}", self.config.base_url(), path);
+    /// Check whether the resource at `path` exists.
+    ///
+    /// Returns `true` for a 2xx response and `false` for 404; other errors are
+    /// returned as usual. Airwallex does not document `HEAD` support, so this
+    /// sends a `GET` and discards the body.
+    #[doc(alias = "head")]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "airwallex.request",
+            skip_all,
+            fields(method = "GET", path = %path, status, attempt, trace_id, error)
+        )
+    )]
+    pub async fn exists(&self, path: &str) -> Result<bool> {
+        let response = self
+            .send_without_query(
+                reqwest::Method::GET,
+                path,
+                RequestBody::None,
+                &RequestOptions::default(),
+            )
+            .await?;
+        let status = response.status;
 
-        let mut request = self
-            .http_client
-            .post(&url)
-            .header(AUTHORIZATION, token.bearer_value())
-            .header(CONTENT_TYPE, "application/json")
-            .header("x-api-version", &self.config.api_version)
-            .json(body);
+        if status.is_success() {
+            Ok(true)
+        } else if status == reqwest::StatusCode::NOT_FOUND {
+            Ok(false)
+        } else {
+            self.handle_error_response(response).await
+        }
+    }
 
-        if let Some(account_id) = &self.config.on_behalf_of {
-            request = request.header("x-on-behalf-of", account_id);
+    /// Check which API scopes the credentials grant.
+    ///
+    /// Sends one read request per scope, listing a single item where the
+    /// endpoint is a list, with at most four in flight and no retries. A
+    /// scope is [`ScopeStatus::Denied`] only when the API answers
+    /// "Insufficient permissions"; any other failure is reported as
+    /// [`ScopeStatus::Unknown`] and logged at `DEBUG` level.
+    ///
+    /// ```no_run
+    /// use airwallex_rs::ScopeStatus;
+    ///
+    /// # async fn example(client: &airwallex_rs::Client) {
+    /// for (scope, status) in client.healthcheck_scopes().await {
+    ///     if status != ScopeStatus::Available {
+    ///         println!("{scope}: {status:?}");
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn healthcheck_scopes(&self) -> BTreeMap<&'static str, ScopeStatus> {
+        let mut client = self.clone();
+        client.config.max_retries = 0;
+        healthcheck::probe_scopes(&client).await
+    }
+
+    /// Send a caller-built request and return the raw response.
+    ///
+    /// For endpoints this crate does not model, such as file downloads where
+    /// the body should be streamed or response headers inspected. The client
+    /// adds the usual authentication, API version and configured headers, and
+    /// error statuses are mapped as for any other call (404 to
+    /// [`Error::NotFound`], 429 to [`Error::RateLimited`], 401 to an
+    /// authentication error). Successful responses are returned unread.
+    ///
+    /// The request is sent once; it is not retried, since its body may not be
+    /// replayable. Requests to a URL outside [`Client::base_url`] are rejected
+    /// with [`Error::Validation`] before any credentials are added, so the
+    /// access token never leaves the configured API host.
+    ///
+    /// ```no_run
+    /// # async fn example(client: &airwallex_rs::Client) -> airwallex_rs::Result<()> {
+    /// let url = format!("{}/api/v1/some/unmodeled/endpoint", client.base_url());
+    /// let response = client.send_raw(reqwest::Client::new().get(url)).await?;
+    /// let bytes = response.bytes().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_raw(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let (http_client, request) = request.build_split();
+        let request = request?;
+        let base_url = url::Url::parse(self.config.base_url())?;
+        if request.url().origin() != base_url.origin() {
+            return Err(Error::Validation(format!(
+                "send_raw only sends to {}, not {}",
+                base_url.origin().ascii_serialization(),
+                request.url().origin().ascii_serialization()
+            )));
         }
 
+        let request = reqwest::RequestBuilder::from_parts(http_client, request);
+        let request = self
+            .apply_headers(request, &RequestOptions::default())
+            .await?;
         let response = request.send().await?;
-        self.handle_empty_response(response).await
+
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            let response = RawResponse::read(response).await?;
+            self.handle_error_response(response).await
+        }
+    }
+
+    /// Make an API request with per-request options.
+    ///
+    /// This is the low-level entry point for endpoints that need more than the
+    /// standard headers, such as a `x-request-id` header. See [`RequestOptions`].
+    pub async fn request_with_options<T: DeserializeOwned, B: Serialize>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&B>,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        self.request_with_meta(method, path, body, options)
+            .await
+            .map(|response| response.data)
+    }
+
+    /// Make an API request and return the response's status and trace ID
+    /// along with its body.
+    ///
+    /// Airwallex support asks for the trace ID when investigating a request,
+    /// including ones that succeeded. See [`ApiResponse`].
+    ///
+    /// ```no_run
+    /// use airwallex_rs::RequestOptions;
+    /// use airwallex_rs::models::Transfer;
+    ///
+    /// # async fn example(client: &airwallex_rs::Client) -> airwallex_rs::Result<()> {
+    /// let response = client
+    ///     .request_with_meta::<Transfer, ()>(
+    ///         reqwest::Method::GET,
+    ///         "/api/v1/transfers/tfr_123",
+    ///         None,
+    ///         &RequestOptions::new(),
+    ///     )
+    ///     .await?;
+    /// println!("{:?} (trace {:?})", response.data.status, response.trace_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "airwallex.request",
+            skip_all,
+            fields(method = %method, path = %path, status, attempt, trace_id, error)
+        )
+    )]
+    pub async fn request_with_meta<T: DeserializeOwned, B: Serialize>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&B>,
+        options: &RequestOptions,
+    ) -> Result<ApiResponse<T>> {
+        let body = match body {
+            Some(body) => RequestBody::json(body)?,
+            None => RequestBody::None,
+        };
+        let response = self.send_without_query(method, path, body, options).await?;
+        self.handle_response_with_meta(response).await
+    }
+
+    /// Make a GET request and return the response's status and trace ID
+    /// along with its body.
+    ///
+    /// See [`Client::request_with_meta`].
+    pub async fn get_with_meta<T: DeserializeOwned>(&self, path: &str) -> Result<ApiResponse<T>> {
+        self.request_with_meta(
+            reqwest::Method::GET,
+            path,
+            Option::<&()>::None,
+            &RequestOptions::default(),
+        )
+        .await
     }
 
     /// Make an API request with the given method, path, and optional body.
@@ -185,75 +829,329 @@ impl Client {
         path: &str,
         body: Option<&B>,
     ) -> Result<T> {
-        let token = self.token_manager.get_token().await?;
-        let url = format!("{}{}", self.config.base_url(), path);
+        self.request_with_options(method, path, body, &RequestOptions::default())
+            .await
+    }
+
+    /// Send a request without query parameters.
+    async fn send_without_query(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: RequestBody,
+        options: &RequestOptions,
+    ) -> Result<RawResponse> {
+        self.send(method, path, Option::<&()>::None, body, options)
+            .await
+    }
 
+    /// Send a request, retrying transient failures.
+    ///
+    /// A request that may already have been applied (a timeout or a gateway
+    /// error) is only resent if it is a `GET` or carries a request ID or
+    /// idempotency key; any request is retried after a 429 or a failure to
+    /// connect. Returns the last response or error once retries are exhausted.
+    async fn send<Q: Serialize + ?Sized>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: Option<&Q>,
+        body: RequestBody,
+        options: &RequestOptions,
+    ) -> Result<RawResponse> {
+        let generated;
+        let options = if options.request_id_required && options.request_id.is_none() {
+            generated = options.clone().request_id(uuid::Uuid::new_v4().to_string());
+            &generated
+        } else {
+            options
+        };
+        let replayable = method == reqwest::Method::GET
+            || options.get_request_id().is_some()
+            || options.get_idempotency_key().is_some()
+            || body.has_request_id();
+        let mut attempt = 0;
+        let result = loop {
+            let result = self
+                .send_once(method.clone(), path, query, &body, options)
+                .await;
+
+            let (reason, retry_after) = match &result {
+                Ok(response)
+                    if retry::is_retryable_status(response.status)
+                        && (replayable
+                            || response.status == reqwest::StatusCode::TOO_MANY_REQUESTS) =>
+                {
+                    (
+                        RetryReason::Status(response.status.as_u16()),
+                        retry_after(&response.headers),
+                    )
+                }
+                Err(Error::Http(e)) if e.is_connect() || (replayable && e.is_timeout()) => {
+                    (RetryReason::Transport(e.to_string()), None)
+                }
+                _ => break result,
+            };
+            if attempt >= self.config.max_retries {
+                break result;
+            }
+            attempt += 1;
+
+            let delay = retry_after
+                .unwrap_or_else(|| retry::backoff(self.config.retry_base_delay, attempt))
+                .min(retry::MAX_RETRY_DELAY);
+            let event = RetryEvent {
+                attempt,
+                delay,
+                method: method.clone(),
+                path: path.to_string(),
+                reason,
+            };
+            tracing::warn!(
+                attempt = event.attempt,
+                delay_ms = event.delay.as_millis() as u64,
+                method = %event.method,
+                path = %event.path,
+                reason = %event.reason,
+                "retry"
+            );
+            if let Some(observer) = &self.config.retry_observer {
+                observer.on_retry(&event);
+            }
+            self.config.metrics.increment(Counter::Retry);
+
+            tokio::time::sleep(delay).await;
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("attempt", attempt);
+
+        // Error responses are counted once they are converted to an Error
+        if let Err(error) = &result {
+            self.record_error(error);
+        }
+        result
+    }
+
+    /// Build and send a single request with the standard authentication and API headers.
+    async fn send_once<Q: Serialize + ?Sized>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: Option<&Q>,
+        body: &RequestBody,
+        options: &RequestOptions,
+    ) -> Result<RawResponse> {
+        let url = self.build_url(path, query)?;
         let mut request = self
             .http_client
-            .request(method, &url)
+            .request(method.clone(), url)
+            .timeout(self.config.timeout);
+        request = self.apply_headers(request, options).await?;
+
+        request = match body {
+            RequestBody::None => request,
+            RequestBody::Empty => request
+                .header(CONTENT_TYPE, "application/json")
+                .header(CONTENT_LENGTH, "0")
+                .body(""),
+            RequestBody::Json(bytes) => request
+                .header(CONTENT_TYPE, "application/json")
+                .body(bytes.clone()),
+            RequestBody::MergePatch(bytes) => request
+                .header(CONTENT_TYPE, "application/merge-patch+json")
+                .body(bytes.clone()),
+        };
+
+        let mut request = request.build()?;
+        self.intercept_request(&mut request, path).await;
+        let started = Instant::now();
+        self.config.metrics.increment(Counter::Request);
+        let result = self.backend.execute(request).await;
+        let elapsed = started.elapsed();
+        #[cfg(feature = "tracing")]
+        if let Ok(response) = &result {
+            let span = tracing::Span::current();
+            span.record("status", response.status.as_u16());
+            if let Some(trace_id) = response.trace_id() {
+                span.record("trace_id", trace_id);
+            }
+        }
+        self.config
+            .metrics
+            .record_request_duration(&method, path, elapsed);
+
+        if !self.config.interceptors.is_empty() {
+            let meta = ResponseMeta {
+                method,
+                path: path.to_string(),
+                status: result.as_ref().ok().map(|response| response.status),
+                elapsed,
+            };
+            for interceptor in self.config.interceptors.iter().rev() {
+                interceptor.after(&meta).await;
+            }
+        }
+        result
+    }
+
+    /// Run the interceptors' `before` hooks on a request.
+    ///
+    /// Interceptors never see the `authorization` header, and their changes
+    /// to headers the client manages are discarded.
+    async fn intercept_request(&self, request: &mut reqwest::Request, path: &str) {
+        if self.config.interceptors.is_empty() {
+            return;
+        }
+
+        let mut headers = request.headers().clone();
+        headers.remove(AUTHORIZATION);
+        let body = request.body().and_then(|body| body.as_bytes());
+        let mut parts =
+            RequestParts::new(request.method().clone(), path.to_string(), headers, body);
+        for interceptor in &self.config.interceptors {
+            interceptor.before(&mut parts).await;
+        }
+
+        let mut headers: HeaderMap = request
+            .headers()
+            .iter()
+            .filter(|(name, _)| is_managed_header(name))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        for (name, value) in &parts.headers {
+            if !is_managed_header(name) {
+                headers.append(name, value.clone());
+            }
+        }
+        *request.headers_mut() = headers;
+    }
+
+    /// Add the authentication, API version and configured headers to a request.
+    async fn apply_headers(
+        &self,
+        mut request: reqwest::RequestBuilder,
+        options: &RequestOptions,
+    ) -> Result<reqwest::RequestBuilder> {
+        if self.closed.load(Ordering::Acquire) {
+            return Err(Error::Shutdown);
+        }
+        let token = self.token_manager.get_token().await?;
+        request = request
             .header(AUTHORIZATION, token.bearer_value())
             .header("x-api-version", &self.config.api_version);
 
         if let Some(account_id) = &self.config.on_behalf_of {
+            // Fail early rather than with the API's generic permission error.
+            if token.claims().and_then(|c| c.permits_account(account_id)) == Some(false) {
+                return Err(Error::ScopeMismatch {
+                    account_id: account_id.clone(),
+                });
+            }
             request = request.header("x-on-behalf-of", account_id);
         }
 
-        if let Some(body) = body {
-            request = request.header(CONTENT_TYPE, "application/json").json(body);
+        if let Some(request_id) = &options.request_id {
+            request = request.header(REQUEST_ID_HEADER, request_id);
         }
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+        if let Some(key) = &options.idempotency_key {
+            request = request.header(IDEMPOTENCY_KEY_HEADER, key);
+        }
+
+        for (name, value) in &self.extra_headers {
+            if !is_managed_header(name) {
+                request = request.header(name, value);
+            }
+        }
+
+        Ok(request)
     }
 
     /// Handle the API response, parsing success or error.
-    async fn handle_response<T: DeserializeOwned>(&self, response: reqwest::Response) -> Result<T> {
-        let status = response.status();
+    async fn handle_response<T: DeserializeOwned>(&self, response: RawResponse) -> Result<T> {
+        self.handle_response_with_meta(response)
+            .await
+            .map(|response| response.data)
+    }
 
-        if status.is_success() {
-            let body = response.json().await?;
-            Ok(body)
+    /// Handle the API response, keeping the status and trace ID on success.
+    async fn handle_response_with_meta<T: DeserializeOwned>(
+        &self,
+        response: RawResponse,
+    ) -> Result<ApiResponse<T>> {
+        if response.status.is_success() {
+            Ok(ApiResponse {
+                data: serde_json::from_slice(&response.body)?,
+                status: response.status,
+                trace_id: response.trace_id().map(str::to_string),
+            })
         } else {
-            self.handle_error_response(response, status).await
+            self.handle_error_response(response).await
         }
     }
 
     /// Handle an API response that should have no body.
-    async fn handle_empty_response(&self, response: reqwest::Response) -> Result<()> {
-        let status = response.status();
-
-        if status.is_success() {
+    async fn handle_empty_response(&self, response: RawResponse) -> Result<()> {
+        if response.status.is_success() {
             Ok(())
         } else {
-            self.handle_error_response(response, status).await
+            self.handle_error_response(response).await
+        }
+    }
+
+    /// Count an error under its code.
+    fn record_error(&self, error: &Error) {
+        self.config.metrics.increment(Counter::Error {
+            code: metrics::error_code(error),
+        });
+
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            span.record("error", metrics::error_code(error));
+            if let Error::Api {
+                trace_id: Some(trace_id),
+                ..
+            } = error
+            {
+                span.record("trace_id", trace_id.as_str());
+            }
         }
     }
 
     /// Convert an error response into an Error.
-    async fn handle_error_response<T>(
-        &self,
-        response: reqwest::Response,
-        status: reqwest::StatusCode,
-    ) -> Result<T> {
+    async fn handle_error_response<T>(&self, response: RawResponse) -> Result<T> {
+        let result = self.error_from_response(response).await;
+        if let Err(error) = &result {
+            self.record_error(error);
+        }
+        result
+    }
+
+    /// Map an error response to the matching Error.
+    async fn error_from_response<T>(&self, response: RawResponse) -> Result<T> {
+        let status = response.status;
+
         if status == reqwest::StatusCode::NOT_FOUND {
             return Err(Error::NotFound);
         }
 
         if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
-            let retry_after = response
-                .headers()
-                .get("retry-after")
-                .and_then(|v| v.to_str().ok())
-                .and_then(|v| v.parse::<u64>().ok())
-                .map(Duration::from_secs);
-
-            return Err(Error::RateLimited { retry_after });
+            return Err(Error::RateLimited {
+                retry_after: retry_after(&response.headers),
+            });
         }
 
         if status == reqwest::StatusCode::UNAUTHORIZED {
+            // A static token can't be refreshed, so report it as expired
+            if self.config.static_token.is_some() {
+                return Err(Error::TokenExpired);
+            }
+
             // Invalidate token and return auth error
             self.token_manager.invalidate().await;
-            let body = response.text().await.unwrap_or_default();
+            let body = response.text();
             return Err(Error::Authentication(format!(
                 "Request unauthorized: {}",
                 body
@@ -261,7 +1159,7 @@ impl Client {
         }
 
         // Try to parse as API error
-        let error_text = response.text().await.unwrap_or_default();
+        let error_text = response.text();
         match serde_json::from_str::<ApiErrorResponse>(&error_text) {
             Ok(api_error) => Err(Error::from_api_response(api_error)),
             Err(_) => Err(Error::Api {
@@ -441,6 +1339,25 @@ impl Client {
     pub fn conversion_amendments(&self) -> resources::ConversionAmendments<'_> {
         resources::ConversionAmendments::new(self)
     }
+
+    /// Access the Webhooks resource.
+    pub fn webhooks(&self) -> resources::Webhooks<'_> {
+        resources::Webhooks::new(self)
+    }
+}
+
+/// Parse a `Retry-After` header given in seconds.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get("retry-after")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Whether a header is set by the client itself.
+fn is_managed_header(name: &HeaderName) -> bool {
+    MANAGED_HEADERS.contains(&name.as_str())
 }
 
 impl Clone for Client {
@@ -448,7 +1365,936 @@ impl Clone for Client {
         Self {
             config: self.config.clone(),
             http_client: self.http_client.clone(),
+            backend: Arc::clone(&self.backend),
             token_manager: Arc::clone(&self.token_manager),
+            extra_headers: self.extra_headers.clone(),
+            rate_cache: self.rate_cache.clone(),
+            closed: Arc::clone(&self.closed),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SANDBOX_API_VERSION;
+    use crate::testing::mock::{mock_client, mock_config, mock_login};
+    use wiremock::matchers::{header, header_exists, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_request_id_header_sent() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/things/create"))
+            .and(header(REQUEST_ID_HEADER, "req_123"))
+            .and(header("x-api-version", SANDBOX_API_VERSION))
+            .and(header("authorization", "Bearer test_token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let options = RequestOptions::new().request_id("req_123");
+        let _: serde_json::Value = client
+            .request_with_options(
+                reqwest::Method::POST,
+                "/api/v1/things/create",
+                Some(&serde_json::json!({"name": "thing"})),
+                &options,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_request_id_generated_when_required() {
+        let server = wiremock::MockServer::start().await;
+        mock_login(&server, "test_token").await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/things/create"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/things/create"))
+            .and(header_exists(REQUEST_ID_HEADER))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+
+        let config = mock_config(&server)
+            .retry_base_delay(Duration::from_millis(1))
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+        let options = RequestOptions::new().require_request_id();
+        assert_eq!(options.get_request_id(), None);
+        for _ in 0..2 {
+            let _: serde_json::Value = client
+                .request_with_options(
+                    reqwest::Method::POST,
+                    "/api/v1/things/create",
+                    Option::<&()>::None,
+                    &options,
+                )
+                .await
+                .unwrap();
+        }
+
+        let ids: Vec<String> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|r| r.url.path() == "/api/v1/things/create")
+            .map(|r| r.headers[REQUEST_ID_HEADER].to_str().unwrap().to_string())
+            .collect();
+        assert_eq!(ids.len(), 3);
+        assert!(uuid::Uuid::parse_str(&ids[0]).is_ok());
+        // The retry reuses the generated ID; the next call gets a new one.
+        assert_eq!(ids[0], ids[1]);
+        assert_ne!(ids[1], ids[2]);
+    }
+
+    #[tokio::test]
+    async fn test_get_with_meta_returns_trace_id() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/transfers/tfr_1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-awx-traceid", "trace_ok_1")
+                    .set_body_json(serde_json::json!({"id": "tfr_1"})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/transfers/tfr_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+
+        let response = client
+            .get_with_meta::<serde_json::Value>("/api/v1/transfers/tfr_1")
+            .await
+            .unwrap();
+        assert_eq!(response.data["id"], "tfr_1");
+        assert_eq!(response.status, reqwest::StatusCode::OK);
+        assert_eq!(response.trace_id.as_deref(), Some("trace_ok_1"));
+
+        let response = client
+            .get_with_meta::<serde_json::Value>("/api/v1/transfers/tfr_2")
+            .await
+            .unwrap();
+        assert_eq!(response.trace_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_patch_sends_only_changed_fields() {
+        use crate::models::Patch;
+
+        #[derive(serde::Serialize)]
+        struct UpdateThing {
+            #[serde(skip_serializing_if = "Patch::is_unchanged")]
+            name: Patch<String>,
+            #[serde(skip_serializing_if = "Patch::is_unchanged")]
+            nick_name: Patch<String>,
+            #[serde(skip_serializing_if = "Patch::is_unchanged")]
+            description: Patch<String>,
+        }
+
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/things/thing_1"))
+            .and(header("content-type", "application/merge-patch+json"))
+            .and(header("authorization", "Bearer test_token"))
+            .and(wiremock::matchers::body_json(serde_json::json!({
+                "name": "renamed",
+                "description": null
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let update = UpdateThing {
+            name: Patch::Set("renamed".to_string()),
+            nick_name: Patch::Unchanged,
+            description: Patch::Clear,
+        };
+        let _: serde_json::Value = client
+            .patch("/api/v1/things/thing_1", &update)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_delete_sends_auth_headers() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/api/v1/things/thing_1"))
+            .and(header("authorization", "Bearer test_token"))
+            .and(header("x-api-version", SANDBOX_API_VERSION))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        client
+            .delete_no_response("/api/v1/things/thing_1")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_on_behalf_of_outside_token_scope() {
+        use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+
+        let server = wiremock::MockServer::start().await;
+        let claims = serde_json::json!({"sub": "client_1", "accounts": ["acct_a"]});
+        let token = format!(
+            "eyJhbGciOiJIUzI1NiJ9.{}.signature",
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        );
+        mock_login(&server, &token).await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/current"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let config = mock_config(&server).on_behalf_of("acct_b").build().unwrap();
+        let client = Client::new(config).unwrap();
+
+        let result = client.balances().current().await;
+        assert!(matches!(
+            result,
+            Err(Error::ScopeMismatch { account_id }) if account_id == "acct_b"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_login_failure_is_auth_failure_only_for_401() {
+        let server = wiremock::MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/authentication/login"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("upstream unavailable"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/authentication/login"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "code": "credentials_invalid",
+                "message": "Invalid client_id or api_key"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::new(mock_config(&server).build().unwrap()).unwrap();
+
+        let unavailable = client.balances().current().await.unwrap_err();
+        assert!(!unavailable.is_auth_failure(), "{:?}", unavailable);
+        let rejected = client.balances().current().await.unwrap_err();
+        assert!(matches!(rejected, Error::Authentication(_)));
+        assert!(rejected.is_auth_failure());
+    }
+
+    #[tokio::test]
+    async fn test_static_token_skips_login() {
+        let server = wiremock::MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/authentication/login"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/current"))
+            .and(header("authorization", "Bearer injected_token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let config = Config::builder()
+            .base_url(server.uri())
+            .static_token("injected_token")
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        let balances = client.balances().current().await.unwrap();
+        assert!(balances.items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_compression_enabled_by_default() {
+        let server = wiremock::MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/current"))
+            .and(header_exists("accept-encoding"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/current"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let config = Config::builder()
+            .base_url(server.uri())
+            .static_token("token")
+            .max_retries(0)
+            .build()
+            .unwrap();
+        assert!(config.compression);
+        let client = Client::new(config).unwrap();
+        client.balances().current().await.unwrap();
+
+        let config = Config::builder()
+            .base_url(server.uri())
+            .static_token("token")
+            .max_retries(0)
+            .compression(false)
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+        assert!(client.balances().current().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_static_token_rejected() {
+        let server = wiremock::MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/current"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "code": "credentials_expired",
+                "message": "Access token has expired"
+            })))
+            .mount(&server)
+            .await;
+
+        let config = Config::builder()
+            .base_url(server.uri())
+            .static_token("stale_token")
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        let result = client.balances().current().await;
+        assert!(matches!(result, Err(Error::TokenExpired)));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_stops_every_clone() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/current"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let clone = client.clone();
+        assert!(clone.balances().current().await.is_ok());
+
+        client.shutdown();
+        client.shutdown();
+
+        let result = clone.balances().current().await;
+        assert!(matches!(result, Err(Error::Shutdown)));
+        let url = format!("{}/api/v1/balances/current", client.base_url());
+        let result = client.send_raw(reqwest::Client::new().get(url)).await;
+        assert!(matches!(result, Err(Error::Shutdown)));
+    }
+
+    #[tokio::test]
+    async fn test_with_headers_sent_alongside_standard_headers() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/current"))
+            .and(header("x-feature-flag", "new-flow"))
+            .and(header("authorization", "Bearer test_token"))
+            .and(header("x-api-version", SANDBOX_API_VERSION))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-feature-flag", "new-flow".parse().unwrap());
+        headers.insert(AUTHORIZATION, "Bearer forged".parse().unwrap());
+        let scoped = client.with_headers(headers);
+
+        scoped.balances().current().await.unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        let sent = requests
+            .iter()
+            .find(|r| r.url.path() == "/api/v1/balances/current")
+            .unwrap();
+        assert_eq!(sent.headers.get_all(AUTHORIZATION).iter().count(), 1);
+        assert!(client.extra_headers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_with_api_version() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/invoices"))
+            .and(header("x-api-version", "2025-02-14"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let pinned = client.with_api_version("2025-02-14");
+        pinned
+            .get::<serde_json::Value>("/api/v1/invoices")
+            .await
+            .unwrap();
+        assert_eq!(pinned.api_version(), "2025-02-14");
+        assert_eq!(client.api_version(), SANDBOX_API_VERSION);
+    }
+
+    #[tokio::test]
+    async fn test_scope_applies_all_settings() {
+        use crate::testing::{MockBackend, RawResponse};
+
+        let backend = Arc::new(MockBackend::new().respond(
+            reqwest::Method::GET,
+            "/api/v1/invoices",
+            RawResponse::json(reqwest::StatusCode::OK, &serde_json::json!({})),
+        ));
+        let config = Config::builder().static_token("token").build().unwrap();
+        let client = Client::with_backend(config, Arc::clone(&backend));
+
+        let scoped = client.scope(|s| {
+            s.on_behalf_of("acct_123")
+                .api_version("2025-02-14")
+                .header("x-feature-flag", HeaderValue::from_static("new-flow"))
+                .timeout(Duration::from_secs(5))
+        });
+        scoped
+            .get::<serde_json::Value>("/api/v1/invoices")
+            .await
+            .unwrap();
+        client
+            .get::<serde_json::Value>("/api/v1/invoices")
+            .await
+            .unwrap();
+
+        let requests = backend.requests();
+        let headers = &requests[0].headers;
+        assert_eq!(headers["x-on-behalf-of"], "acct_123");
+        assert_eq!(headers["x-api-version"], "2025-02-14");
+        assert_eq!(headers["x-feature-flag"], "new-flow");
+        assert_eq!(requests[0].timeout, Some(Duration::from_secs(5)));
+
+        let headers = &requests[1].headers;
+        assert!(!headers.contains_key("x-on-behalf-of"));
+        assert!(!headers.contains_key("x-feature-flag"));
+        assert_eq!(headers["x-api-version"], SANDBOX_API_VERSION);
+        assert_ne!(requests[1].timeout, Some(Duration::from_secs(5)));
+    }
+
+    #[tokio::test]
+    async fn test_interceptors_see_and_change_requests() {
+        use std::sync::Mutex;
+
+        use crate::interceptor::{RequestInterceptor, async_trait};
+        use crate::testing::{MockBackend, RawResponse};
+
+        #[derive(Default)]
+        struct Recorder(Mutex<Vec<String>>);
+
+        #[async_trait]
+        impl RequestInterceptor for Arc<Recorder> {
+            async fn before(&self, request: &mut RequestParts) {
+                let mut seen = self.0.lock().unwrap();
+                seen.push(format!("{} {}", request.method(), request.path()));
+                assert!(!request.headers.contains_key(AUTHORIZATION));
+                let body: serde_json::Value =
+                    serde_json::from_slice(request.body().unwrap()).unwrap();
+                assert_eq!(
+                    body,
+                    serde_json::json!({
+                        "name": "thing",
+                        "payment_method": {
+                            "card": {"number": "************1111", "cvc": "***"}
+                        }
+                    })
+                );
+                request
+                    .headers
+                    .insert("x-correlation-id", HeaderValue::from_static("corr_1"));
+                request
+                    .headers
+                    .insert(AUTHORIZATION, HeaderValue::from_static("Bearer forged"));
+            }
+
+            async fn after(&self, response: &ResponseMeta) {
+                let status = response.status.map(|status| status.as_u16());
+                self.0.lock().unwrap().push(format!("{:?}", status));
+            }
+        }
+
+        let backend = Arc::new(MockBackend::new().respond(
+            reqwest::Method::POST,
+            "/api/v1/things/create",
+            RawResponse::json(reqwest::StatusCode::CREATED, &serde_json::json!({})),
+        ));
+        let recorder = Arc::new(Recorder::default());
+        let config = Config::builder()
+            .static_token("token")
+            .interceptor(Arc::clone(&recorder))
+            .interceptor(crate::interceptor::LatencyLogger)
+            .build()
+            .unwrap();
+        let client = Client::with_backend(config, Arc::clone(&backend));
+
+        let _: serde_json::Value = client
+            .post(
+                "/api/v1/things/create",
+                &serde_json::json!({
+                    "name": "thing",
+                    "payment_method": {
+                        "card": {"number": "4111111111111111", "cvc": "123"}
+                    }
+                }),
+            )
+            .await
+            .unwrap();
+
+        let sent = String::from_utf8(backend.requests()[0].body.clone().unwrap()).unwrap();
+        assert!(sent.contains("4111111111111111"));
+        let headers = &backend.requests()[0].headers;
+        assert_eq!(headers["x-correlation-id"], "corr_1");
+        assert_eq!(headers[AUTHORIZATION], "Bearer token");
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            ["POST /api/v1/things/create", "Some(201)"]
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_request_span_records_fields() {
+        use std::collections::HashMap;
+        use std::sync::Mutex;
+
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+        #[derive(Clone, Default)]
+        struct Fields(Arc<Mutex<HashMap<String, String>>>);
+
+        impl Visit for Fields {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                let mut fields = self.0.lock().unwrap();
+                fields.insert(field.name().to_string(), value.to_string());
+            }
+
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                let mut fields = self.0.lock().unwrap();
+                fields.insert(field.name().to_string(), format!("{:?}", value));
+            }
+        }
+
+        impl<S: tracing::Subscriber> Layer<S> for Fields {
+            fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+                attrs.record(&mut self.clone());
+            }
+
+            fn on_record(&self, _: &Id, values: &Record<'_>, _: Context<'_, S>) {
+                values.record(&mut self.clone());
+            }
+        }
+
+        let fields = Fields::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(fields.clone()));
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/things"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "code": "invalid_argument",
+                "message": "Bad things",
+                "trace_id": "trace_123"
+            })))
+            .mount(&server)
+            .await;
+
+        let result = client
+            .get_with_query::<serde_json::Value, _>("/api/v1/things", &[("page_size", 10)])
+            .await;
+        assert!(result.is_err());
+
+        let fields = fields.0.lock().unwrap();
+        assert_eq!(fields["method"], "GET");
+        assert_eq!(fields["path"], "/api/v1/things");
+        assert_eq!(fields["status"], "400");
+        assert_eq!(fields["attempt"], "0");
+        assert_eq!(fields["error"], "invalid_argument");
+        assert_eq!(fields["trace_id"], "trace_123");
+        assert!(!fields.contains_key("authorization"));
+    }
+
+    #[tokio::test]
+    async fn test_exists() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/transfers/tfr_1"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": "tfr_1"})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/transfers/tfr_missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/transfers/tfr_broken"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        assert!(client.exists("/api/v1/transfers/tfr_1").await.unwrap());
+        assert!(
+            !client
+                .exists("/api/v1/transfers/tfr_missing")
+                .await
+                .unwrap()
+        );
+        assert!(matches!(
+            client.exists("/api/v1/transfers/tfr_broken").await,
+            Err(Error::Api { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_build_url() {
+        use crate::models::ListTransfersParams;
+
+        let (server, client) = mock_client().await;
+        let params = ListTransfersParams::new()
+            .status("PAID")
+            .page_num(2)
+            .page_size(50);
+
+        assert_eq!(
+            client
+                .build_url("/api/v1/transfers", Some(&params))
+                .unwrap(),
+            format!(
+                "{}/api/v1/transfers?status=PAID&page_num=2&page_size=50",
+                server.uri()
+            )
+        );
+        assert_eq!(
+            client
+                .build_url("/api/v1/transfers", Some(&ListTransfersParams::new()))
+                .unwrap(),
+            format!("{}/api/v1/transfers", server.uri())
+        );
+        assert_eq!(
+            client
+                .build_url("/api/v1/transfers", Option::<&()>::None)
+                .unwrap(),
+            format!("{}/api/v1/transfers", server.uri())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_raw() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/reports/rpt_1/download"))
+            .and(header("authorization", "Bearer test_token"))
+            .and(header("x-api-version", SANDBOX_API_VERSION))
+            .respond_with(ResponseTemplate::new(200).set_body_raw("id,amount\n1,10\n", "text/csv"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/reports/rpt_busy/download"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "7"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let http = reqwest::Client::new();
+        let response = client
+            .send_raw(http.get(format!(
+                "{}/api/v1/reports/rpt_1/download",
+                client.base_url()
+            )))
+            .await
+            .unwrap();
+        assert_eq!(response.headers()["content-type"], "text/csv");
+        assert_eq!(response.text().await.unwrap(), "id,amount\n1,10\n");
+
+        let result = client
+            .send_raw(http.get(format!(
+                "{}/api/v1/reports/rpt_busy/download",
+                client.base_url()
+            )))
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::RateLimited {
+                retry_after: Some(d)
+            }) if d == Duration::from_secs(7)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_raw_rejects_other_hosts() {
+        let (server, client) = mock_client().await;
+        let other = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&other)
+            .await;
+
+        let result = client
+            .send_raw(
+                reqwest::Client::new().get(format!("{}/api/v1/balances/current", other.uri())),
+            )
+            .await;
+        assert!(matches!(result, Err(Error::Validation(_))), "{:?}", result);
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_retry_observer_on_rate_limits() {
+        use std::sync::Mutex;
+
+        let server = wiremock::MockServer::start().await;
+        mock_login(&server, "test_token").await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/current"))
+            .respond_with(ResponseTemplate::new(429))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/current"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&events);
+        let config = mock_config(&server)
+            .retry_base_delay(Duration::from_millis(1))
+            .retry_observer(move |event: &RetryEvent| {
+                recorded.lock().unwrap().push(event.clone());
+            })
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        client.balances().current().await.unwrap();
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].attempt, 1);
+        assert_eq!(events[1].attempt, 2);
+        assert_eq!(events[0].delay, Duration::from_millis(1));
+        assert_eq!(events[1].delay, Duration::from_millis(2));
+        assert_eq!(events[0].reason, RetryReason::Status(429));
+        assert_eq!(events[0].path, "/api/v1/balances/current");
+    }
+
+    #[tokio::test]
+    async fn test_retries_exhausted_returns_rate_limited() {
+        let server = wiremock::MockServer::start().await;
+        mock_login(&server, "test_token").await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/current"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let config = mock_config(&server).max_retries(1).build().unwrap();
+        let client = Client::new(config).unwrap();
+
+        let result = client.balances().current().await;
+        assert!(matches!(result, Err(Error::RateLimited { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_retried_create_resends_identical_body() {
+        let server = wiremock::MockServer::start().await;
+        mock_login(&server, "test_token").await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/transfers/create"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/transfers/create"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": "tfr_1"})),
+            )
+            .mount(&server)
+            .await;
+
+        let config = mock_config(&server).build().unwrap();
+        let client = Client::new(config).unwrap();
+        let request = crate::models::CreateTransferRequest::local(
+            "req_retry_1",
+            "ben_1",
+            "USD",
+            100.0,
+            "Invoice 42",
+        );
+        client.transfers().create(request).await.unwrap();
+
+        let bodies: Vec<Vec<u8>> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|request| request.url.path() == "/api/v1/transfers/create")
+            .map(|request| request.body)
+            .collect();
+        assert_eq!(bodies.len(), 2);
+        assert_eq!(bodies[0], bodies[1]);
+        let sent: serde_json::Value = serde_json::from_slice(&bodies[1]).unwrap();
+        assert_eq!(sent["request_id"], "req_retry_1");
+    }
+
+    #[tokio::test]
+    async fn test_timed_out_post_empty_is_not_resent() {
+        let server = wiremock::MockServer::start().await;
+        mock_login(&server, "test_token").await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/transfers/tfr_1/cancel"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(500)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/pa/payment_intents/int_1/capture"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let config = mock_config(&server)
+            .timeout(Duration::from_millis(100))
+            .retry_base_delay(Duration::from_millis(1))
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        let result = client
+            .post_empty::<serde_json::Value>("/api/v1/transfers/tfr_1/cancel")
+            .await;
+        assert!(
+            matches!(result, Err(Error::Http(ref e)) if e.is_timeout()),
+            "{:?}",
+            result
+        );
+        let result = client
+            .post_empty::<serde_json::Value>("/api/v1/pa/payment_intents/int_1/capture")
+            .await;
+        assert!(matches!(result, Err(Error::Api { .. })), "{:?}", result);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_count_requests_retries_and_errors() {
+        use std::collections::HashMap;
+        use std::sync::Mutex;
+
+        use crate::metrics::{Counter, Metrics};
+
+        #[derive(Default)]
+        struct Counting(Mutex<HashMap<String, u32>>);
+
+        impl Metrics for Counting {
+            fn increment(&self, counter: Counter<'_>) {
+                let key = match counter {
+                    Counter::Error { code } => format!("error:{}", code),
+                    other => format!("{:?}", other),
+                };
+                *self.0.lock().unwrap().entry(key).or_default() += 1;
+            }
+        }
+
+        let server = wiremock::MockServer::start().await;
+        mock_login(&server, "test_token").await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/current"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/current"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/transfers/tfr_1"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "code": "invalid_argument",
+                "message": "Invalid transfer id"
+            })))
+            .mount(&server)
+            .await;
+
+        let metrics = Arc::new(Counting::default());
+        let config = mock_config(&server)
+            .retry_base_delay(Duration::from_millis(1))
+            .metrics(Arc::clone(&metrics))
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        client.balances().current().await.unwrap();
+        assert!(client.transfers().get("tfr_1").await.is_err());
+
+        let counts = metrics.0.lock().unwrap();
+        assert_eq!(counts["Request"], 3);
+        assert_eq!(counts["Retry"], 1);
+        assert_eq!(counts["AuthRefresh"], 1);
+        assert_eq!(counts["error:invalid_argument"], 1);
+        assert_eq!(counts.len(), 4);
+    }
+}
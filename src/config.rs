@@ -1,13 +1,26 @@
 //! Configuration for the Airwallex API client.
 
+use std::sync::Arc;
 use std::time::Duration;
 
 use secrecy::{ExposeSecret, SecretString};
 
 use crate::error::{Error, Result};
+use crate::interceptor::RequestInterceptor;
+use crate::metrics::{Metrics, NoopMetrics};
+use crate::retry::RetryObserver;
+
+/// The API version used in production unless overridden.
+pub const PRODUCTION_API_VERSION: &str = "2024-09-27";
+
+/// The API version used in the sandbox unless overridden.
+pub const SANDBOX_API_VERSION: &str = "2024-09-27";
 
 /// The API version to use for requests.
-pub const DEFAULT_API_VERSION: &str = "2024-09-27";
+///
+/// Kept for compatibility; the default now depends on the environment, see
+/// [`Environment::default_api_version`].
+pub const DEFAULT_API_VERSION: &str = PRODUCTION_API_VERSION;
 
 /// Default request timeout.
 pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
@@ -15,6 +28,15 @@ pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 /// Default token refresh buffer (refresh token 5 minutes before expiry).
 pub const DEFAULT_TOKEN_REFRESH_BUFFER: Duration = Duration::from_secs(300);
 
+/// Default number of retries for transient failures.
+pub const DEFAULT_MAX_RETRIES: u32 = 2;
+
+/// Default delay before the first retry; later retries back off exponentially.
+pub const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Default lifetime of cached FX rates; see [`ConfigBuilder::rate_cache`].
+pub const DEFAULT_RATE_CACHE_TTL: Duration = Duration::from_secs(5);
+
 /// Environment (sandbox or production).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Environment {
@@ -33,6 +55,14 @@ impl Environment {
             Environment::Production => "https://api.airwallex.com",
         }
     }
+
+    /// Get the API version used for this environment unless overridden.
+    pub fn default_api_version(&self) -> &'static str {
+        match self {
+            Environment::Sandbox => SANDBOX_API_VERSION,
+            Environment::Production => PRODUCTION_API_VERSION,
+        }
+    }
 }
 
 impl std::str::FromStr for Environment {
@@ -69,6 +99,24 @@ pub struct Config {
     pub(crate) on_behalf_of: Option<String>,
     /// Optional account ID to log in as (for scoped API keys with multi-account access).
     pub(crate) login_as: Option<String>,
+    /// Optional base URL overriding the environment's default.
+    pub(crate) base_url: Option<String>,
+    /// Optional pre-issued bearer token that replaces the login flow.
+    pub(crate) static_token: Option<SecretString>,
+    /// Maximum number of retries for transient failures.
+    pub(crate) max_retries: u32,
+    /// Delay before the first retry.
+    pub(crate) retry_base_delay: Duration,
+    /// Optional callback notified before each retry.
+    pub(crate) retry_observer: Option<Arc<dyn RetryObserver>>,
+    /// Receives request, retry, auth and error counts.
+    pub(crate) metrics: Arc<dyn Metrics>,
+    /// How long FX rates are cached, if caching is enabled.
+    pub(crate) rate_cache_ttl: Option<Duration>,
+    /// Whether to request and decode compressed responses.
+    pub(crate) compression: bool,
+    /// Hooks run around every request, in registration order.
+    pub(crate) interceptors: Vec<Arc<dyn RequestInterceptor>>,
 }
 
 impl std::fmt::Debug for Config {
@@ -82,6 +130,17 @@ impl std::fmt::Debug for Config {
             .field("token_refresh_buffer", &self.token_refresh_buffer)
             .field("on_behalf_of", &self.on_behalf_of)
             .field("login_as", &self.login_as)
+            .field("base_url", &self.base_url)
+            .field(
+                "static_token",
+                &self.static_token.as_ref().map(|_| "[REDACTED]"),
+            )
+            .field("max_retries", &self.max_retries)
+            .field("retry_base_delay", &self.retry_base_delay)
+            .field("retry_observer", &self.retry_observer.is_some())
+            .field("rate_cache_ttl", &self.rate_cache_ttl)
+            .field("compression", &self.compression)
+            .field("interceptors", &self.interceptors.len())
             .finish()
     }
 }
@@ -100,57 +159,95 @@ impl Config {
     /// - `AIRWALLEX_PRODUCTION_CLIENT_ID` / `AIRWALLEX_PRODUCTION_API_KEY` (if environment is production)
     /// - `AIRWALLEX_ENVIRONMENT` - "sandbox" or "production" (default: "sandbox")
     pub fn from_env() -> Result<Self> {
+        Self::from_env_with_overrides(|builder| builder)
+    }
+
+    /// Load configuration from environment variables, then adjust it in code.
+    ///
+    /// `overrides` receives a builder set to the environment from
+    /// `AIRWALLEX_ENVIRONMENT` and may change any setting. Credentials and
+    /// `login_as` it leaves unset are then read from the same variables as
+    /// [`Config::from_env`], for the environment the overrides settled on, so
+    /// switching to production never sends sandbox credentials.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use airwallex_rs::{Config, Environment};
+    ///
+    /// let config = Config::from_env_with_overrides(|builder| {
+    ///     builder
+    ///         .environment(Environment::Production)
+    ///         .timeout(Duration::from_secs(10))
+    /// })?;
+    /// # Ok::<(), airwallex_rs::Error>(())
+    /// ```
+    pub fn from_env_with_overrides(
+        overrides: impl FnOnce(ConfigBuilder) -> ConfigBuilder,
+    ) -> Result<Self> {
         // Try to load .env file, but don't fail if it doesn't exist
         let _ = dotenvy::dotenv();
 
-        let environment: Environment = std::env::var("AIRWALLEX_ENVIRONMENT")
-            .unwrap_or_else(|_| "sandbox".to_string())
+        Self::builder_from_vars(|name| std::env::var(name).ok(), overrides)?.build()
+    }
+
+    /// Populate a builder from variables looked up with `var`.
+    ///
+    /// `overrides` runs first, so credentials are read for the environment it
+    /// chooses and only where it did not set them itself.
+    fn builder_from_vars(
+        var: impl Fn(&str) -> Option<String>,
+        overrides: impl FnOnce(ConfigBuilder) -> ConfigBuilder,
+    ) -> Result<ConfigBuilder> {
+        let environment: Environment = var("AIRWALLEX_ENVIRONMENT")
+            .unwrap_or_else(|| "sandbox".to_string())
             .parse()?;
+        let mut builder = overrides(Config::builder().environment(environment));
 
         // Try environment-specific vars first, then fall back to generic ones
-        let env_prefix = match environment {
+        let env_prefix = match builder.environment {
             Environment::Sandbox => "AIRWALLEX_SANDBOX",
             Environment::Production => "AIRWALLEX_PRODUCTION",
         };
 
-        let client_id = std::env::var(format!("{}_CLIENT_ID", env_prefix))
-            .or_else(|_| std::env::var("AIRWALLEX_CLIENT_ID"))
-            .map_err(|_| {
-                Error::Env(format!(
-                    "Neither {}_CLIENT_ID nor AIRWALLEX_CLIENT_ID is set",
-                    env_prefix
-                ))
-            })?;
-
-        let api_key = std::env::var(format!("{}_API_KEY", env_prefix))
-            .or_else(|_| std::env::var("AIRWALLEX_API_KEY"))
-            .map_err(|_| {
-                Error::Env(format!(
-                    "Neither {}_API_KEY nor AIRWALLEX_API_KEY is set",
-                    env_prefix
-                ))
-            })?;
-
-        // Optional: account ID to log in as (for scoped API keys with multi-account access)
-        let login_as = std::env::var(format!("{}_LOGIN_AS", env_prefix))
-            .or_else(|_| std::env::var("AIRWALLEX_LOGIN_AS"))
-            .ok();
+        if builder.client_id.is_none() {
+            let client_id = var(&format!("{}_CLIENT_ID", env_prefix))
+                .or_else(|| var("AIRWALLEX_CLIENT_ID"))
+                .ok_or_else(|| {
+                    Error::Env(format!(
+                        "Neither {}_CLIENT_ID nor AIRWALLEX_CLIENT_ID is set",
+                        env_prefix
+                    ))
+                })?;
+            builder = builder.client_id(client_id);
+        }
 
-        let mut builder = Config::builder()
-            .client_id(client_id)
-            .api_key(api_key)
-            .environment(environment);
+        if builder.api_key.is_none() {
+            let api_key = var(&format!("{}_API_KEY", env_prefix))
+                .or_else(|| var("AIRWALLEX_API_KEY"))
+                .ok_or_else(|| {
+                    Error::Env(format!(
+                        "Neither {}_API_KEY nor AIRWALLEX_API_KEY is set",
+                        env_prefix
+                    ))
+                })?;
+            builder = builder.api_key(api_key);
+        }
 
-        if let Some(account_id) = login_as {
-            builder = builder.login_as(account_id);
+        // Optional: account ID to log in as (for scoped API keys with multi-account access)
+        if builder.login_as.is_none() {
+            builder.login_as =
+                var(&format!("{}_LOGIN_AS", env_prefix)).or_else(|| var("AIRWALLEX_LOGIN_AS"));
         }
 
-        builder.build()
+        Ok(builder)
     }
 
-    /// Get the base URL for the configured environment.
+    /// Get the base URL, either the override or the configured environment's default.
     pub fn base_url(&self) -> &str {
-        self.environment.base_url()
+        self.base_url
+            .as_deref()
+            .unwrap_or_else(|| self.environment.base_url())
     }
 
     /// Get the API key (for internal use only).
@@ -170,6 +267,15 @@ pub struct ConfigBuilder {
     token_refresh_buffer: Option<Duration>,
     on_behalf_of: Option<String>,
     login_as: Option<String>,
+    base_url: Option<String>,
+    static_token: Option<SecretString>,
+    max_retries: Option<u32>,
+    retry_base_delay: Option<Duration>,
+    retry_observer: Option<Arc<dyn RetryObserver>>,
+    metrics: Option<Arc<dyn Metrics>>,
+    rate_cache_ttl: Option<Duration>,
+    compression: Option<bool>,
+    interceptors: Vec<Arc<dyn RequestInterceptor>>,
 }
 
 impl ConfigBuilder {
@@ -192,6 +298,10 @@ impl ConfigBuilder {
     }
 
     /// Set the API version to use.
+    ///
+    /// Airwallex versions are release dates (`YYYY-MM-DD`);
+    /// [`build`](Self::build) rejects anything else. Defaults to
+    /// [`Environment::default_api_version`].
     pub fn api_version(mut self, version: impl Into<String>) -> Self {
         self.api_version = Some(version.into());
         self
@@ -225,35 +335,164 @@ impl ConfigBuilder {
         self
     }
 
+    /// Override the base URL (e.g. for a proxy or a mock server).
+    ///
+    /// Defaults to the environment's URL.
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into().trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Use a pre-issued bearer token instead of logging in.
+    ///
+    /// The client never calls the authentication endpoint and never refreshes
+    /// the token; `client_id` and `api_key` become optional. If the API rejects
+    /// the token, requests fail with [`Error::TokenExpired`].
+    pub fn static_token(mut self, token: impl Into<String>) -> Self {
+        self.static_token = Some(SecretString::new(token.into().into()));
+        self
+    }
+
+    /// Set the maximum number of retries for transient failures.
+    ///
+    /// Rate limits, gateway errors and connection failures are retried with
+    /// exponential backoff. Gateway errors and timeouts are only retried for
+    /// `GET` requests and requests with a request ID or idempotency key, since
+    /// the first attempt may already have been applied. Set to 0 to disable
+    /// retries. See [`crate::retry`].
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = Some(retries);
+        self
+    }
+
+    /// Set the delay before the first retry.
+    pub fn retry_base_delay(mut self, delay: Duration) -> Self {
+        self.retry_base_delay = Some(delay);
+        self
+    }
+
+    /// Register a callback notified before each retry.
+    ///
+    /// Useful for logging or counting retries without a `tracing` subscriber.
+    pub fn retry_observer(mut self, observer: impl RetryObserver + 'static) -> Self {
+        self.retry_observer = Some(Arc::new(observer));
+        self
+    }
+
+    /// Register a hook run around every request.
+    ///
+    /// May be called more than once; see [`crate::interceptor`].
+    pub fn interceptor(mut self, interceptor: impl RequestInterceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Report request, retry, auth refresh and error counts to `metrics`.
+    ///
+    /// Defaults to [`NoopMetrics`]. See [`crate::metrics`].
+    pub fn metrics(mut self, metrics: impl Metrics + 'static) -> Self {
+        self.metrics = Some(Arc::new(metrics));
+        self
+    }
+
+    /// Cache FX rates for [`DEFAULT_RATE_CACHE_TTL`].
+    ///
+    /// See [`ConfigBuilder::rate_cache_ttl`].
+    pub fn rate_cache(self) -> Self {
+        self.rate_cache_ttl(DEFAULT_RATE_CACHE_TTL)
+    }
+
+    /// Cache FX rates for the given duration.
+    ///
+    /// [`Conversions::get_rate`](crate::resources::Conversions::get_rate)
+    /// then reuses a rate fetched for the same currency pair, amount, fixed
+    /// side and conversion date until it expires, so rapid refreshes make a
+    /// single API call. The cache is shared by clones of the client. A zero
+    /// duration disables caching, which is the default.
+    pub fn rate_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.rate_cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Enable or disable compressed responses.
+    ///
+    /// When enabled, the default, requests advertise gzip, deflate and brotli
+    /// support and compressed responses are decoded transparently. Disable it
+    /// if a proxy mishandles compressed bodies.
+    pub fn compression(mut self, enabled: bool) -> Self {
+        self.compression = Some(enabled);
+        self
+    }
+
     /// Build the configuration.
     pub fn build(self) -> Result<Config> {
-        let client_id = self
-            .client_id
-            .ok_or_else(|| Error::Config("client_id is required".to_string()))?;
+        let has_static_token = self.static_token.is_some();
+
+        let client_id = match self.client_id {
+            Some(client_id) => client_id,
+            None if has_static_token => String::new(),
+            None => return Err(Error::Config("client_id is required".to_string())),
+        };
+
+        let api_key = match self.api_key {
+            Some(api_key) => api_key,
+            None if has_static_token => SecretString::new(String::new().into()),
+            None => return Err(Error::Config("api_key is required".to_string())),
+        };
 
-        let api_key = self
-            .api_key
-            .ok_or_else(|| Error::Config("api_key is required".to_string()))?;
+        let api_version = self
+            .api_version
+            .unwrap_or_else(|| self.environment.default_api_version().to_string());
+        validate_api_version(&api_version)?;
 
         Ok(Config {
             client_id,
             api_key,
             environment: self.environment,
-            api_version: self
-                .api_version
-                .unwrap_or_else(|| DEFAULT_API_VERSION.to_string()),
+            api_version,
             timeout: self.timeout.unwrap_or(DEFAULT_TIMEOUT),
             token_refresh_buffer: self
                 .token_refresh_buffer
                 .unwrap_or(DEFAULT_TOKEN_REFRESH_BUFFER),
             on_behalf_of: self.on_behalf_of,
             login_as: self.login_as,
+            base_url: self.base_url,
+            static_token: self.static_token,
+            max_retries: self.max_retries.unwrap_or(DEFAULT_MAX_RETRIES),
+            retry_base_delay: self.retry_base_delay.unwrap_or(DEFAULT_RETRY_BASE_DELAY),
+            retry_observer: self.retry_observer,
+            metrics: self.metrics.unwrap_or_else(|| Arc::new(NoopMetrics)),
+            rate_cache_ttl: self.rate_cache_ttl.filter(|ttl| !ttl.is_zero()),
+            compression: self.compression.unwrap_or(true),
+            interceptors: self.interceptors,
         })
     }
 }
 
+/// Check that an API version is a `YYYY-MM-DD` date.
+///
+/// A well-formed date later than today cannot be a released version; it is
+/// allowed, since clocks can be wrong, but logged as a warning.
+pub(crate) fn validate_api_version(version: &str) -> Result<()> {
+    let date = chrono::NaiveDate::parse_from_str(version, "%Y-%m-%d")
+        .ok()
+        .filter(|_| version.len() == 10)
+        .ok_or_else(|| {
+            Error::Config(format!(
+                "api_version must be a YYYY-MM-DD date, got '{}'",
+                version
+            ))
+        })?;
+    if date > chrono::Utc::now().date_naive() {
+        tracing::warn!(api_version = version, "API version is dated in the future");
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use super::*;
 
     #[test]
@@ -276,6 +515,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_env_vars_with_overrides() {
+        let vars: HashMap<&str, &str> = [
+            ("AIRWALLEX_ENVIRONMENT", "sandbox"),
+            ("AIRWALLEX_SANDBOX_CLIENT_ID", "sandbox_client"),
+            ("AIRWALLEX_SANDBOX_API_KEY", "sandbox_key"),
+            ("AIRWALLEX_PRODUCTION_CLIENT_ID", "production_client"),
+            ("AIRWALLEX_API_KEY", "env_key"),
+        ]
+        .into_iter()
+        .collect();
+        let from_vars = |overrides: fn(ConfigBuilder) -> ConfigBuilder| {
+            Config::builder_from_vars(|name| vars.get(name).map(|v| v.to_string()), overrides)
+        };
+
+        let sandbox = from_vars(|builder| builder).unwrap().build().unwrap();
+        assert_eq!(sandbox.client_id, "sandbox_client");
+        assert_eq!(sandbox.api_key(), "sandbox_key");
+
+        // Credentials follow the overridden environment.
+        let production = from_vars(|builder| {
+            builder
+                .environment(Environment::Production)
+                .timeout(Duration::from_secs(5))
+        })
+        .unwrap()
+        .build()
+        .unwrap();
+        assert_eq!(production.client_id, "production_client");
+        assert_eq!(production.api_key(), "env_key");
+        assert_eq!(production.environment, Environment::Production);
+        assert_eq!(production.timeout, Duration::from_secs(5));
+
+        let explicit = from_vars(|builder| builder.client_id("code_client"))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(explicit.client_id, "code_client");
+        assert_eq!(explicit.api_key(), "sandbox_key");
+
+        let missing = Config::builder_from_vars(|_| None, |builder| builder);
+        assert!(matches!(missing, Err(Error::Env(_))));
+    }
+
     #[test]
     fn test_config_builder() {
         let config = Config::builder()
@@ -288,7 +571,64 @@ mod tests {
         assert_eq!(config.client_id, "test_client");
         assert_eq!(config.api_key(), "test_key");
         assert_eq!(config.environment, Environment::Sandbox);
-        assert_eq!(config.api_version, DEFAULT_API_VERSION);
+        assert_eq!(config.api_version, SANDBOX_API_VERSION);
+    }
+
+    #[test]
+    fn test_api_version_defaults_per_environment() {
+        let build = |environment| {
+            Config::builder()
+                .static_token("token")
+                .environment(environment)
+        };
+
+        for environment in [Environment::Sandbox, Environment::Production] {
+            let config = build(environment).build().unwrap();
+            assert_eq!(config.api_version, environment.default_api_version());
+        }
+
+        let pinned = build(Environment::Production)
+            .api_version("2025-02-14")
+            .build()
+            .unwrap();
+        assert_eq!(pinned.api_version, "2025-02-14");
+    }
+
+    #[test]
+    fn test_api_version_validation() {
+        let builder = || Config::builder().client_id("id").api_key("key");
+
+        let config = builder().api_version("2025-02-14").build().unwrap();
+        assert_eq!(config.api_version, "2025-02-14");
+
+        for version in ["v1", "2024-9-27", "2024-13-01", "2024-09-27T00:00:00Z", ""] {
+            let err = builder().api_version(version).build().unwrap_err();
+            assert!(
+                matches!(err, Error::Config(ref message) if message.contains("api_version")),
+                "{}: {:?}",
+                version,
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_config_base_url_override() {
+        let config = Config::builder()
+            .client_id("test_client")
+            .api_key("test_key")
+            .base_url("http://localhost:8080/")
+            .build()
+            .unwrap();
+
+        assert_eq!(config.base_url(), "http://localhost:8080");
+    }
+
+    #[test]
+    fn test_config_static_token_without_credentials() {
+        let config = Config::builder().static_token("injected").build().unwrap();
+        assert!(config.static_token.is_some());
+        assert!(!format!("{:?}", config).contains("injected"));
     }
 
     #[test]
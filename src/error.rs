@@ -2,9 +2,14 @@
 
 use std::time::Duration;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// The main error type for the Airwallex client.
+///
+/// Variants wrapping another error ([`Http`](Error::Http),
+/// [`Serialization`](Error::Serialization), [`Url`](Error::Url),
+/// [`ReissueIncomplete`](Error::ReissueIncomplete)) return it from
+/// [`std::error::Error::source`], so reporters can show the full cause.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     /// HTTP transport error from reqwest.
@@ -24,6 +29,17 @@ pub enum Error {
         details: Option<serde_json::Value>,
     },
 
+    /// An idempotency key was reused for a request with a different body.
+    ///
+    /// Send a genuinely new operation with a fresh key.
+    #[error("Idempotency key conflict: {message}")]
+    IdempotencyConflict {
+        /// Human-readable error message.
+        message: String,
+        /// Trace ID for debugging with Airwallex support.
+        trace_id: Option<String>,
+    },
+
     /// Rate limit exceeded (HTTP 429).
     #[error("Rate limit exceeded")]
     RateLimited {
@@ -31,10 +47,21 @@ pub enum Error {
         retry_after: Option<Duration>,
     },
 
-    /// Authentication failed.
+    /// The API answered a login or request with 401 Unauthorized.
     #[error("Authentication error: {0}")]
     Authentication(String),
 
+    /// The configured static access token was rejected (HTTP 401).
+    #[error("Static access token was rejected; it may have expired")]
+    TokenExpired,
+
+    /// The access token cannot act on behalf of the configured account.
+    #[error("Access token is not scoped for account {account_id}")]
+    ScopeMismatch {
+        /// The `on_behalf_of` account the token lacks access to.
+        account_id: String,
+    },
+
     /// Request validation failed.
     #[error("Validation error: {0}")]
     Validation(String),
@@ -58,26 +85,107 @@ pub enum Error {
     /// Environment variable error.
     #[error("Environment error: {0}")]
     Env(String),
+
+    /// A replacement card was created, but closing the old card failed.
+    ///
+    /// See [`IssuingCards::reissue`](crate::resources::IssuingCards::reissue).
+    /// The old card is still open; close it once the cause is fixed.
+    #[error("Created replacement card {new_card_id}, but closing the old card failed: {source}")]
+    ReissueIncomplete {
+        /// ID of the new card, empty if the API did not return one.
+        new_card_id: String,
+        /// Why the old card could not be closed.
+        #[source]
+        source: Box<Error>,
+    },
+
+    /// The client was shut down with [`Client::shutdown`](crate::Client::shutdown).
+    #[error("Client has been shut down")]
+    Shutdown,
 }
 
 /// API error response structure from Airwallex.
-#[derive(Debug, Deserialize)]
+///
+/// Get it back from an [`Error::Api`] with [`Error::to_api_response`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ApiErrorResponse {
     /// Error code.
     pub code: String,
     /// Error message.
     pub message: String,
     /// Trace ID for debugging.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trace_id: Option<String>,
     /// Additional details.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub details: Option<serde_json::Value>,
 }
 
+/// A classified Airwallex error code.
+///
+/// Derived from the `code` and `message` of an [`ApiErrorResponse`]; codes
+/// this client does not recognise are kept as [`ApiErrorCode::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiErrorCode {
+    /// The client ID or API key was rejected.
+    CredentialsInvalid,
+    /// The credentials or access token have expired.
+    CredentialsExpired,
+    /// The credentials lack the scope for the endpoint.
+    InsufficientPermissions,
+    /// The requested resource does not exist.
+    ResourceNotFound,
+    /// The request failed validation.
+    Validation,
+    /// The endpoint is not available in the requested API version.
+    UnsupportedApiVersion,
+    /// A code not known to this version of the client.
+    Unknown(String),
+}
+
+impl ApiErrorCode {
+    /// Classify an error code, using the message where the code alone is ambiguous.
+    pub fn from_code(code: &str, message: &str) -> Self {
+        let message = message.to_ascii_lowercase();
+        match code {
+            "unsupported_api_version" | "invalid_api_version" | "api_version_not_supported" => {
+                ApiErrorCode::UnsupportedApiVersion
+            }
+            "credentials_invalid" => ApiErrorCode::CredentialsInvalid,
+            "credentials_expired" => ApiErrorCode::CredentialsExpired,
+            _ if message.contains("api version") => ApiErrorCode::UnsupportedApiVersion,
+            "403" | "forbidden" | "insufficient_permissions" => {
+                ApiErrorCode::InsufficientPermissions
+            }
+            _ if message.contains("insufficient permissions") => {
+                ApiErrorCode::InsufficientPermissions
+            }
+            "not_found" | "resource_not_found" => ApiErrorCode::ResourceNotFound,
+            "validation_error" | "invalid_argument" | "field_required" => ApiErrorCode::Validation,
+            _ => ApiErrorCode::Unknown(code.to_string()),
+        }
+    }
+}
+
+/// Codes Airwallex uses for a key reused with a different request.
+const IDEMPOTENCY_CONFLICT_CODES: [&str; 2] = ["idempotency_key_conflict", "idempotency_conflict"];
+
 impl Error {
     /// Create an API error from a response body.
+    ///
+    /// Errors with an idempotency conflict code become
+    /// [`Error::IdempotencyConflict`]; every other error, including
+    /// validation errors about the key itself, stays [`Error::Api`].
     pub fn from_api_response(response: ApiErrorResponse) -> Self {
+        if IDEMPOTENCY_CONFLICT_CODES
+            .iter()
+            .any(|code| response.code.eq_ignore_ascii_case(code))
+        {
+            return Error::IdempotencyConflict {
+                message: response.message,
+                trace_id: response.trace_id,
+            };
+        }
         Error::Api {
             code: response.code,
             message: response.message,
@@ -86,6 +194,27 @@ impl Error {
         }
     }
 
+    /// The structured error response of an API error.
+    ///
+    /// Clones the fields of [`Error::Api`] into a new [`ApiErrorResponse`] on
+    /// every call; match on the variant to borrow them instead.
+    pub fn to_api_response(&self) -> Option<ApiErrorResponse> {
+        match self {
+            Error::Api {
+                code,
+                message,
+                trace_id,
+                details,
+            } => Some(ApiErrorResponse {
+                code: code.clone(),
+                message: message.clone(),
+                trace_id: trace_id.clone(),
+                details: details.clone(),
+            }),
+            _ => None,
+        }
+    }
+
     /// Check if this error is retryable.
     pub fn is_retryable(&self) -> bool {
         match self {
@@ -95,6 +224,75 @@ impl Error {
         }
     }
 
+    /// Check if this error means the credentials lack the scope for the endpoint.
+    ///
+    /// Airwallex reports missing API key scopes as "Insufficient permissions",
+    /// either as a 401 (surfaced as [`Error::Authentication`]) or a 403.
+    pub fn is_permission_denied(&self) -> bool {
+        self.api_error_code() == Some(ApiErrorCode::InsufficientPermissions)
+    }
+
+    /// Check if this error means the credentials themselves were rejected.
+    ///
+    /// True for invalid or expired credentials, a rejected static token, and
+    /// any other 401 that is not a missing scope. Other failures, such as a
+    /// login answered with a 5xx, are not auth failures.
+    pub fn is_auth_failure(&self) -> bool {
+        match self {
+            Error::TokenExpired => true,
+            Error::Authentication(_) => !self.is_permission_denied(),
+            _ => matches!(
+                self.api_error_code(),
+                Some(ApiErrorCode::CredentialsInvalid | ApiErrorCode::CredentialsExpired)
+            ),
+        }
+    }
+
+    /// The classified error code of an API or authentication error.
+    ///
+    /// Authentication errors are classified from the response body they
+    /// carry, or from their message if the body is not a JSON error.
+    pub fn api_error_code(&self) -> Option<ApiErrorCode> {
+        match self {
+            Error::Api { code, message, .. } => Some(ApiErrorCode::from_code(code, message)),
+            Error::Authentication(message) => {
+                let embedded = message.find('{').and_then(|start| {
+                    serde_json::from_str::<ApiErrorResponse>(&message[start..]).ok()
+                });
+                match embedded {
+                    Some(response) => {
+                        Some(ApiErrorCode::from_code(&response.code, &response.message))
+                    }
+                    None => match ApiErrorCode::from_code("", message) {
+                        ApiErrorCode::Unknown(_) => None,
+                        code => Some(code),
+                    },
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Check if the endpoint is not available in the configured API version.
+    ///
+    /// Retry with a supported version using
+    /// [`Client::with_api_version`](crate::Client::with_api_version).
+    pub fn is_unsupported_api_version(&self) -> bool {
+        self.api_error_code() == Some(ApiErrorCode::UnsupportedApiVersion)
+    }
+
+    /// A suggestion for resolving this error, if there is one.
+    pub fn hint(&self) -> Option<&'static str> {
+        if self.is_unsupported_api_version() {
+            Some(
+                "this endpoint is not available in the configured API version; \
+                 use Client::with_api_version or ConfigBuilder::api_version to select a supported one",
+            )
+        } else {
+            None
+        }
+    }
+
     /// Get the suggested retry delay for rate limited errors.
     pub fn retry_after(&self) -> Option<Duration> {
         match self {
@@ -106,3 +304,345 @@ impl Error {
 
 /// Result type alias for Airwallex operations.
 pub type Result<T> = std::result::Result<T, Error>;
+
+/// Extension methods for [`Result`].
+pub trait ResultExt<T> {
+    /// Map permission-denied errors to `Ok(None)`.
+    ///
+    /// Useful for skipping endpoints the API key is not scoped for. Any other
+    /// error is returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use airwallex_rs::{Client, ResultExt};
+    ///
+    /// # async fn example(client: &Client) -> airwallex_rs::Result<()> {
+    /// match client.balances().current().await.ok_or_skip_permissions()? {
+    ///     Some(balances) => println!("{} balances", balances.items.len()),
+    ///     None => println!("balances:read not granted, skipping"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn ok_or_skip_permissions(self) -> Result<Option<T>>;
+
+    /// Map [`Error::NotFound`] to an empty value.
+    ///
+    /// Some list endpoints return 404 when the feature is not provisioned for
+    /// the account rather than an empty list. All list responses implement
+    /// `Default`, so this turns that 404 into an empty page. Any other error
+    /// is returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use airwallex_rs::ResultExt;
+    /// use airwallex_rs::models::ListCardsParams;
+    ///
+    /// # async fn example(client: &airwallex_rs::Client) -> airwallex_rs::Result<()> {
+    /// let cards = client
+    ///     .issuing_cards()
+    ///     .list(&ListCardsParams::new())
+    ///     .await
+    ///     .ok_or_empty_if_not_found()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn ok_or_empty_if_not_found(self) -> Result<T>
+    where
+        T: Default;
+}
+
+impl<T> ResultExt<T> for Result<T> {
+    fn ok_or_skip_permissions(self) -> Result<Option<T>> {
+        match self {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.is_permission_denied() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn ok_or_empty_if_not_found(self) -> Result<T>
+    where
+        T: Default,
+    {
+        match self {
+            Err(Error::NotFound) => Ok(T::default()),
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source_chain(error: &dyn std::error::Error) -> Vec<String> {
+        let mut chain = Vec::new();
+        let mut source = error.source();
+        while let Some(cause) = source {
+            chain.push(cause.to_string());
+            source = cause.source();
+        }
+        chain
+    }
+
+    #[test]
+    fn test_source_chain() {
+        let http = reqwest::Client::new()
+            .get("http://[::1")
+            .build()
+            .unwrap_err();
+        let error = Error::from(http);
+        let chain = source_chain(&error);
+        assert!(chain.len() >= 2, "{:?}", chain);
+        assert_eq!(chain[0], "builder error");
+        assert!(
+            chain
+                .iter()
+                .any(|cause| cause.contains("invalid IPv6 address"))
+        );
+
+        let json = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+        let error = Error::from(json);
+        assert_eq!(source_chain(&error).len(), 1);
+
+        assert!(source_chain(&Error::NotFound).is_empty());
+    }
+
+    fn api_error(code: &str, message: &str) -> Error {
+        Error::Api {
+            code: code.to_string(),
+            message: message.to_string(),
+            trace_id: None,
+            details: None,
+        }
+    }
+
+    #[test]
+    fn test_api_response_round_trip() {
+        let payload = serde_json::json!({
+            "code": "invalid_argument",
+            "message": "beneficiary_id is invalid",
+            "trace_id": "f0e1d2c3",
+            "details": {"field": "beneficiary_id"}
+        });
+        let response: ApiErrorResponse = serde_json::from_value(payload.clone()).unwrap();
+        let error = Error::from_api_response(response.clone());
+
+        let recovered = error.to_api_response().unwrap();
+        assert_eq!(recovered, response);
+        assert_eq!(recovered.details.unwrap()["field"], "beneficiary_id");
+        assert_eq!(
+            serde_json::to_value(error.to_api_response()).unwrap(),
+            payload
+        );
+        assert!(Error::NotFound.to_api_response().is_none());
+    }
+
+    #[test]
+    fn test_unsupported_api_version_from_payload() {
+        let payload = r#"{
+            "code": "invalid_argument",
+            "message": "The API version 2024-09-27 is not supported for this endpoint. Please use API version 2025-02-14 or later.",
+            "trace_id": "b4c2e1f0"
+        }"#;
+        let response: ApiErrorResponse = serde_json::from_str(payload).unwrap();
+        let error = Error::from_api_response(response);
+
+        assert_eq!(
+            error.api_error_code(),
+            Some(ApiErrorCode::UnsupportedApiVersion)
+        );
+        assert!(error.is_unsupported_api_version());
+        assert!(error.hint().unwrap().contains("with_api_version"));
+
+        let other = api_error("invalid_argument", "amount is required");
+        assert_eq!(other.api_error_code(), Some(ApiErrorCode::Validation));
+        assert!(!other.is_unsupported_api_version());
+        assert!(other.hint().is_none());
+        assert!(!Error::NotFound.is_unsupported_api_version());
+    }
+
+    #[test]
+    fn test_idempotency_conflict_only_for_conflict_codes() {
+        let conflict = from_body(
+            r#"{"code": "idempotency_key_conflict", "message": "Idempotency key was used with a different request"}"#,
+        );
+        assert!(matches!(conflict, Error::IdempotencyConflict { .. }));
+
+        let invalid = from_body(
+            r#"{"code": "validation_error", "message": "idempotency key must be at most 64 characters", "details": {"field": "idempotency-key"}}"#,
+        );
+        assert_eq!(invalid.api_error_code(), Some(ApiErrorCode::Validation));
+        assert_eq!(
+            invalid.to_api_response().unwrap().details.unwrap()["field"],
+            "idempotency-key"
+        );
+    }
+
+    fn from_body(body: &str) -> Error {
+        Error::from_api_response(serde_json::from_str(body).unwrap())
+    }
+
+    #[test]
+    fn test_error_codes_from_payloads() {
+        let cases = [
+            (
+                r#"{"code": "credentials_expired", "message": "Access token has expired"}"#,
+                ApiErrorCode::CredentialsExpired,
+            ),
+            (
+                r#"{"code": "resource_not_found", "message": "The requested resource was not found", "trace_id": "9f1c2d3e"}"#,
+                ApiErrorCode::ResourceNotFound,
+            ),
+            (
+                r#"{"code": "validation_error", "source": "amount", "message": "amount must be greater than 0"}"#,
+                ApiErrorCode::Validation,
+            ),
+            (
+                r#"{"code": "forbidden", "message": "Insufficient permissions"}"#,
+                ApiErrorCode::InsufficientPermissions,
+            ),
+            (
+                r#"{"code": "already_exists", "message": "request_id has been used"}"#,
+                ApiErrorCode::Unknown("already_exists".to_string()),
+            ),
+        ];
+        for (body, expected) in cases {
+            assert_eq!(from_body(body).api_error_code(), Some(expected), "{}", body);
+        }
+
+        let expired =
+            from_body(r#"{"code": "credentials_expired", "message": "Access token has expired"}"#);
+        assert!(expired.is_auth_failure());
+        assert!(!expired.is_permission_denied());
+        assert!(
+            !from_body(r#"{"code": "validation_error", "message": "amount is required"}"#)
+                .is_auth_failure()
+        );
+        assert_eq!(Error::NotFound.api_error_code(), None);
+    }
+
+    #[test]
+    fn test_authentication_error_codes() {
+        let login = Error::Authentication(
+            r#"Invalid credentials: {"code":"credentials_invalid","message":"Invalid client_id or api_key"}"#
+                .to_string(),
+        );
+        assert_eq!(
+            login.api_error_code(),
+            Some(ApiErrorCode::CredentialsInvalid)
+        );
+        assert!(login.is_auth_failure());
+        assert!(!login.is_permission_denied());
+
+        let scope = Error::Authentication(
+            r#"Request unauthorized: {"code":"unauthorized","message":"Insufficient permissions"}"#
+                .to_string(),
+        );
+        assert_eq!(
+            scope.api_error_code(),
+            Some(ApiErrorCode::InsufficientPermissions)
+        );
+        assert!(scope.is_permission_denied());
+        assert!(!scope.is_auth_failure());
+
+        let rejected = Error::Authentication(
+            r#"Request unauthorized: {"code":"unauthorized","message":"Access denied"}"#
+                .to_string(),
+        );
+        assert_eq!(
+            rejected.api_error_code(),
+            Some(ApiErrorCode::Unknown("unauthorized".to_string()))
+        );
+        assert!(rejected.is_auth_failure());
+
+        let plain = Error::Api {
+            code: "500".to_string(),
+            message: "oops".to_string(),
+            trace_id: None,
+            details: None,
+        };
+        assert!(!plain.is_auth_failure());
+        let expired = from_body(r#"{"code": "credentials_expired", "message": "Expired"}"#);
+        assert!(expired.is_auth_failure());
+        assert!(Error::TokenExpired.is_auth_failure());
+        assert!(!Error::TokenExpired.is_retryable());
+        assert!(Error::RateLimited { retry_after: None }.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_empty_if_not_found() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let (server, client) = crate::testing::mock::mock_client().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/payers"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "code": "not_found",
+                "message": "Payers are not enabled for this account"
+            })))
+            .mount(&server)
+            .await;
+
+        let payers = client
+            .payers()
+            .list(crate::models::ListPayersParams::new())
+            .await
+            .ok_or_empty_if_not_found()
+            .unwrap();
+        assert!(payers.items.is_empty());
+        assert!(!payers.has_more);
+
+        let result: Result<crate::models::ListPayersResponse> =
+            Err(api_error("invalid_argument", "page_size is too large"));
+        assert!(matches!(
+            result.ok_or_empty_if_not_found(),
+            Err(Error::Api { .. })
+        ));
+    }
+
+    #[test]
+    fn test_skip_permissions_ok() {
+        let result: Result<i32> = Ok(42);
+        assert_eq!(result.ok_or_skip_permissions().unwrap(), Some(42));
+    }
+
+    #[test]
+    fn test_skip_permissions_denied() {
+        let result: Result<i32> = Err(Error::Authentication(
+            "Request unauthorized: {\"code\":\"unauthorized\",\"message\":\"Insufficient permissions\"}"
+                .to_string(),
+        ));
+        assert_eq!(result.ok_or_skip_permissions().unwrap(), None);
+
+        let result: Result<i32> = Err(api_error("forbidden", "Access denied"));
+        assert_eq!(result.ok_or_skip_permissions().unwrap(), None);
+    }
+
+    #[test]
+    fn test_skip_permissions_other_errors() {
+        let result: Result<i32> = Err(Error::Authentication(
+            "Invalid credentials: credentials_invalid".to_string(),
+        ));
+        assert!(matches!(
+            result.ok_or_skip_permissions(),
+            Err(Error::Authentication(_))
+        ));
+
+        let result: Result<i32> = Err(api_error("invalid_argument", "amount is required"));
+        assert!(matches!(
+            result.ok_or_skip_permissions(),
+            Err(Error::Api { .. })
+        ));
+
+        let result: Result<i32> = Err(Error::NotFound);
+        assert!(matches!(
+            result.ok_or_skip_permissions(),
+            Err(Error::NotFound)
+        ));
+    }
+}
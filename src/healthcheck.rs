@@ -0,0 +1,144 @@
+//! Probing which API scopes the credentials grant.
+//!
+//! See [`Client::healthcheck_scopes`].
+
+use std::collections::BTreeMap;
+
+use futures_util::{StreamExt, stream};
+
+use crate::client::Client;
+use crate::error::Error;
+
+/// Maximum number of probes in flight at once.
+pub(crate) const PROBE_CONCURRENCY: usize = 4;
+
+/// The read endpoint probed for each scope.
+///
+/// List endpoints are asked for a single item, so each probe is one cheap
+/// request.
+pub(crate) const SCOPE_PROBES: [(&str, &str); 15] = [
+    ("balances:read", "/api/v1/balances/current"),
+    ("global_accounts:read", "/api/v1/global_accounts"),
+    ("beneficiaries:read", "/api/v1/beneficiaries"),
+    ("transfers:read", "/api/v1/transfers"),
+    ("conversions:read", "/api/v1/fx/conversions"),
+    ("deposits:read", "/api/v1/deposits"),
+    ("linked_accounts:read", "/api/v1/linked_accounts"),
+    (
+        "financial_transactions:read",
+        "/api/v1/financial_transactions",
+    ),
+    ("invoices:read", "/api/v1/invoices"),
+    ("customers:read", "/api/v1/pa/customers"),
+    ("payment_intents:read", "/api/v1/pa/payment_intents"),
+    ("refunds:read", "/api/v1/pa/refunds"),
+    ("issuing_cards:read", "/api/v1/issuing/cards"),
+    ("issuing_cardholders:read", "/api/v1/issuing/cardholders"),
+    ("issuing_transactions:read", "/api/v1/issuing/transactions"),
+];
+
+/// Whether the credentials grant an API scope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeStatus {
+    /// The probe succeeded.
+    Available,
+    /// The API rejected the probe with "Insufficient permissions".
+    Denied,
+    /// The probe failed for another reason, such as a network error, a rate
+    /// limit or the product not being provisioned.
+    Unknown,
+}
+
+impl ScopeStatus {
+    /// Classify the outcome of a probe.
+    pub(crate) fn from_result<T>(result: &Result<T, Error>) -> Self {
+        match result {
+            Ok(_) => ScopeStatus::Available,
+            Err(e) if e.is_permission_denied() => ScopeStatus::Denied,
+            Err(_) => ScopeStatus::Unknown,
+        }
+    }
+}
+
+/// Probe every scope in [`SCOPE_PROBES`] with `client`.
+pub(crate) async fn probe_scopes(client: &Client) -> BTreeMap<&'static str, ScopeStatus> {
+    stream::iter(SCOPE_PROBES)
+        .map(|(scope, path)| async move {
+            let result = client
+                .get_with_query::<serde_json::Value, _>(path, &[("page_size", 1)])
+                .await;
+            if let Err(e) = &result {
+                tracing::debug!(scope, error = %e, "scope probe failed");
+            }
+            (scope, ScopeStatus::from_result(&result))
+        })
+        .buffer_unordered(PROBE_CONCURRENCY)
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::mock::mock_client;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_healthcheck_scopes_reports_mixed_permissions() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/current"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/transfers"))
+            .and(query_param("page_size", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "has_more": false,
+                "items": []
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/pa/customers"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "code": "unauthorized",
+                "message": "Insufficient permissions"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issuing/cards"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "code": "forbidden",
+                "message": "Insufficient permissions"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/fx/conversions"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let report = client.healthcheck_scopes().await;
+
+        assert_eq!(report.len(), SCOPE_PROBES.len());
+        assert_eq!(report["balances:read"], ScopeStatus::Available);
+        assert_eq!(report["transfers:read"], ScopeStatus::Available);
+        assert_eq!(report["customers:read"], ScopeStatus::Denied);
+        assert_eq!(report["issuing_cards:read"], ScopeStatus::Denied);
+        // Not retried, so a single 503 leaves the scope unknown.
+        assert_eq!(report["conversions:read"], ScopeStatus::Unknown);
+        // Unmounted endpoints return 404.
+        assert_eq!(report["deposits:read"], ScopeStatus::Unknown);
+    }
+}
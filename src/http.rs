@@ -0,0 +1,114 @@
+//! The HTTP layer used by [`Client`](crate::Client).
+//!
+//! Requests are built with `reqwest` and executed through an [`HttpBackend`],
+//! which by default sends them over the network. Tests can substitute a
+//! backend that returns scripted responses (`testing::MockBackend`, behind
+//! the `testing` feature).
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+#[cfg(any(test, feature = "testing"))]
+use reqwest::header::{HeaderName, HeaderValue};
+
+use crate::error::Result;
+
+/// A fully read HTTP response.
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    /// Response status.
+    pub status: StatusCode,
+    /// Response headers.
+    pub headers: HeaderMap,
+    /// Response body.
+    pub body: Vec<u8>,
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl RawResponse {
+    /// Create a response with the given status and body.
+    pub fn new(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Create a response with a JSON body.
+    pub fn json(status: StatusCode, body: &serde_json::Value) -> Self {
+        Self::new(status, body.to_string())
+            .header("content-type", HeaderValue::from_static("application/json"))
+    }
+
+    /// Add a header.
+    pub fn header(mut self, name: &'static str, value: HeaderValue) -> Self {
+        self.headers.insert(HeaderName::from_static(name), value);
+        self
+    }
+}
+
+/// Response headers carrying the Airwallex trace ID, in order of preference.
+const TRACE_ID_HEADERS: [&str; 2] = ["x-awx-traceid", "x-awx-trace-id"];
+
+impl RawResponse {
+    /// The trace ID Airwallex assigned to the request, if it sent one.
+    pub fn trace_id(&self) -> Option<&str> {
+        TRACE_ID_HEADERS
+            .iter()
+            .find_map(|name| self.headers.get(*name))
+            .and_then(|value| value.to_str().ok())
+    }
+
+    /// The body as text, replacing invalid UTF-8.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Read a `reqwest` response in full.
+    pub(crate) async fn read(response: reqwest::Response) -> Result<Self> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?.to_vec();
+        Ok(Self {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+/// Executes HTTP requests for the client.
+#[async_trait]
+pub trait HttpBackend: Send + Sync + std::fmt::Debug {
+    /// Send a request and read the whole response.
+    async fn execute(&self, request: reqwest::Request) -> Result<RawResponse>;
+}
+
+#[async_trait]
+impl<T: HttpBackend + ?Sized> HttpBackend for std::sync::Arc<T> {
+    async fn execute(&self, request: reqwest::Request) -> Result<RawResponse> {
+        (**self).execute(request).await
+    }
+}
+
+/// The default backend, sending requests with `reqwest`.
+#[derive(Debug, Clone)]
+pub(crate) struct ReqwestBackend {
+    client: reqwest::Client,
+}
+
+impl ReqwestBackend {
+    /// Create a backend using the given `reqwest` client.
+    pub(crate) fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl HttpBackend for ReqwestBackend {
+    async fn execute(&self, request: reqwest::Request) -> Result<RawResponse> {
+        let response = self.client.execute(request).await?;
+        RawResponse::read(response).await
+    }
+}
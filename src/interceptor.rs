@@ -0,0 +1,170 @@
+//! Hooks for inspecting and changing outgoing requests.
+//!
+//! Implement [`RequestInterceptor`] and register it with
+//! [`ConfigBuilder::interceptor`](crate::ConfigBuilder::interceptor) to add
+//! correlation headers, log requests or record timings. Interceptors run for
+//! every attempt, including retries, but not for logins.
+
+use std::time::Duration;
+
+pub use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use serde_json::Value;
+
+use crate::models::payment_methods::mask_card_number;
+
+/// An outgoing request, as seen by a [`RequestInterceptor`].
+///
+/// The `authorization` header is withheld, so interceptors never see the
+/// access token, and card details are masked in the body.
+#[derive(Debug)]
+pub struct RequestParts {
+    method: Method,
+    path: String,
+    body: Option<Vec<u8>>,
+    /// Request headers, without `authorization`.
+    ///
+    /// Headers may be added, changed or removed, except those the client
+    /// manages itself (see [`Client::with_headers`](crate::Client::with_headers)),
+    /// whose changes are ignored.
+    pub headers: HeaderMap,
+}
+
+impl RequestParts {
+    pub(crate) fn new(
+        method: Method,
+        path: String,
+        headers: HeaderMap,
+        body: Option<&[u8]>,
+    ) -> Self {
+        Self {
+            method,
+            path,
+            body: body.and_then(redact_body),
+            headers,
+        }
+    }
+
+    /// HTTP method.
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Request path, without the base URL.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Request body, if any.
+    ///
+    /// A copy of the JSON body with the `number` of every `card` object
+    /// masked down to its last four digits and every `cvc` replaced with
+    /// `***`. Bodies that are not JSON are withheld. Changes to it are not
+    /// sent.
+    pub fn body(&self) -> Option<&[u8]> {
+        self.body.as_deref()
+    }
+}
+
+/// Copy a JSON request body with its card details masked.
+fn redact_body(body: &[u8]) -> Option<Vec<u8>> {
+    let mut value: Value = serde_json::from_slice(body).ok()?;
+    redact_value(&mut value);
+    serde_json::to_vec(&value).ok()
+}
+
+/// Mask card numbers and CVCs anywhere in a JSON value.
+fn redact_value(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match (key.as_str(), &mut *value) {
+                    ("cvc", Value::String(cvc)) => *cvc = "***".to_string(),
+                    ("card", Value::Object(card)) => {
+                        if let Some(Value::String(number)) = card.get_mut("number") {
+                            *number = mask_card_number(number);
+                        }
+                        redact_value(value);
+                    }
+                    _ => redact_value(value),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+/// The outcome of a request, as seen by a [`RequestInterceptor`].
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// HTTP method.
+    pub method: Method,
+    /// Request path, without the base URL.
+    pub path: String,
+    /// Response status, or `None` if no response was received.
+    pub status: Option<StatusCode>,
+    /// How long the request took, including reading the body.
+    pub elapsed: Duration,
+}
+
+/// Inspects outgoing requests and their outcomes.
+///
+/// Both methods default to doing nothing, so an implementation only overrides
+/// what it needs. With several interceptors, `before` runs in registration
+/// order and `after` in reverse.
+///
+/// ```
+/// use airwallex_rs::Config;
+/// use airwallex_rs::interceptor::{RequestInterceptor, RequestParts, async_trait};
+/// use reqwest::header::HeaderValue;
+///
+/// struct Correlation;
+///
+/// #[async_trait]
+/// impl RequestInterceptor for Correlation {
+///     async fn before(&self, request: &mut RequestParts) {
+///         request
+///             .headers
+///             .insert("x-correlation-id", HeaderValue::from_static("batch-42"));
+///     }
+/// }
+///
+/// let config = Config::builder()
+///     .client_id("id")
+///     .api_key("key")
+///     .interceptor(Correlation)
+///     .build();
+/// ```
+#[async_trait]
+pub trait RequestInterceptor: Send + Sync {
+    /// Called before a request is sent.
+    async fn before(&self, request: &mut RequestParts) {
+        let _ = request;
+    }
+
+    /// Called once a response has been read, or the request failed.
+    async fn after(&self, response: &ResponseMeta) {
+        let _ = response;
+    }
+}
+
+/// A [`RequestInterceptor`] that logs the latency of every request.
+///
+/// Logs at `INFO` level with the method, path, status and elapsed time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LatencyLogger;
+
+#[async_trait]
+impl RequestInterceptor for LatencyLogger {
+    async fn after(&self, response: &ResponseMeta) {
+        tracing::info!(
+            method = %response.method,
+            path = %response.path,
+            status = response.status.map(|status| status.as_u16()),
+            elapsed_ms = response.elapsed.as_millis() as u64,
+            "request completed"
+        );
+    }
+}
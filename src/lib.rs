@@ -58,14 +58,24 @@
 #![allow(clippy::result_large_err)]
 
 pub mod auth;
+mod cache;
 pub mod client;
 pub mod config;
 pub mod error;
+pub mod healthcheck;
+mod http;
+pub mod interceptor;
+pub mod metrics;
 pub mod models;
 pub mod resources;
+pub mod retry;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod webhooks;
 
 // Re-export main types at crate root
-pub use client::Client;
+pub use cache::CacheStatus;
+pub use client::{ApiResponse, Client, ClientScope, RequestOptions};
 pub use config::{Config, ConfigBuilder, Environment};
-pub use error::{Error, Result};
+pub use error::{ApiErrorCode, ApiErrorResponse, Error, Result, ResultExt};
+pub use healthcheck::ScopeStatus;
@@ -0,0 +1,124 @@
+//! Counters and timings for monitoring the client.
+//!
+//! Implement [`Metrics`] to forward them to a metrics backend and register it
+//! with [`ConfigBuilder::metrics`](crate::ConfigBuilder::metrics). Without
+//! one, [`NoopMetrics`] discards everything.
+
+use std::time::Duration;
+
+use crate::error::Error;
+
+/// A counter incremented by the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Counter<'a> {
+    /// An HTTP request was sent, counting each retry attempt separately.
+    Request,
+    /// A request is being retried after a transient failure.
+    Retry,
+    /// A new access token was obtained by logging in.
+    AuthRefresh,
+    /// A request failed; see [`error_code`] for the codes used.
+    Error {
+        /// The API error code, or a name for errors without one.
+        code: &'a str,
+    },
+}
+
+/// Receives counters and timings from the client.
+///
+/// All methods default to doing nothing, so an implementation only overrides
+/// what it needs. Methods are called on the request path and should not
+/// block.
+///
+/// ```
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// use airwallex_rs::Config;
+/// use airwallex_rs::metrics::{Counter, Metrics};
+///
+/// #[derive(Default)]
+/// struct Retries(AtomicU64);
+///
+/// impl Metrics for Retries {
+///     fn increment(&self, counter: Counter<'_>) {
+///         if counter == Counter::Retry {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+///
+/// let config = Config::builder()
+///     .client_id("id")
+///     .api_key("key")
+///     .metrics(Retries::default())
+///     .build();
+/// ```
+pub trait Metrics: Send + Sync {
+    /// Increment a counter by one.
+    fn increment(&self, counter: Counter<'_>) {
+        let _ = counter;
+    }
+
+    /// Record how long a single HTTP request took, including reading the body.
+    fn record_request_duration(&self, method: &reqwest::Method, path: &str, duration: Duration) {
+        let _ = (method, path, duration);
+    }
+}
+
+impl<T: Metrics + ?Sized> Metrics for std::sync::Arc<T> {
+    fn increment(&self, counter: Counter<'_>) {
+        (**self).increment(counter)
+    }
+
+    fn record_request_duration(&self, method: &reqwest::Method, path: &str, duration: Duration) {
+        (**self).record_request_duration(method, path, duration)
+    }
+}
+
+/// A [`Metrics`] implementation that discards everything.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {}
+
+/// The code an error is counted under.
+///
+/// API errors use the code returned by Airwallex; other errors use a fixed
+/// name such as `not_found`, `rate_limited` or `http`.
+pub fn error_code(error: &Error) -> &str {
+    match error {
+        Error::Http(_) => "http",
+        Error::Api { code, .. } => code,
+        Error::IdempotencyConflict { .. } => "idempotency_conflict",
+        Error::RateLimited { .. } => "rate_limited",
+        Error::Authentication(_) => "authentication",
+        Error::TokenExpired => "token_expired",
+        Error::ScopeMismatch { .. } => "scope_mismatch",
+        Error::Validation(_) => "validation",
+        Error::NotFound => "not_found",
+        Error::Serialization(_) => "serialization",
+        Error::Config(_) => "config",
+        Error::Url(_) => "url",
+        Error::Env(_) => "env",
+        Error::ReissueIncomplete { .. } => "reissue_incomplete",
+        Error::Shutdown => "shutdown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_code() {
+        let error = Error::Api {
+            code: "insufficient_fund".to_string(),
+            message: "Not enough funds".to_string(),
+            trace_id: None,
+            details: None,
+        };
+        assert_eq!(error_code(&error), "insufficient_fund");
+        assert_eq!(error_code(&Error::NotFound), "not_found");
+    }
+}
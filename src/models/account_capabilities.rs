@@ -5,15 +5,20 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::common::Timestamp;
+
 /// Status of a capability.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CapabilityStatus {
     /// Capability is enabled.
+    #[serde(alias = "enabled")]
     Enabled,
     /// Capability is disabled.
+    #[serde(alias = "disabled")]
     Disabled,
     /// Capability is pending approval.
+    #[serde(alias = "pending")]
     Pending,
 }
 
@@ -22,8 +27,10 @@ pub enum CapabilityStatus {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum EntityType {
     /// Individual entity.
+    #[serde(alias = "individual")]
     Individual,
     /// Business entity.
+    #[serde(alias = "business")]
     Business,
 }
 
@@ -32,8 +39,10 @@ pub enum EntityType {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum FundingLimitType {
     /// Direct debit deposit.
+    #[serde(alias = "direct_debit_deposit")]
     DirectDebitDeposit,
     /// Faster direct debit deposit.
+    #[serde(alias = "faster_direct_debit_deposit")]
     FasterDirectDebitDeposit,
 }
 
@@ -42,9 +51,14 @@ pub enum FundingLimitType {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum FundingLimitStatus {
     /// Limit is active.
+    #[serde(alias = "active")]
     Active,
     /// Limit is requested.
+    #[serde(alias = "requested")]
     Requested,
+    /// A status not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Details about a capability's status.
@@ -75,7 +89,8 @@ pub struct AccountCapability {
     pub comment: Option<String>,
     /// Last update time.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// A funding limit request.
@@ -146,6 +161,9 @@ pub struct FundingLimit {
     /// Status of the limit.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<FundingLimitStatus>,
+    /// Amount of the limit still available.
+    #[serde(alias = "remaining", skip_serializing_if = "Option::is_none")]
+    pub available: Option<f64>,
     /// Available limits by scheme (e.g., SAME_DAY_ACH, BACS, etc.).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub availables: Option<HashMap<String, f64>>,
@@ -154,10 +172,25 @@ pub struct FundingLimit {
     pub mandate_type: Option<String>,
     /// Effective timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub effective_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub effective_at: Option<Timestamp>,
     /// Last update time.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
+}
+
+impl FundingLimit {
+    /// Fraction of the limit used, from `0.0` upwards.
+    ///
+    /// Derived from `limit` and `available`. Returns `0.0` when either is
+    /// missing or the limit is zero.
+    pub fn utilization(&self) -> f64 {
+        match (self.limit, self.available) {
+            (Some(limit), Some(available)) if limit > 0.0 => (limit - available).max(0.0) / limit,
+            _ => 0.0,
+        }
+    }
 }
 
 /// Parameters for listing funding limits.
@@ -218,12 +251,56 @@ impl ListFundingLimitsParams {
 }
 
 /// Response for listing funding limits.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListFundingLimitsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of funding limits.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<FundingLimit>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_funding_limit() {
+        let limit: FundingLimit = serde_json::from_value(serde_json::json!({
+            "currency": "USD",
+            "limit": 10000,
+            "type": "DIRECT_DEBIT_DEPOSIT",
+            "status": "ACTIVE",
+            "available": 7500
+        }))
+        .unwrap();
+        assert_eq!(limit.status, Some(FundingLimitStatus::Active));
+        assert_eq!(limit.available, Some(7500.0));
+        assert_eq!(limit.utilization(), 0.25);
+
+        let limit: FundingLimit = serde_json::from_value(serde_json::json!({
+            "limit": 1000,
+            "status": "SUSPENDED",
+            "remaining": 100
+        }))
+        .unwrap();
+        assert_eq!(
+            limit.status,
+            Some(FundingLimitStatus::Unknown("SUSPENDED".to_string()))
+        );
+        assert_eq!(limit.utilization(), 0.9);
+
+        let limit: FundingLimit = serde_json::from_value(serde_json::json!({
+            "limit": 1000
+        }))
+        .unwrap();
+        assert_eq!(limit.utilization(), 0.0);
+    }
 }
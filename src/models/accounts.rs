@@ -5,6 +5,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::common::{Timestamp, capped_page_size};
+
 /// An Airwallex account.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Account {
@@ -43,7 +45,8 @@ pub struct Account {
     pub reactivate_details: Option<Value>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// View link for hosted onboarding.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub view_link: Option<String>,
@@ -219,26 +222,82 @@ impl ListAccountsParams {
         self
     }
 
+    /// Filter by a metadata entry.
+    ///
+    /// The accounts endpoint takes a single `key:value` pair rather than
+    /// `metadata.<key>` parameters, so a later call replaces an earlier one.
+    pub fn metadata(mut self, key: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        self.metadata = Some(format!("{}:{}", key.as_ref(), value.as_ref()));
+        self
+    }
+
     /// Set page number.
     pub fn page_num(mut self, num: i32) -> Self {
         self.page_num = Some(num);
         self
     }
 
-    /// Set page size.
+    /// Set page size, capped at 500.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 500));
         self
     }
 }
 
 /// Response for listing accounts.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListAccountsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of accounts.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<Account>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
+
+/// Which product areas are enabled for the account.
+///
+/// Returned by [`Accounts::capabilities`](crate::resources::Accounts::capabilities).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountCapabilitiesSummary {
+    /// Card issuing.
+    pub issuing: bool,
+    /// Scale (connected accounts).
+    pub scale: bool,
+    /// Payment acceptance.
+    pub payments: bool,
+    /// Payouts.
+    pub transfers: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_capabilities_summary() {
+        let summary: AccountCapabilitiesSummary = serde_json::from_str(
+            r#"{"issuing": false, "scale": true, "payments": true, "transfers": true}"#,
+        )
+        .unwrap();
+        assert!(!summary.issuing);
+        assert!(summary.scale && summary.payments && summary.transfers);
+
+        let partial: AccountCapabilitiesSummary =
+            serde_json::from_str(r#"{"transfers": true}"#).unwrap();
+        assert_eq!(
+            partial,
+            AccountCapabilitiesSummary {
+                transfers: true,
+                ..Default::default()
+            }
+        );
+    }
 }
@@ -0,0 +1,402 @@
+//! Numeric amounts.
+//!
+//! [`Amount`] is an `f64` by default. Enable the `decimal` feature to back it
+//! with [`rust_decimal::Decimal`] instead, so amounts keep the exact digits
+//! the API sent and sums don't drift.
+
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+#[cfg(feature = "decimal")]
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::{Error, Result};
+
+#[cfg(feature = "decimal")]
+type Repr = Decimal;
+#[cfg(not(feature = "decimal"))]
+type Repr = f64;
+
+#[cfg(feature = "decimal")]
+const REPR_ZERO: Repr = Decimal::ZERO;
+#[cfg(not(feature = "decimal"))]
+const REPR_ZERO: Repr = 0.0;
+
+/// A monetary amount, without its currency.
+///
+/// Deserializes from a JSON number or a numeric string such as `"10.02"`, and
+/// serializes as a JSON number. With the `decimal` feature, both keep every
+/// digit: amounts an `f64` cannot hold exactly are written as JSON numbers
+/// with their full digits.
+///
+/// ```
+/// use airwallex_rs::models::Amount;
+///
+/// let total: Amount = ["10.01", "0.01"].iter().map(|a| a.parse::<Amount>().unwrap()).sum();
+/// assert_eq!(total.to_f64(), 10.02);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Amount(Repr);
+
+impl Amount {
+    /// Zero.
+    pub const ZERO: Amount = Amount(REPR_ZERO);
+
+    /// The amount as an `f64`.
+    pub fn to_f64(self) -> f64 {
+        #[cfg(feature = "decimal")]
+        {
+            self.0.to_f64().unwrap_or_default()
+        }
+        #[cfg(not(feature = "decimal"))]
+        {
+            self.0
+        }
+    }
+
+    /// The amount as a [`Decimal`].
+    #[cfg(feature = "decimal")]
+    pub fn to_decimal(self) -> Decimal {
+        self.0
+    }
+
+    /// Whether the amount is zero.
+    pub fn is_zero(self) -> bool {
+        self.0 == REPR_ZERO
+    }
+
+    /// Whether the amount is below zero.
+    pub fn is_negative(self) -> bool {
+        self.0 < REPR_ZERO
+    }
+
+    /// Round to the number of decimal places the currency uses, half away
+    /// from zero.
+    ///
+    /// See [`currency_exponent`](super::currency_exponent).
+    pub fn round_for_currency(self, currency: &str) -> Amount {
+        let places = super::currency_exponent(currency);
+        #[cfg(feature = "decimal")]
+        {
+            Amount(self.0.round_dp_with_strategy(
+                places,
+                rust_decimal::RoundingStrategy::MidpointAwayFromZero,
+            ))
+        }
+        #[cfg(not(feature = "decimal"))]
+        {
+            let scale = 10f64.powi(places as i32);
+            Amount((self.0 * scale).round() / scale)
+        }
+    }
+
+    /// An amount from integer minor units with the given number of decimal
+    /// places.
+    pub(crate) fn from_minor(units: i64, places: u32) -> Amount {
+        #[cfg(feature = "decimal")]
+        {
+            Amount(Decimal::new(units, places))
+        }
+        #[cfg(not(feature = "decimal"))]
+        {
+            Amount(units as f64 / 10f64.powi(places as i32))
+        }
+    }
+
+    /// The amount in integer minor units with the given number of decimal
+    /// places, rounded to the nearest unit.
+    pub(crate) fn to_minor(self, places: u32) -> i64 {
+        #[cfg(feature = "decimal")]
+        {
+            let rounded = self.0.round_dp_with_strategy(
+                places,
+                rust_decimal::RoundingStrategy::MidpointAwayFromZero,
+            );
+            (rounded * Decimal::from(10i64.pow(places)))
+                .to_i64()
+                .unwrap_or_default()
+        }
+        #[cfg(not(feature = "decimal"))]
+        {
+            (self.0 * 10f64.powi(places as i32)).round() as i64
+        }
+    }
+}
+
+impl From<f64> for Amount {
+    /// Convert using the shortest decimal that reads back as the same `f64`,
+    /// so `0.1` becomes exactly `0.1`. Non-finite values become zero.
+    fn from(value: f64) -> Self {
+        #[cfg(feature = "decimal")]
+        {
+            if !value.is_finite() {
+                return Amount::ZERO;
+            }
+            value
+                .to_string()
+                .parse()
+                .ok()
+                .or_else(|| Decimal::from_f64(value))
+                .map(Amount)
+                .unwrap_or_default()
+        }
+        #[cfg(not(feature = "decimal"))]
+        {
+            if value.is_finite() {
+                Amount(value)
+            } else {
+                Amount::ZERO
+            }
+        }
+    }
+}
+
+impl From<i64> for Amount {
+    fn from(value: i64) -> Self {
+        #[cfg(feature = "decimal")]
+        {
+            Amount(Decimal::from(value))
+        }
+        #[cfg(not(feature = "decimal"))]
+        {
+            Amount(value as f64)
+        }
+    }
+}
+
+impl From<i32> for Amount {
+    fn from(value: i32) -> Self {
+        Amount::from(i64::from(value))
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl From<Decimal> for Amount {
+    fn from(value: Decimal) -> Self {
+        Amount(value)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl From<Amount> for Decimal {
+    fn from(value: Amount) -> Self {
+        value.0
+    }
+}
+
+impl FromStr for Amount {
+    type Err = Error;
+
+    /// Parse a decimal string such as `"1050"`, `"-3.25"` or `"1.5e-3"`.
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        #[cfg(feature = "decimal")]
+        let parsed = Decimal::from_str_exact(s)
+            .or_else(|_| Decimal::from_scientific(s))
+            .ok();
+        #[cfg(not(feature = "decimal"))]
+        let parsed = s.parse::<f64>().ok().filter(|value| value.is_finite());
+        parsed
+            .map(Amount)
+            .ok_or_else(|| Error::Validation(format!("invalid amount: {:?}", s)))
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Add for Amount {
+    type Output = Amount;
+
+    fn add(self, rhs: Amount) -> Amount {
+        Amount(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Amount {
+    type Output = Amount;
+
+    fn sub(self, rhs: Amount) -> Amount {
+        Amount(self.0 - rhs.0)
+    }
+}
+
+impl Neg for Amount {
+    type Output = Amount;
+
+    fn neg(self) -> Amount {
+        Amount(-self.0)
+    }
+}
+
+impl AddAssign for Amount {
+    fn add_assign(&mut self, rhs: Amount) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Amount {
+    fn sub_assign(&mut self, rhs: Amount) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Sum for Amount {
+    fn sum<I: Iterator<Item = Amount>>(iter: I) -> Amount {
+        iter.fold(Amount::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Amount> for Amount {
+    fn sum<I: Iterator<Item = &'a Amount>>(iter: I) -> Amount {
+        iter.copied().sum()
+    }
+}
+
+/// The key serde_json's arbitrary-precision numbers are passed under.
+#[cfg(feature = "decimal")]
+const JSON_NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        #[cfg(feature = "decimal")]
+        {
+            // Plain numbers work with any serializer, including query strings,
+            // so only amounts an `f64` cannot hold go out as exact digits.
+            let value = self.to_f64();
+            if Amount::from(value) == *self {
+                return serializer.serialize_f64(value);
+            }
+            let number: serde_json::Number = self
+                .0
+                .normalize()
+                .to_string()
+                .parse()
+                .map_err(serde::ser::Error::custom)?;
+            number.serialize(serializer)
+        }
+        #[cfg(not(feature = "decimal"))]
+        {
+            serializer.serialize_f64(self.0)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct AmountVisitor;
+
+        impl<'de> Visitor<'de> for AmountVisitor {
+            type Value = Amount;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a number or a numeric string")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> std::result::Result<Amount, E> {
+                Ok(Amount::from(value))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> std::result::Result<Amount, E> {
+                i64::try_from(value)
+                    .map(Amount::from)
+                    .map_err(|_| E::custom(format!("amount out of range: {}", value)))
+            }
+
+            fn visit_f64<E: de::Error>(self, value: f64) -> std::result::Result<Amount, E> {
+                Ok(Amount::from(value))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> std::result::Result<Amount, E> {
+                value.parse().map_err(E::custom)
+            }
+
+            /// A JSON number with every digit, from serde_json's arbitrary
+            /// precision mode.
+            #[cfg(feature = "decimal")]
+            fn visit_map<A: de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> std::result::Result<Amount, A::Error> {
+                match map.next_entry::<String, String>()? {
+                    Some((key, digits)) if key == JSON_NUMBER_TOKEN => {
+                        digits.parse().map_err(de::Error::custom)
+                    }
+                    _ => Err(de::Error::invalid_type(de::Unexpected::Map, &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(AmountVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_numbers_and_strings() {
+        let amounts: Vec<Amount> =
+            serde_json::from_str(r#"[10.5, 1000, "0.000123456789", " -3.25 "]"#).unwrap();
+        assert_eq!(amounts[0], Amount::from(10.5));
+        assert_eq!(amounts[1], Amount::from(1000));
+        assert_eq!(amounts[2], "0.000123456789".parse().unwrap());
+        assert_eq!(amounts[3], Amount::from(-3.25));
+        assert!(serde_json::from_str::<Amount>(r#""ten""#).is_err());
+    }
+
+    #[test]
+    fn test_round_trip_without_drift() {
+        for text in ["10.02", "1000", "0.1", "1.23456789012", "99999999.99"] {
+            let amount: Amount = text.parse().unwrap();
+            let json = serde_json::to_string(&amount).unwrap();
+            let back: Amount = serde_json::from_str(&json).unwrap();
+            assert_eq!(back, amount, "{}", text);
+        }
+
+        let total: Amount = [0.1, 0.2].into_iter().map(Amount::from).sum();
+        assert_eq!(total.round_for_currency("USD"), Amount::from(0.3));
+    }
+
+    #[test]
+    fn test_round_for_currency() {
+        let amount: Amount = "1050.5".parse().unwrap();
+        assert_eq!(amount.round_for_currency("JPY"), Amount::from(1051));
+        assert_eq!(amount.round_for_currency("USD"), Amount::from(1050.5));
+        let amount: Amount = "1.23456".parse().unwrap();
+        assert_eq!(amount.round_for_currency("KWD"), Amount::from(1.235));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_decimal_is_exact() {
+        let total: Amount = [0.1, 0.2].into_iter().map(Amount::from).sum();
+        assert_eq!(total.to_string(), "0.3");
+
+        let rate: Amount = "0.7312456789012345678".parse().unwrap();
+        assert_eq!(rate.to_string(), "0.7312456789012345678");
+        let yen: Amount = serde_json::from_str("1000").unwrap();
+        assert_eq!(yen.to_string(), "1000");
+
+        let json = serde_json::to_string(&rate).unwrap();
+        assert_eq!(json, "0.7312456789012345678");
+        let back: Amount = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.to_string(), "0.7312456789012345678");
+        let large: Amount = serde_json::from_str("12345678901234567.89").unwrap();
+        assert_eq!(
+            serde_json::to_value(large).unwrap(),
+            serde_json::from_str::<serde_json::Value>("12345678901234567.89").unwrap()
+        );
+        assert!(serde_json::from_str::<Amount>(r#"{"value": "1"}"#).is_err());
+    }
+}
@@ -1,9 +1,12 @@
 //! Models for the Balances API.
 
-use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::common::Currency;
+use super::amount::Amount;
+use super::common::{Currency, capped_page_size};
 
 /// Current balance for a currency.
 #[derive(Debug, Clone, Deserialize)]
@@ -11,23 +14,23 @@ pub struct Balance {
     /// The currency code.
     pub currency: Currency,
     /// Available balance (can be used for payments).
-    pub available_amount: f64,
+    pub available_amount: Amount,
     /// Pending balance (not yet settled).
-    pub pending_amount: f64,
+    pub pending_amount: Amount,
     /// Reserved balance (held for pending operations).
     #[serde(default)]
-    pub reserved_amount: f64,
+    pub reserved_amount: Amount,
     /// Total balance.
     #[serde(default)]
-    pub total_amount: f64,
+    pub total_amount: Amount,
     /// Prepayment amount.
     #[serde(default)]
-    pub prepayment_amount: f64,
+    pub prepayment_amount: Amount,
 }
 
 /// Response from GET /balances/current.
 /// Note: The API returns a raw array, so we use a wrapper for convenience.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct CurrentBalancesResponse {
     /// List of balances by currency.
     pub items: Vec<Balance>,
@@ -38,6 +41,35 @@ impl CurrentBalancesResponse {
     pub fn new(items: Vec<Balance>) -> Self {
         Self { items }
     }
+
+    /// The balance for a currency, ignoring case.
+    pub fn by_currency(&self, currency: &str) -> Option<&Balance> {
+        self.items
+            .iter()
+            .find(|balance| balance.currency.eq_ignore_ascii_case(currency))
+    }
+
+    /// The currencies held, in response order.
+    pub fn currencies(&self) -> Vec<&str> {
+        self.items
+            .iter()
+            .map(|balance| balance.currency.as_str())
+            .collect()
+    }
+
+    /// Available amount per currency.
+    ///
+    /// Amounts in different currencies cannot be summed, so this is a map
+    /// keyed by currency code rather than a single total.
+    pub fn total_available(&self) -> BTreeMap<Currency, Amount> {
+        let mut totals = BTreeMap::new();
+        for balance in &self.items {
+            *totals
+                .entry(balance.currency.clone())
+                .or_insert(Amount::ZERO) += balance.available_amount;
+        }
+        totals
+    }
 }
 
 /// A single balance history entry.
@@ -46,15 +78,15 @@ pub struct BalanceHistoryEntry {
     /// Unique ID for this transaction.
     pub id: String,
     /// The amount of the balance change.
-    pub amount: f64,
+    pub amount: Amount,
     /// The currency.
     pub currency: Currency,
     /// Opening balance before this transaction.
     #[serde(default)]
-    pub opening_balance: Option<f64>,
+    pub opening_balance: Option<Amount>,
     /// Closing balance after this transaction.
     #[serde(default)]
-    pub closing_balance: Option<f64>,
+    pub closing_balance: Option<Amount>,
     /// Time when this transaction was posted.
     pub posted_at: DateTime<Utc>,
     /// Time when this transaction was created.
@@ -84,10 +116,17 @@ pub struct BalanceHistoryEntry {
 }
 
 /// Response from GET /balances/history.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct BalanceHistoryResponse {
     /// List of balance history entries.
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<BalanceHistoryEntry>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
@@ -99,6 +138,39 @@ pub struct BalanceHistoryResponse {
     pub page_before: Option<String>,
 }
 
+impl BalanceHistoryResponse {
+    /// The entries for a currency, ignoring case.
+    pub fn by_currency<'a>(
+        &'a self,
+        currency: &'a str,
+    ) -> impl Iterator<Item = &'a BalanceHistoryEntry> + 'a {
+        self.items
+            .iter()
+            .filter(move |entry| entry.currency.eq_ignore_ascii_case(currency))
+    }
+
+    /// The distinct currencies on this page, sorted.
+    pub fn currencies(&self) -> Vec<&str> {
+        let mut currencies: Vec<&str> = self
+            .items
+            .iter()
+            .map(|entry| entry.currency.as_str())
+            .collect();
+        currencies.sort_unstable();
+        currencies.dedup();
+        currencies
+    }
+
+    /// Net balance change per currency on this page.
+    pub fn net_by_currency(&self) -> BTreeMap<Currency, Amount> {
+        let mut totals = BTreeMap::new();
+        for entry in &self.items {
+            *totals.entry(entry.currency.clone()).or_insert(Amount::ZERO) += entry.amount;
+        }
+        totals
+    }
+}
+
 /// Query parameters for balance history.
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct BalanceHistoryParams {
@@ -123,6 +195,11 @@ pub struct BalanceHistoryParams {
     /// Filter by request ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<String>,
+    /// Drop entries already yielded when streaming history.
+    ///
+    /// Not sent to the API; see [`dedup`](Self::dedup).
+    #[serde(skip)]
+    pub dedup: bool,
 }
 
 impl BalanceHistoryParams {
@@ -149,15 +226,25 @@ impl BalanceHistoryParams {
         self
     }
 
+    /// Set the start of the date range from a `DateTime`, sent as RFC 3339.
+    pub fn from_post_datetime(self, from: DateTime<Utc>) -> Self {
+        self.from_post_at(from.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+
+    /// Set the end of the date range from a `DateTime`, sent as RFC 3339.
+    pub fn to_post_datetime(self, to: DateTime<Utc>) -> Self {
+        self.to_post_at(to.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+
     /// Set the page number.
     pub fn page_num(mut self, page: i32) -> Self {
         self.page_num = Some(page);
         self
     }
 
-    /// Set the page size.
+    /// Set the page size, capped at 2000.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 2000));
         self
     }
 
@@ -166,4 +253,85 @@ impl BalanceHistoryParams {
         self.page = Some(cursor.into());
         self
     }
+
+    /// De-duplicate entries by ID when streaming history.
+    ///
+    /// New activity during pagination can shift entries across a page
+    /// boundary, so the same entry is returned twice. With this set,
+    /// [`Balances::history_stream`](crate::resources::Balances::history_stream)
+    /// yields each entry ID once.
+    pub fn dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_balance_lookups() {
+        let balances: Vec<Balance> = serde_json::from_value(serde_json::json!([
+            {"currency": "USD", "available_amount": 100.5, "pending_amount": 0.0},
+            {"currency": "EUR", "available_amount": 20.0, "pending_amount": 5.0}
+        ]))
+        .unwrap();
+        let response = CurrentBalancesResponse::new(balances);
+
+        assert_eq!(
+            response.by_currency("eur").unwrap().pending_amount,
+            Amount::from(5.0)
+        );
+        assert!(response.by_currency("GBP").is_none());
+        assert_eq!(response.currencies(), ["USD", "EUR"]);
+
+        let totals = response.total_available();
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals["USD"], Amount::from(100.5));
+        assert_eq!(totals["EUR"], Amount::from(20.0));
+    }
+
+    #[test]
+    fn test_history_params_datetime_setters() {
+        use chrono::TimeZone;
+
+        let params = BalanceHistoryParams::new()
+            .from_post_datetime(Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap())
+            .to_post_datetime(Utc.with_ymd_and_hms(2024, 5, 2, 12, 30, 0).unwrap());
+        assert_eq!(params.from_post_at.as_deref(), Some("2024-05-01T00:00:00Z"));
+        assert_eq!(params.to_post_at.as_deref(), Some("2024-05-02T12:30:00Z"));
+    }
+
+    #[test]
+    fn test_history_per_currency() {
+        let entry = |id: &str, currency: &str, amount: f64| {
+            serde_json::json!({
+                "id": id,
+                "amount": amount,
+                "currency": currency,
+                "posted_at": "2024-05-01T00:00:00Z",
+                "type": "DEPOSIT"
+            })
+        };
+        let response: BalanceHistoryResponse = serde_json::from_value(serde_json::json!({
+            "items": [
+                entry("bh_1", "USD", 50.0),
+                entry("bh_2", "EUR", 10.0),
+                entry("bh_3", "USD", -20.0)
+            ]
+        }))
+        .unwrap();
+
+        let usd: Vec<&str> = response
+            .by_currency("USD")
+            .map(|entry| entry.id.as_str())
+            .collect();
+        assert_eq!(usd, ["bh_1", "bh_3"]);
+        assert_eq!(response.currencies(), ["EUR", "USD"]);
+
+        let net = response.net_by_currency();
+        assert_eq!(net["USD"], Amount::from(30.0));
+        assert_eq!(net["EUR"], Amount::from(10.0));
+    }
 }
@@ -5,6 +5,83 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::beneficiaries::Beneficiary;
+use super::common::{Timestamp, capped_page_size};
+use super::transfers::TransferMethod;
+use crate::error::{Error, Result};
+
+/// Status of a batch transfer.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BatchTransferStatus {
+    /// Items are still being added.
+    #[serde(alias = "drafting")]
+    Drafting,
+    /// Waiting for approval.
+    #[serde(alias = "in_approval")]
+    InApproval,
+    /// Approval request was recalled.
+    #[serde(alias = "approval_recalled")]
+    ApprovalRecalled,
+    /// Approval was rejected.
+    #[serde(alias = "approval_rejected")]
+    ApprovalRejected,
+    /// Approval is blocked.
+    #[serde(alias = "approval_blocked")]
+    ApprovalBlocked,
+    /// Scheduled for the transfer date.
+    #[serde(alias = "scheduled")]
+    Scheduled,
+    /// Past the transfer date without being processed.
+    #[serde(alias = "overdue")]
+    Overdue,
+    /// Transfers are being booked.
+    #[serde(alias = "booking")]
+    Booking,
+    /// Some transfers were booked.
+    #[serde(alias = "partially_booked")]
+    PartiallyBooked,
+    /// All transfers were booked.
+    #[serde(alias = "booked")]
+    Booked,
+    /// Failed.
+    #[serde(alias = "failed")]
+    Failed,
+    /// Cancellation requested.
+    #[serde(alias = "cancellation_requested")]
+    CancellationRequested,
+    /// Cancelled.
+    #[serde(alias = "cancelled")]
+    Cancelled,
+    /// A status not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Funding status of a batch transfer.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FundingStatus {
+    /// Funding is scheduled.
+    #[serde(alias = "scheduled")]
+    Scheduled,
+    /// Funding is being processed.
+    #[serde(alias = "processing")]
+    Processing,
+    /// Funds have been received.
+    #[serde(alias = "funded")]
+    Funded,
+    /// Funding failed.
+    #[serde(alias = "failed")]
+    Failed,
+    /// Funding was reversed.
+    #[serde(alias = "reversed")]
+    Reversed,
+    /// A status not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
 /// A batch transfer.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BatchTransfer {
@@ -22,7 +99,7 @@ pub struct BatchTransfer {
     pub short_reference_id: Option<String>,
     /// Status.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<BatchTransferStatus>,
     /// Transfer date.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transfer_date: Option<String>,
@@ -46,7 +123,22 @@ pub struct BatchTransfer {
     pub quote_summary: Option<BatchQuoteSummary>,
     /// Updated at timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
+}
+
+impl BatchTransfer {
+    /// Whether the batch has been funded.
+    pub fn is_funded(&self) -> bool {
+        self.funding
+            .as_ref()
+            .is_some_and(|f| f.status == Some(FundingStatus::Funded))
+    }
+
+    /// Whether all transfers in the batch have been booked.
+    pub fn is_completed(&self) -> bool {
+        self.status == Some(BatchTransferStatus::Booked)
+    }
 }
 
 /// Batch funding details.
@@ -60,7 +152,7 @@ pub struct BatchFunding {
     pub deposit_type: Option<String>,
     /// Status.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<FundingStatus>,
     /// Failure reason.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub failure_reason: Option<String>,
@@ -74,10 +166,12 @@ pub struct BatchFunding {
 pub struct BatchQuoteSummary {
     /// When the quote expires.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub expires_at: Option<Timestamp>,
     /// Last quoted timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_quoted_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub last_quoted_at: Option<Timestamp>,
     /// Individual quotes.
     #[serde(default)]
     pub quotes: Vec<BatchQuote>,
@@ -250,7 +344,7 @@ pub struct BatchTransferItemRequest {
     pub transfer_currency: Option<String>,
     /// Transfer method.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transfer_method: Option<String>,
+    pub transfer_method: Option<TransferMethod>,
     /// Reference for beneficiary.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reference: Option<String>,
@@ -297,11 +391,56 @@ impl BatchTransferItemRequest {
     }
 
     /// Set transfer method.
-    pub fn transfer_method(mut self, method: impl Into<String>) -> Self {
-        self.transfer_method = Some(method.into());
+    pub fn transfer_method(mut self, method: TransferMethod) -> Self {
+        self.transfer_method = Some(method);
         self
     }
 
+    /// Check the item against the beneficiary it pays.
+    ///
+    /// The beneficiary must be the one referenced by the item, a `SWIFT`
+    /// item needs a beneficiary with a SWIFT code, and a `LOCAL` item must
+    /// pay out in the beneficiary's account currency. Details the
+    /// beneficiary doesn't include are not checked. Returns
+    /// [`Error::Validation`] otherwise.
+    pub fn validate_for(&self, beneficiary: &Beneficiary) -> Result<()> {
+        if let Some(id) = beneficiary
+            .id
+            .as_deref()
+            .filter(|id| *id != self.beneficiary_id)
+        {
+            return Err(Error::Validation(format!(
+                "item is for beneficiary {}, not {}",
+                self.beneficiary_id, id
+            )));
+        }
+        let Some(bank) = beneficiary.bank_details.as_ref() else {
+            return Ok(());
+        };
+        match self.transfer_method {
+            Some(TransferMethod::Swift) if bank.swift_code.is_none() => Err(Error::Validation(
+                format!("beneficiary {} has no SWIFT code", self.beneficiary_id),
+            )),
+            Some(TransferMethod::Local) => {
+                match (
+                    self.transfer_currency.as_deref(),
+                    bank.account_currency.as_deref(),
+                ) {
+                    (Some(currency), Some(account_currency))
+                        if !currency.eq_ignore_ascii_case(account_currency) =>
+                    {
+                        Err(Error::Validation(format!(
+                            "LOCAL transfers to beneficiary {} must be in {}, not {}",
+                            self.beneficiary_id, account_currency, currency
+                        )))
+                    }
+                    _ => Ok(()),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Set reference.
     pub fn reference(mut self, reference: impl Into<String>) -> Self {
         self.reference = Some(reference.into());
@@ -360,22 +499,28 @@ impl ListBatchTransfersParams {
         self
     }
 
-    /// Set page size.
+    /// Set page size, capped at 50.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 50));
         self
     }
 }
 
 /// Response for listing batch transfers.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListBatchTransfersResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of batch transfers.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<BatchTransfer>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
 
 /// Parameters for listing batch transfer items.
@@ -401,20 +546,123 @@ impl ListBatchItemsParams {
         self
     }
 
-    /// Set page size.
+    /// Set page size, capped at 1000.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 1000));
         self
     }
 }
 
 /// Response for listing batch transfer items.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListBatchItemsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of items.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<BatchTransferItem>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_size_capped_at_endpoint_maximum() {
+        assert_eq!(
+            ListBatchTransfersParams::new().page_size(500).page_size,
+            Some(50)
+        );
+        assert_eq!(
+            ListBatchTransfersParams::new().page_size(20).page_size,
+            Some(20)
+        );
+        assert_eq!(
+            ListBatchItemsParams::new().page_size(5000).page_size,
+            Some(1000)
+        );
+    }
+
+    #[test]
+    fn test_batch_transfer_status_serde() {
+        let batch: BatchTransfer = serde_json::from_str(
+            r#"{"id":"batch_1","status":"BOOKED","funding":{"status":"FUNDED"}}"#,
+        )
+        .unwrap();
+        assert_eq!(batch.status, Some(BatchTransferStatus::Booked));
+        assert!(batch.is_completed());
+        assert!(batch.is_funded());
+
+        let json = serde_json::to_value(BatchTransferStatus::PartiallyBooked).unwrap();
+        assert_eq!(json, "PARTIALLY_BOOKED");
+    }
+
+    #[test]
+    fn test_item_transfer_method_serde() {
+        let item = BatchTransferItemRequest::with_transfer_amount("ben_1", 100.0, "AUD")
+            .transfer_method(TransferMethod::Local);
+        let json = serde_json::to_value(&item).unwrap();
+        assert_eq!(json["transfer_method"], "LOCAL");
+
+        let item: BatchTransferItemRequest =
+            serde_json::from_str(r#"{"beneficiary_id": "ben_1", "transfer_method": "swift"}"#)
+                .unwrap();
+        assert_eq!(item.transfer_method, Some(TransferMethod::Swift));
+        assert!(
+            serde_json::from_str::<BatchTransferItemRequest>(
+                r#"{"beneficiary_id": "ben_1", "transfer_method": "WIRE"}"#
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_item_validate_for_beneficiary() {
+        let beneficiary: Beneficiary = serde_json::from_value(serde_json::json!({
+            "id": "ben_1",
+            "bank_details": {"account_currency": "AUD", "account_number": "123456"}
+        }))
+        .unwrap();
+
+        let local = BatchTransferItemRequest::with_transfer_amount("ben_1", 100.0, "AUD")
+            .transfer_method(TransferMethod::Local);
+        assert!(local.validate_for(&beneficiary).is_ok());
+
+        for item in [
+            BatchTransferItemRequest::with_transfer_amount("ben_1", 100.0, "USD")
+                .transfer_method(TransferMethod::Local),
+            BatchTransferItemRequest::with_transfer_amount("ben_1", 100.0, "AUD")
+                .transfer_method(TransferMethod::Swift),
+            BatchTransferItemRequest::with_transfer_amount("ben_2", 100.0, "AUD"),
+        ] {
+            assert!(
+                matches!(item.validate_for(&beneficiary), Err(Error::Validation(_))),
+                "{:?}",
+                item
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_transfer_status_unknown() {
+        let batch: BatchTransfer =
+            serde_json::from_str(r#"{"status":"ON_HOLD","funding":{"status":"PENDING_REVIEW"}}"#)
+                .unwrap();
+        assert_eq!(
+            batch.status,
+            Some(BatchTransferStatus::Unknown("ON_HOLD".to_string()))
+        );
+        assert!(!batch.is_completed());
+        assert!(!batch.is_funded());
+
+        let json = serde_json::to_value(FundingStatus::Unknown("PENDING_REVIEW".into())).unwrap();
+        assert_eq!(json, "PENDING_REVIEW");
+    }
 }
@@ -3,7 +3,9 @@
 //! Models for managing payout beneficiaries (recipients of payments).
 
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
+
+use super::common::Timestamp;
 
 /// A beneficiary (payment recipient).
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,16 +40,97 @@ pub struct Beneficiary {
     pub address: Option<BeneficiaryAddress>,
     /// Additional info.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_info: Option<Value>,
+    pub additional_info: Option<BeneficiaryAdditionalInfo>,
     /// Digital wallet details.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub digital_wallet: Option<Value>,
     /// When the beneficiary was created.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// When the beneficiary was last updated.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
+}
+
+/// Additional beneficiary information.
+///
+/// Which fields are required depends on the country and payment method;
+/// fields without a typed counterpart are kept in `extra`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct BeneficiaryAdditionalInfo {
+    /// Category of business activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_area: Option<String>,
+    /// Business phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_phone_number: Option<String>,
+    /// Business registration number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_registration_number: Option<String>,
+    /// Business registration type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_registration_type: Option<String>,
+    /// Your identifier for the beneficiary.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_identifier: Option<String>,
+    /// Personal email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_email: Option<String>,
+    /// Personal ID number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_id_number: Option<String>,
+    /// Personal ID type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_id_type: Option<String>,
+    /// Personal mobile number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_mobile_number: Option<String>,
+    /// Airwallex account ID of the recipient, for payouts to an Airwallex account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_account_id: Option<String>,
+    /// Any other fields, such as the `legal_rep_*` details.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl BeneficiaryAdditionalInfo {
+    /// Create empty additional info.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set business registration number.
+    pub fn business_registration_number(mut self, number: impl Into<String>) -> Self {
+        self.business_registration_number = Some(number.into());
+        self
+    }
+
+    /// Set your identifier for the beneficiary.
+    pub fn external_identifier(mut self, id: impl Into<String>) -> Self {
+        self.external_identifier = Some(id.into());
+        self
+    }
+
+    /// Set personal email.
+    pub fn personal_email(mut self, email: impl Into<String>) -> Self {
+        self.personal_email = Some(email.into());
+        self
+    }
+
+    /// Set personal ID type and number.
+    pub fn personal_id(mut self, id_type: impl Into<String>, number: impl Into<String>) -> Self {
+        self.personal_id_type = Some(id_type.into());
+        self.personal_id_number = Some(number.into());
+        self
+    }
+
+    /// Set a field that has no typed counterpart.
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
 }
 
 /// Beneficiary bank details.
@@ -141,7 +224,7 @@ pub struct CreateBeneficiaryRequest {
     pub address: Option<BeneficiaryAddress>,
     /// Additional info.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_info: Option<Value>,
+    pub additional_info: Option<BeneficiaryAdditionalInfo>,
     /// Payment methods.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_methods: Option<Vec<String>>,
@@ -202,6 +285,12 @@ impl CreateBeneficiaryRequest {
         self.payment_methods = Some(methods);
         self
     }
+
+    /// Set additional info.
+    pub fn additional_info(mut self, info: BeneficiaryAdditionalInfo) -> Self {
+        self.additional_info = Some(info);
+        self
+    }
 }
 
 /// Request to update a beneficiary.
@@ -228,7 +317,7 @@ pub struct UpdateBeneficiaryRequest {
     pub address: Option<BeneficiaryAddress>,
     /// Additional info.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_info: Option<Value>,
+    pub additional_info: Option<BeneficiaryAdditionalInfo>,
 }
 
 impl UpdateBeneficiaryRequest {
@@ -350,12 +439,54 @@ impl ListBeneficiariesParams {
 }
 
 /// Response for listing beneficiaries.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListBeneficiariesResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of beneficiaries.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<Beneficiary>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_additional_info_round_trip() {
+        let json = serde_json::json!({
+            "business_registration_number": "12345678",
+            "personal_email": "jane@example.com",
+            "external_identifier": "vendor-42",
+            "legal_rep_first_name": "Jane",
+            "legal_rep_address": {"country_code": "CN"}
+        });
+
+        let info: BeneficiaryAdditionalInfo = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(
+            info.business_registration_number.as_deref(),
+            Some("12345678")
+        );
+        assert_eq!(info.personal_email.as_deref(), Some("jane@example.com"));
+        assert_eq!(info.extra["legal_rep_first_name"], "Jane");
+        assert_eq!(serde_json::to_value(&info).unwrap(), json);
+
+        let built = BeneficiaryAdditionalInfo::new()
+            .business_registration_number("12345678")
+            .personal_email("jane@example.com")
+            .external_identifier("vendor-42")
+            .extra("legal_rep_first_name", "Jane")
+            .extra(
+                "legal_rep_address",
+                serde_json::json!({"country_code": "CN"}),
+            );
+        assert_eq!(built, info);
+    }
 }
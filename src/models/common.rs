@@ -1,6 +1,32 @@
 //! Common types shared across multiple API resources.
 
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use super::{
+    Account, AccountCapability, Amount, Balance, BalanceHistoryEntry, BalanceHistoryResponse, Bank,
+    BatchTransfer, BatchTransferItem, Beneficiary, Cardholder, ConnectedAccountTransfer,
+    Conversion, ConversionAmendment, CurrentBalancesResponse, Customer, Deposit,
+    FinancialTransaction, FundingLimit, GlobalAccount, GlobalAccountTransaction, Invoice,
+    InvoiceItem, InvoicePreviewResponse, IssuingAuthorization, IssuingCard, IssuingTransaction,
+    IssuingTransactionDispute, LinkedAccount, ListAccountsResponse, ListAmendmentsResponse,
+    ListBanksResponse, ListBatchItemsResponse, ListBatchTransfersResponse,
+    ListBeneficiariesResponse, ListCardholdersResponse, ListCardsResponse,
+    ListConnectedAccountTransfersResponse, ListConversionsResponse, ListCustomersResponse,
+    ListDepositsResponse, ListFinancialTransactionsResponse, ListFundingLimitsResponse,
+    ListGlobalAccountsResponse, ListInvoiceItemsResponse, ListInvoicesResponse,
+    ListIssuingAuthorizationsResponse, ListIssuingTransactionDisputesResponse,
+    ListIssuingTransactionsResponse, ListLinkedAccountsResponse, ListMandatesResponse,
+    ListPayersResponse, ListPaymentAttemptsResponse, ListPaymentConsentsResponse,
+    ListPaymentDisputesResponse, ListPaymentIntentsResponse, ListPaymentLinksResponse,
+    ListPaymentMethodTypesResponse, ListPaymentMethodsResponse, ListRefundsResponse,
+    ListSettlementsResponse, ListTransactionsResponse, ListTransfersResponse,
+    ListTreasuryBalancesResponse, ListWebhooksResponse, Mandate, PayerContact, PaymentAttempt,
+    PaymentConsent, PaymentDispute, PaymentIntent, PaymentLink, PaymentMethod,
+    PaymentMethodTypeConfig, Refund, Settlement, Transfer, TreasuryBalance, Webhook,
+};
 
 /// ISO 4217 currency code (3 letters).
 pub type Currency = String;
@@ -10,21 +36,48 @@ pub type CountryCode = String;
 
 /// A monetary amount with currency.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct Amount {
+pub struct Money {
     /// The currency code (ISO 4217).
     pub currency: Currency,
     /// The amount value.
-    pub value: f64,
+    pub value: Amount,
 }
 
-impl Amount {
+impl Money {
     /// Create a new amount.
-    pub fn new(currency: impl Into<String>, value: f64) -> Self {
+    pub fn new(currency: impl Into<String>, value: impl Into<Amount>) -> Self {
         Self {
             currency: currency.into(),
-            value,
+            value: value.into(),
         }
     }
+
+    /// Create an amount from integer minor units (e.g. cents).
+    ///
+    /// The currency's exponent is used, so `from_minor(1050, "USD")` is 10.50 USD
+    /// and `from_minor(1050, "JPY")` is 1050 JPY.
+    pub fn from_minor(units: i64, currency: impl Into<String>) -> Self {
+        let currency = currency.into();
+        let value = Amount::from_minor(units, currency_exponent(&currency));
+        Self { currency, value }
+    }
+
+    /// Convert the amount to integer minor units, rounding to the nearest unit.
+    pub fn to_minor(&self) -> i64 {
+        self.value.to_minor(currency_exponent(&self.currency))
+    }
+}
+
+/// Number of decimal places (ISO 4217 minor unit exponent) for a currency.
+///
+/// Defaults to 2 for currencies not listed as zero- or three-decimal.
+pub fn currency_exponent(currency: &str) -> u32 {
+    match currency.to_ascii_uppercase().as_str() {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        _ => 2,
+    }
 }
 
 /// A physical or mailing address.
@@ -48,6 +101,147 @@ pub struct Address {
     pub street_address: Option<String>,
 }
 
+/// Information about the shopper's device, used for risk assessment and 3DS.
+///
+/// Converts into the `device_data` JSON object; request builders also accept
+/// raw JSON for fields not modelled here.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct DeviceData {
+    /// Public IPv4 or IPv6 address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+    /// Device ID, advertisement ID or IMEI.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_id: Option<String>,
+    /// `Accept` header value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_header: Option<String>,
+    /// Language or locale.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Offset from UTC in hours, e.g. `"+8"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Browser details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub browser: Option<BrowserData>,
+    /// Mobile device details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<MobileData>,
+}
+
+/// Browser details within [`DeviceData`].
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct BrowserData {
+    /// `User-Agent` header value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+    /// Whether JavaScript is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub javascript_enabled: Option<bool>,
+    /// Whether Java is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub java_enabled: Option<bool>,
+}
+
+/// Mobile device details within [`DeviceData`].
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct MobileData {
+    /// Operating system, e.g. `ANDROID` or `IOS`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os_type: Option<String>,
+    /// Operating system version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os_version: Option<String>,
+    /// Device model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_model: Option<String>,
+}
+
+impl DeviceData {
+    /// Create empty device data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the public IP address.
+    pub fn ip_address(mut self, ip: impl Into<String>) -> Self {
+        self.ip_address = Some(ip.into());
+        self
+    }
+
+    /// Set the browser's user agent.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.browser
+            .get_or_insert_with(BrowserData::default)
+            .user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Set the device ID.
+    pub fn device_id(mut self, id: impl Into<String>) -> Self {
+        self.device_id = Some(id.into());
+        self
+    }
+
+    /// Set the mobile operating system and its version.
+    pub fn os(mut self, os_type: impl Into<String>, os_version: impl Into<String>) -> Self {
+        let mobile = self.mobile.get_or_insert_with(MobileData::default);
+        mobile.os_type = Some(os_type.into());
+        mobile.os_version = Some(os_version.into());
+        self
+    }
+
+    /// Set the language or locale.
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+}
+
+impl From<DeviceData> for Value {
+    fn from(data: DeviceData) -> Self {
+        serde_json::to_value(data).expect("device data serializes to JSON")
+    }
+}
+
+/// Options controlling Airwallex's fraud checks for a payment.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct RiskControlOptions {
+    /// Skip fraud processing. Only takes effect once enabled for the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_risk_processing: Option<bool>,
+    /// Request a transaction risk analysis (TRA) 3DS exemption. Only
+    /// applies when risk processing is skipped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tra_applicable: Option<bool>,
+}
+
+impl RiskControlOptions {
+    /// Create empty options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skip fraud processing.
+    pub fn skip_risk_processing(mut self, skip: bool) -> Self {
+        self.skip_risk_processing = Some(skip);
+        self
+    }
+
+    /// Request a TRA exemption.
+    pub fn tra_applicable(mut self, applicable: bool) -> Self {
+        self.tra_applicable = Some(applicable);
+        self
+    }
+}
+
+impl From<RiskControlOptions> for Value {
+    fn from(options: RiskControlOptions) -> Self {
+        serde_json::to_value(options).expect("risk control options serialize to JSON")
+    }
+}
+
 /// Pagination information for list responses.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Pagination {
@@ -78,6 +272,233 @@ pub struct PaginatedResponse<T> {
     pub page_before: Option<String>,
 }
 
+/// A field in a JSON Merge Patch update.
+///
+/// Merge patch (RFC 7396) leaves out fields that should not change and sends
+/// `null` to clear one, which `Option` cannot express on its own. Use with
+/// `#[serde(default, skip_serializing_if = "Patch::is_unchanged")]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Patch<T> {
+    /// Leave the field as it is; not serialized.
+    #[default]
+    Unchanged,
+    /// Clear the field; serialized as `null`.
+    Clear,
+    /// Set the field to a new value.
+    Set(T),
+}
+
+impl<T> Patch<T> {
+    /// Whether the field is left out of the patch.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Patch::Unchanged)
+    }
+}
+
+impl<T> From<T> for Patch<T> {
+    fn from(value: T) -> Self {
+        Patch::Set(value)
+    }
+}
+
+impl<T: Serialize> Serialize for Patch<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Patch::Set(value) => value.serialize(serializer),
+            Patch::Unchanged | Patch::Clear => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Patch<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(value) => Patch::Set(value),
+            None => Patch::Clear,
+        })
+    }
+}
+
+/// Where a list page sits, for fetching the next or previous one on demand.
+///
+/// Endpoints paginate either by page number or by cursor; only the fields
+/// for the endpoint's scheme are set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageInfo {
+    /// Whether there are more results after this page.
+    pub has_more: bool,
+    /// `page_num` of the next page, for page-numbered endpoints.
+    pub next_page_num: Option<i32>,
+    /// Cursor for the next page, for cursor-based endpoints.
+    pub next_cursor: Option<String>,
+    /// Cursor for the previous page, for cursor-based endpoints.
+    pub prev_cursor: Option<String>,
+}
+
+impl PageInfo {
+    /// Page info for a page-numbered endpoint, given the requested page.
+    pub(crate) fn numbered(has_more: bool, page_num: Option<i32>) -> Self {
+        Self {
+            has_more,
+            next_page_num: has_more.then(|| page_num.unwrap_or(0) + 1),
+            ..Self::default()
+        }
+    }
+
+    /// Page info for a cursor-based endpoint.
+    pub(crate) fn cursors(page_after: Option<String>, page_before: Option<String>) -> Self {
+        Self {
+            has_more: page_after.is_some(),
+            next_cursor: page_after,
+            prev_cursor: page_before,
+            ..Self::default()
+        }
+    }
+
+    /// A checkpoint for the next page, or `None` on the last page.
+    pub fn checkpoint(&self) -> Option<PageCheckpoint> {
+        match (&self.next_page_num, &self.next_cursor) {
+            (Some(page_num), _) => Some(PageCheckpoint::PageNum(*page_num)),
+            (None, Some(cursor)) => Some(PageCheckpoint::Cursor(cursor.clone())),
+            (None, None) => None,
+        }
+    }
+}
+
+/// A saved position in a paginated listing, for resuming after a restart.
+///
+/// Serializes as `{"page_num": 3}` or `{"cursor": "..."}`, so it can be
+/// stored between runs and passed back to a resource's `resume_from`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PageCheckpoint {
+    /// The `page_num` to fetch next, for page-numbered endpoints.
+    PageNum(i32),
+    /// The cursor to fetch next, for cursor-based endpoints.
+    Cursor(String),
+}
+
+/// Deserialize a list response's `items`, treating `null` as an empty list.
+///
+/// A value that is neither is moved to `raw_items` before this runs, see
+/// [`ListResponse`].
+pub(crate) fn list_items<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    Ok(Option::<Vec<T>>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// A list response with a `raw_items` field.
+///
+/// Some error and edge responses put an object where the `items` array
+/// belongs. Such a response still deserializes, with empty `items` and the
+/// raw value in `raw_items`, so the rest of it stays readable.
+pub(crate) trait ListResponse {
+    /// The raw `items` value, if it was not an array.
+    fn raw_items(&self) -> Option<&Value>;
+
+    /// Fail if `items` was not an array.
+    ///
+    /// Listing helpers call this so a malformed page is reported instead of
+    /// being skipped as empty. The error carries the raw value, and with it
+    /// any upstream message.
+    fn check_items(&self) -> crate::Result<()> {
+        match self.raw_items() {
+            Some(raw) => Err(crate::Error::Serialization(serde::de::Error::custom(
+                format!("expected an array for `items`, got {}", raw),
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Move a non-array, non-null `items` value out of a list response.
+fn take_raw_items(value: &mut Value) -> Option<Value> {
+    let map = value.as_object_mut()?;
+    if matches!(map.get("items")?, Value::Array(_) | Value::Null) {
+        return None;
+    }
+    map.remove("items")
+}
+
+// List responses are derived with `#[serde(remote = "Self")]`, which turns
+// the derived impls into inherent functions that these impls wrap.
+macro_rules! impl_list_response {
+    ($($response:ty),* $(,)?) => {
+        $(
+            impl_list_response!(@deserialize $response);
+
+            impl Serialize for $response {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    <$response>::serialize(self, serializer)
+                }
+            }
+        )*
+    };
+    (@deserialize $response:ty) => {
+        impl<'de> Deserialize<'de> for $response {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let mut value = Value::deserialize(deserializer)?;
+                let raw_items = take_raw_items(&mut value);
+                let mut response =
+                    <$response>::deserialize(value).map_err(serde::de::Error::custom)?;
+                response.raw_items = raw_items;
+                Ok(response)
+            }
+        }
+
+        impl ListResponse for $response {
+            fn raw_items(&self) -> Option<&Value> {
+                self.raw_items.as_ref()
+            }
+        }
+    };
+}
+
+impl_list_response!(@deserialize BalanceHistoryResponse);
+
+impl_list_response! {
+    InvoicePreviewResponse,
+    ListAccountsResponse,
+    ListAmendmentsResponse,
+    ListBanksResponse,
+    ListBatchItemsResponse,
+    ListBatchTransfersResponse,
+    ListBeneficiariesResponse,
+    ListCardholdersResponse,
+    ListCardsResponse,
+    ListConnectedAccountTransfersResponse,
+    ListConversionsResponse,
+    ListCustomersResponse,
+    ListDepositsResponse,
+    ListFinancialTransactionsResponse,
+    ListFundingLimitsResponse,
+    ListGlobalAccountsResponse,
+    ListInvoiceItemsResponse,
+    ListInvoicesResponse,
+    ListIssuingAuthorizationsResponse,
+    ListIssuingTransactionDisputesResponse,
+    ListIssuingTransactionsResponse,
+    ListLinkedAccountsResponse,
+    ListMandatesResponse,
+    ListPayersResponse,
+    ListPaymentAttemptsResponse,
+    ListPaymentConsentsResponse,
+    ListPaymentDisputesResponse,
+    ListPaymentIntentsResponse,
+    ListPaymentLinksResponse,
+    ListPaymentMethodTypesResponse,
+    ListPaymentMethodsResponse,
+    ListRefundsResponse,
+    ListSettlementsResponse,
+    ListTransactionsResponse,
+    ListTransfersResponse,
+    ListTreasuryBalancesResponse,
+    ListWebhooksResponse,
+}
+
 /// Common query parameters for list endpoints.
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct ListParams {
@@ -116,3 +537,503 @@ impl ListParams {
         self
     }
 }
+
+/// Cap a page size at an endpoint's maximum.
+///
+/// Larger sizes are rejected by some endpoints, so they are lowered to the
+/// maximum and logged as a `tracing` warning.
+pub(crate) fn capped_page_size(size: i32, max: i32) -> i32 {
+    if size > max {
+        tracing::warn!(
+            page_size = size,
+            max,
+            "page_size is above the endpoint maximum; using the maximum"
+        );
+        max
+    } else {
+        size
+    }
+}
+
+/// Parse an API timestamp, accepting both `+00:00` and `+0000` offsets.
+pub(crate) fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// A timestamp field of a response, such as `created_at`.
+///
+/// The API's string by default. With the `chrono` feature, the parsed
+/// [`DateTime<Utc>`], accepting `Z`, `+00:00` and `+0000` offsets.
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = String;
+
+/// A timestamp field of a response, such as `created_at`.
+///
+/// The API's string by default. With the `chrono` feature, the parsed
+/// [`DateTime<Utc>`], accepting `Z`, `+00:00` and `+0000` offsets.
+#[cfg(feature = "chrono")]
+pub type Timestamp = DateTime<Utc>;
+
+/// A [`Timestamp`] as a `DateTime`, or `None` if it doesn't parse.
+pub(crate) fn timestamp_to_utc(value: &Timestamp) -> Option<DateTime<Utc>> {
+    #[cfg(feature = "chrono")]
+    {
+        Some(*value)
+    }
+    #[cfg(not(feature = "chrono"))]
+    {
+        parse_timestamp(value)
+    }
+}
+
+/// Serde adapter for `Option<Timestamp>` fields with the `chrono` feature.
+///
+/// Serializes in RFC 3339 with a `Z` offset.
+#[cfg(feature = "chrono")]
+pub(crate) mod timestamp {
+    use chrono::{DateTime, SecondsFormat, Utc};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<DateTime<Utc>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(dt) => serializer.serialize_str(&dt.to_rfc3339_opts(SecondsFormat::AutoSi, true)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<DateTime<Utc>>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(value) => super::parse_timestamp(&value)
+                .map(Some)
+                .ok_or_else(|| serde::de::Error::custom(format!("invalid timestamp: {:?}", value))),
+            None => Ok(None),
+        }
+    }
+}
+
+/// A dispute reason code, shared by payment and issuing disputes.
+///
+/// Payment disputes ([`DisputeReasonType`](super::DisputeReasonType)) and issuing
+/// disputes ([`IssuingDisputeReason`](super::IssuingDisputeReason)) use different
+/// vocabularies; this trait lets reporting code handle either as the raw API code.
+pub trait DisputeReasonCode {
+    /// The reason as sent by the API (e.g. `"FRAUDULENT"`).
+    fn as_str(&self) -> &str;
+}
+
+/// A model with a primary identifier.
+///
+/// Airwallex names the identifier differently per resource (`id`, `card_id`,
+/// `conversion_id`, ...). This trait exposes it uniformly for generic code
+/// such as caching or logging.
+pub trait Identifiable {
+    /// The resource's primary identifier, if present.
+    fn id(&self) -> Option<&str>;
+}
+
+macro_rules! impl_identifiable {
+    ($($model:ty => $field:ident),* $(,)?) => {
+        $(
+            impl Identifiable for $model {
+                fn id(&self) -> Option<&str> {
+                    self.$field.as_deref()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_identifiable_required {
+    ($($model:ty => $field:ident),* $(,)?) => {
+        $(
+            impl Identifiable for $model {
+                fn id(&self) -> Option<&str> {
+                    Some(&self.$field)
+                }
+            }
+        )*
+    };
+}
+
+impl_identifiable! {
+    Account => id,
+    AccountCapability => id,
+    BatchTransfer => id,
+    Beneficiary => id,
+    Cardholder => cardholder_id,
+    ConnectedAccountTransfer => id,
+    Conversion => conversion_id,
+    ConversionAmendment => amendment_id,
+    Customer => id,
+    Deposit => deposit_id,
+    FinancialTransaction => id,
+    GlobalAccountTransaction => id,
+    Invoice => id,
+    IssuingAuthorization => transaction_id,
+    IssuingCard => card_id,
+    IssuingTransaction => transaction_id,
+    IssuingTransactionDispute => id,
+    PayerContact => id,
+    PaymentAttempt => id,
+    PaymentConsent => id,
+    PaymentDispute => id,
+    PaymentIntent => id,
+    PaymentLink => id,
+    PaymentMethod => id,
+    Refund => id,
+    Settlement => id,
+    Transfer => id,
+    TreasuryBalance => id,
+}
+
+impl_identifiable_required! {
+    BalanceHistoryEntry => id,
+    GlobalAccount => id,
+    LinkedAccount => id,
+    Mandate => id,
+}
+
+macro_rules! impl_list_iter {
+    ($($response:ty => $item:ty),* $(,)?) => {
+        $(
+            impl IntoIterator for $response {
+                type Item = $item;
+                type IntoIter = std::vec::IntoIter<$item>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    self.items.into_iter()
+                }
+            }
+
+            impl<'a> IntoIterator for &'a $response {
+                type Item = &'a $item;
+                type IntoIter = std::slice::Iter<'a, $item>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    self.items.iter()
+                }
+            }
+        )*
+    };
+}
+
+// List responses iterate over their `items`.
+impl_list_iter! {
+    BalanceHistoryResponse => BalanceHistoryEntry,
+    CurrentBalancesResponse => Balance,
+    ListAccountsResponse => Account,
+    ListAmendmentsResponse => ConversionAmendment,
+    ListBanksResponse => Bank,
+    ListBatchItemsResponse => BatchTransferItem,
+    ListBatchTransfersResponse => BatchTransfer,
+    ListBeneficiariesResponse => Beneficiary,
+    ListCardholdersResponse => Cardholder,
+    ListCardsResponse => IssuingCard,
+    ListConnectedAccountTransfersResponse => ConnectedAccountTransfer,
+    ListConversionsResponse => Conversion,
+    ListCustomersResponse => Customer,
+    ListDepositsResponse => Deposit,
+    ListFinancialTransactionsResponse => FinancialTransaction,
+    ListFundingLimitsResponse => FundingLimit,
+    ListGlobalAccountsResponse => GlobalAccount,
+    ListInvoiceItemsResponse => InvoiceItem,
+    ListInvoicesResponse => Invoice,
+    ListIssuingAuthorizationsResponse => IssuingAuthorization,
+    ListIssuingTransactionDisputesResponse => IssuingTransactionDispute,
+    ListIssuingTransactionsResponse => IssuingTransaction,
+    ListLinkedAccountsResponse => LinkedAccount,
+    ListMandatesResponse => Mandate,
+    ListPayersResponse => PayerContact,
+    ListPaymentAttemptsResponse => PaymentAttempt,
+    ListPaymentConsentsResponse => PaymentConsent,
+    ListPaymentDisputesResponse => PaymentDispute,
+    ListPaymentIntentsResponse => PaymentIntent,
+    ListPaymentLinksResponse => PaymentLink,
+    ListPaymentMethodTypesResponse => PaymentMethodTypeConfig,
+    ListPaymentMethodsResponse => PaymentMethod,
+    ListRefundsResponse => Refund,
+    ListSettlementsResponse => Settlement,
+    ListTransactionsResponse => GlobalAccountTransaction,
+    ListTransfersResponse => Transfer,
+    ListTreasuryBalancesResponse => TreasuryBalance,
+    ListWebhooksResponse => Webhook,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id_of<T: Identifiable>(model: &T) -> Option<&str> {
+        model.id()
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_timestamp_fields_accept_each_offset_form() {
+        use chrono::TimeZone;
+
+        let expected = Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap();
+        for created_at in [
+            "2024-05-01T09:00:00Z",
+            "2024-05-01T09:00:00+00:00",
+            "2024-05-01T09:00:00+0000",
+        ] {
+            let settlement: Settlement =
+                serde_json::from_value(serde_json::json!({ "created_at": created_at })).unwrap();
+            assert_eq!(settlement.created_at, Some(expected), "{}", created_at);
+            assert_eq!(
+                serde_json::to_value(&settlement).unwrap()["created_at"],
+                "2024-05-01T09:00:00Z"
+            );
+        }
+
+        let settlement: Settlement = serde_json::from_str("{}").unwrap();
+        assert_eq!(settlement.created_at, None);
+        assert!(
+            serde_json::from_value::<Settlement>(serde_json::json!({ "created_at": "soon" }))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_list_response_into_iter() {
+        let response: ListTransfersResponse = serde_json::from_value(serde_json::json!({
+            "has_more": false,
+            "items": [{"id": "tfr_1"}, {"id": "tfr_2"}]
+        }))
+        .unwrap();
+
+        let mut borrowed = Vec::new();
+        for transfer in &response {
+            borrowed.push(transfer.id.as_deref().unwrap());
+        }
+        assert_eq!(borrowed, ["tfr_1", "tfr_2"]);
+
+        let owned: Vec<Transfer> = response.into_iter().collect();
+        assert_eq!(owned[1].id.as_deref(), Some("tfr_2"));
+    }
+
+    #[test]
+    fn test_verify_consent_risk_options() {
+        let request = crate::models::VerifyPaymentConsentRequest::new("req_1")
+            .device_data(
+                DeviceData::new()
+                    .ip_address("198.51.100.4")
+                    .language("en-GB"),
+            )
+            .risk_control_options(
+                RiskControlOptions::new()
+                    .skip_risk_processing(true)
+                    .tra_applicable(true),
+            );
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "request_id": "req_1",
+                "device_data": {"ip_address": "198.51.100.4", "language": "en-GB"},
+                "risk_control_options": {
+                    "skip_risk_processing": true,
+                    "tra_applicable": true
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_list_items_captures_non_array() {
+        use crate::models::ListTransfersResponse;
+
+        let response: ListTransfersResponse = serde_json::from_str(
+            r#"{"has_more": true, "items": {"code": "internal_error", "message": "upstream timeout"}}"#,
+        )
+        .unwrap();
+        assert!(response.items.is_empty());
+        assert!(response.has_more);
+        assert_eq!(
+            response.raw_items,
+            Some(serde_json::json!({"code": "internal_error", "message": "upstream timeout"}))
+        );
+        let message = response.check_items().unwrap_err().to_string();
+        assert!(message.contains("upstream timeout"), "{}", message);
+        // The raw value is a diagnostic only and is not serialized back.
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            serde_json::json!({"has_more": true, "items": []})
+        );
+
+        let response: ListTransfersResponse =
+            serde_json::from_str(r#"{"items": [{"id": "tfr_1"}], "has_more": true}"#).unwrap();
+        assert_eq!(response.items[0].id.as_deref(), Some("tfr_1"));
+        assert!(response.check_items().is_ok());
+
+        let response: ListTransfersResponse = serde_json::from_str(r#"{"items": null}"#).unwrap();
+        assert!(response.items.is_empty());
+
+        assert!(serde_json::from_str::<ListTransfersResponse>(r#"{"items": [42]}"#).is_err());
+    }
+
+    #[test]
+    fn test_identifiable_across_field_names() {
+        let transfer: Transfer = serde_json::from_str(r#"{"id":"tfr_123"}"#).unwrap();
+        let card: IssuingCard = serde_json::from_str(r#"{"card_id":"card_456"}"#).unwrap();
+        let conversion: Conversion =
+            serde_json::from_str(r#"{"conversion_id":"conv_789"}"#).unwrap();
+
+        assert_eq!(id_of(&transfer), Some("tfr_123"));
+        assert_eq!(id_of(&card), Some("card_456"));
+        assert_eq!(id_of(&conversion), Some("conv_789"));
+    }
+
+    #[test]
+    fn test_amount_minor_units_usd() {
+        let amount = Money::from_minor(1050, "USD");
+        assert_eq!(amount.value, Amount::from(10.5));
+        assert_eq!(amount.to_minor(), 1050);
+
+        // Float noise is rounded away rather than truncated.
+        assert_eq!(Money::new("USD", 0.29).to_minor(), 29);
+        assert_eq!(Money::new("USD", 19.99).to_minor(), 1999);
+    }
+
+    #[test]
+    fn test_amount_minor_units_jpy() {
+        let amount = Money::from_minor(1050, "JPY");
+        assert_eq!(amount.value, Amount::from(1050));
+        assert_eq!(amount.to_minor(), 1050);
+    }
+
+    #[test]
+    fn test_amount_minor_units_three_decimals() {
+        let amount = Money::from_minor(12345, "KWD");
+        assert_eq!(amount.value, Amount::from(12.345));
+        assert_eq!(amount.to_minor(), 12345);
+    }
+
+    #[test]
+    fn test_dispute_reason_codes_round_trip() {
+        use crate::models::{DisputeReasonType, IssuingDisputeReason};
+
+        for code in [
+            "FRAUDULENT",
+            "DUPLICATE_CHARGE",
+            "UNKNOWN",
+            "NEW_SCHEME_REASON",
+        ] {
+            let reason = DisputeReasonType::from(code);
+            assert_eq!(reason.as_str(), code);
+            assert_eq!(String::from(reason), code);
+        }
+        assert_eq!(
+            DisputeReasonType::from("NEW_SCHEME_REASON"),
+            DisputeReasonType::Other("NEW_SCHEME_REASON".to_string())
+        );
+
+        for code in ["SUSPECTED_FRAUD", "GOODS_SERVICE_NOT_RECEIVED", "OTHER"] {
+            let reason = IssuingDisputeReason::try_from(code).unwrap();
+            assert_eq!(reason.as_str(), code);
+        }
+        assert!(IssuingDisputeReason::try_from("FRAUDULENT").is_err());
+
+        let mixed: [&dyn DisputeReasonCode; 2] = [
+            &DisputeReasonType::Fraudulent,
+            &IssuingDisputeReason::SuspectedFraud,
+        ];
+        let codes: Vec<&str> = mixed.iter().map(|r| r.as_str()).collect();
+        assert_eq!(codes, ["FRAUDULENT", "SUSPECTED_FRAUD"]);
+    }
+
+    #[test]
+    fn test_identifiable_missing_id() {
+        let transfer: Transfer = serde_json::from_str("{}").unwrap();
+        assert_eq!(transfer.id(), None);
+    }
+
+    #[test]
+    fn test_enums_accept_either_casing() {
+        use crate::models::{BatchTransferStatus, PaymentAttemptStatus, RoutingCodeType};
+
+        for value in [r#""SETTLED""#, r#""settled""#] {
+            let status: PaymentAttemptStatus = serde_json::from_str(value).unwrap();
+            assert_eq!(status, PaymentAttemptStatus::Settled);
+        }
+
+        let status: BatchTransferStatus = serde_json::from_str(r#""in_approval""#).unwrap();
+        assert_eq!(status, BatchTransferStatus::InApproval);
+
+        let code: RoutingCodeType = serde_json::from_str(r#""SORT_CODE""#).unwrap();
+        assert_eq!(code, RoutingCodeType::SortCode);
+
+        // Serialization keeps the documented casing.
+        assert_eq!(
+            serde_json::to_string(&PaymentAttemptStatus::Settled).unwrap(),
+            r#""SETTLED""#
+        );
+        assert_eq!(
+            serde_json::to_string(&RoutingCodeType::SortCode).unwrap(),
+            r#""sort_code""#
+        );
+    }
+
+    #[test]
+    fn test_list_responses_accept_empty_object() {
+        use crate::models::*;
+
+        // Airwallex sometimes omits empty arrays and paging fields entirely.
+        macro_rules! assert_empty_ok {
+            ($($ty:ty),* $(,)?) => {
+                $(
+                    let response: $ty = serde_json::from_str("{}")
+                        .unwrap_or_else(|e| panic!("{}: {}", stringify!($ty), e));
+                    assert!(response.items.is_empty(), stringify!($ty));
+                )*
+            };
+        }
+
+        assert_empty_ok!(
+            ListFundingLimitsResponse,
+            ListAccountsResponse,
+            BalanceHistoryResponse,
+            ListBatchTransfersResponse,
+            ListBatchItemsResponse,
+            ListBeneficiariesResponse,
+            ListConnectedAccountTransfersResponse,
+            ListAmendmentsResponse,
+            ListConversionsResponse,
+            ListCustomersResponse,
+            ListDepositsResponse,
+            ListFinancialTransactionsResponse,
+            ListGlobalAccountsResponse,
+            ListTransactionsResponse,
+            ListMandatesResponse,
+            ListInvoicesResponse,
+            ListInvoiceItemsResponse,
+            ListIssuingAuthorizationsResponse,
+            ListCardholdersResponse,
+            ListCardsResponse,
+            ListIssuingTransactionDisputesResponse,
+            ListIssuingTransactionsResponse,
+            ListLinkedAccountsResponse,
+            ListPayersResponse,
+            ListPaymentAttemptsResponse,
+            ListPaymentMethodTypesResponse,
+            ListBanksResponse,
+            ListPaymentConsentsResponse,
+            ListPaymentDisputesResponse,
+            ListPaymentIntentsResponse,
+            ListPaymentLinksResponse,
+            ListPaymentMethodsResponse,
+            ListTreasuryBalancesResponse,
+            ListRefundsResponse,
+            ListSettlementsResponse,
+            ListTransfersResponse,
+        );
+    }
+}
@@ -5,6 +5,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::common::Timestamp;
+
 /// A connected account transfer.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConnectedAccountTransfer {
@@ -43,10 +45,12 @@ pub struct ConnectedAccountTransfer {
     pub additional_info: Option<Value>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Updated timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// Request to create a connected account transfer.
@@ -176,12 +180,18 @@ impl ListConnectedAccountTransfersParams {
 }
 
 /// Response for listing connected account transfers.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListConnectedAccountTransfersResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of transfers.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<ConnectedAccountTransfer>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
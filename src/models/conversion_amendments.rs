@@ -5,6 +5,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::common::Timestamp;
+
 /// A conversion amendment.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConversionAmendment {
@@ -31,10 +33,12 @@ pub struct ConversionAmendment {
     pub metadata: Option<Value>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Updated timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// A charge resulting from a conversion amendment.
@@ -186,12 +190,18 @@ impl ListAmendmentsParams {
 }
 
 /// Response for listing conversion amendments.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListAmendmentsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of amendments.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<ConversionAmendment>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
@@ -2,8 +2,12 @@
 //!
 //! Models for managing foreign exchange conversions.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::amount::Amount;
+use super::common::{Timestamp, parse_timestamp};
+
 /// A currency conversion.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Conversion {
@@ -18,13 +22,13 @@ pub struct Conversion {
     pub status: Option<String>,
     /// Buy amount.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub buy_amount: Option<f64>,
+    pub buy_amount: Option<Amount>,
     /// Buy currency.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub buy_currency: Option<String>,
     /// Sell amount.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sell_amount: Option<f64>,
+    pub sell_amount: Option<Amount>,
     /// Sell currency.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sell_currency: Option<String>,
@@ -51,13 +55,44 @@ pub struct Conversion {
     pub reason: Option<String>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Last updated timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub last_updated_at: Option<Timestamp>,
+}
+
+impl Conversion {
+    /// Whether the conversion can still be amended (cancelled).
+    ///
+    /// Only conversions that are `AWAITING_FUNDS` or `PENDING_SETTLEMENT`
+    /// can be amended, and only before their settlement cutoff time.
+    pub fn is_amendable(&self) -> bool {
+        self.is_amendable_at(Utc::now())
+    }
+
+    /// Whether the conversion can still be amended at the given time.
+    pub fn is_amendable_at(&self, now: DateTime<Utc>) -> bool {
+        let open = matches!(
+            self.status.as_deref(),
+            Some("AWAITING_FUNDS") | Some("PENDING_SETTLEMENT")
+        );
+        let cutoff = self
+            .settlement_cutoff_time
+            .as_deref()
+            .and_then(parse_timestamp);
+        open && cutoff.is_none_or(|cutoff| now < cutoff)
+    }
 }
 
 /// Request to create a conversion.
+///
+/// The API has no field for the balance to convert from: the conversion
+/// debits the wallet of the account the request is made as. To convert in a
+/// connected account's wallet, create it through
+/// [`Client::with_on_behalf_of`](crate::Client::with_on_behalf_of) or
+/// `client.scope(|s| s.on_behalf_of(..))`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateConversionRequest {
     /// Unique request ID.
@@ -68,10 +103,10 @@ pub struct CreateConversionRequest {
     pub buy_currency: String,
     /// Sell amount (mutually exclusive with buy_amount).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sell_amount: Option<f64>,
+    pub sell_amount: Option<Amount>,
     /// Buy amount (mutually exclusive with sell_amount).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub buy_amount: Option<f64>,
+    pub buy_amount: Option<Amount>,
     /// Conversion date.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conversion_date: Option<String>,
@@ -91,14 +126,14 @@ impl CreateConversionRequest {
     pub fn sell(
         request_id: impl Into<String>,
         sell_currency: impl Into<String>,
-        sell_amount: f64,
+        sell_amount: impl Into<Amount>,
         buy_currency: impl Into<String>,
     ) -> Self {
         Self {
             request_id: request_id.into(),
             sell_currency: sell_currency.into(),
             buy_currency: buy_currency.into(),
-            sell_amount: Some(sell_amount),
+            sell_amount: Some(sell_amount.into()),
             buy_amount: None,
             conversion_date: None,
             reason: None,
@@ -112,14 +147,14 @@ impl CreateConversionRequest {
         request_id: impl Into<String>,
         sell_currency: impl Into<String>,
         buy_currency: impl Into<String>,
-        buy_amount: f64,
+        buy_amount: impl Into<Amount>,
     ) -> Self {
         Self {
             request_id: request_id.into(),
             sell_currency: sell_currency.into(),
             buy_currency: buy_currency.into(),
             sell_amount: None,
-            buy_amount: Some(buy_amount),
+            buy_amount: Some(buy_amount.into()),
             conversion_date: None,
             reason: None,
             quote_id: None,
@@ -158,6 +193,9 @@ pub struct ListConversionsParams {
     /// Filter by sell currency.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sell_currency: Option<String>,
+    /// Filter by client-supplied request ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
     /// Start date filter.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub from_created_at: Option<String>,
@@ -196,6 +234,24 @@ impl ListConversionsParams {
         self
     }
 
+    /// Filter by client-supplied request ID.
+    pub fn request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// Set from created_at filter (inclusive, ISO-8601).
+    pub fn from_created_at(mut self, from: impl Into<String>) -> Self {
+        self.from_created_at = Some(from.into());
+        self
+    }
+
+    /// Set to created_at filter (inclusive, ISO-8601).
+    pub fn to_created_at(mut self, to: impl Into<String>) -> Self {
+        self.to_created_at = Some(to.into());
+        self
+    }
+
     /// Set page number.
     pub fn page_num(mut self, num: i32) -> Self {
         self.page_num = Some(num);
@@ -210,14 +266,20 @@ impl ListConversionsParams {
 }
 
 /// Response for listing conversions.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListConversionsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of conversions.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<Conversion>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
 
 /// A rate quote.
@@ -237,13 +299,13 @@ pub struct RateQuote {
     pub mid_rate: Option<f64>,
     /// Buy amount.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub buy_amount: Option<f64>,
+    pub buy_amount: Option<Amount>,
     /// Buy currency.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub buy_currency: Option<String>,
     /// Sell amount.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sell_amount: Option<f64>,
+    pub sell_amount: Option<Amount>,
     /// Sell currency.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sell_currency: Option<String>,
@@ -264,10 +326,12 @@ pub struct RateQuote {
     pub validity: Option<String>,
     /// Quote valid from.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valid_from_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub valid_from_at: Option<Timestamp>,
     /// Quote valid to.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valid_to_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub valid_to_at: Option<Timestamp>,
 }
 
 /// A current FX rate.
@@ -293,7 +357,40 @@ pub struct FxRate {
     pub conversion_date: Option<String>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
+}
+
+impl FxRate {
+    /// The rate in the opposite direction of the currency pair.
+    pub fn inverse_rate(&self) -> Option<f64> {
+        self.rate.filter(|rate| *rate != 0.0).map(|rate| 1.0 / rate)
+    }
+
+    /// Convert an amount of the dealt (fixed) currency into the other side.
+    ///
+    /// `rate` is quoted as units of the pair's second currency per unit of
+    /// its first, so a sell-side amount for `USDEUR` is multiplied and a
+    /// buy-side amount is divided. Without a `dealt_currency` the amount is
+    /// taken to be in the sell currency. Returns `None` if the rate is
+    /// missing or zero.
+    pub fn convert(&self, amount: f64) -> Option<f64> {
+        let rate = self.rate.filter(|rate| *rate != 0.0)?;
+        let dealt = self
+            .dealt_currency
+            .as_deref()
+            .or(self.sell_currency.as_deref());
+        let base = self
+            .currency_pair
+            .as_deref()
+            .and_then(|pair| pair.get(..3))
+            .or(self.sell_currency.as_deref());
+        if dealt == base {
+            Some(amount * rate)
+        } else {
+            Some(amount / rate)
+        }
+    }
 }
 
 /// Parameters for getting current FX rate.
@@ -305,10 +402,10 @@ pub struct GetFxRateParams {
     pub buy_currency: String,
     /// Sell amount (mutually exclusive with buy_amount).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sell_amount: Option<f64>,
+    pub sell_amount: Option<Amount>,
     /// Buy amount (mutually exclusive with sell_amount).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub buy_amount: Option<f64>,
+    pub buy_amount: Option<Amount>,
     /// Conversion date.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conversion_date: Option<String>,
@@ -327,14 +424,14 @@ impl GetFxRateParams {
     }
 
     /// Set sell amount.
-    pub fn sell_amount(mut self, amount: f64) -> Self {
-        self.sell_amount = Some(amount);
+    pub fn sell_amount(mut self, amount: impl Into<Amount>) -> Self {
+        self.sell_amount = Some(amount.into());
         self
     }
 
     /// Set buy amount.
-    pub fn buy_amount(mut self, amount: f64) -> Self {
-        self.buy_amount = Some(amount);
+    pub fn buy_amount(mut self, amount: impl Into<Amount>) -> Self {
+        self.buy_amount = Some(amount.into());
         self
     }
 
@@ -356,10 +453,10 @@ pub struct CreateQuoteRequest {
     pub validity: String,
     /// Sell amount (mutually exclusive with buy_amount).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sell_amount: Option<f64>,
+    pub sell_amount: Option<Amount>,
     /// Buy amount (mutually exclusive with sell_amount).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub buy_amount: Option<f64>,
+    pub buy_amount: Option<Amount>,
     /// Conversion date.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conversion_date: Option<String>,
@@ -383,14 +480,14 @@ impl CreateQuoteRequest {
     }
 
     /// Set sell amount.
-    pub fn sell_amount(mut self, amount: f64) -> Self {
-        self.sell_amount = Some(amount);
+    pub fn sell_amount(mut self, amount: impl Into<Amount>) -> Self {
+        self.sell_amount = Some(amount.into());
         self
     }
 
     /// Set buy amount.
-    pub fn buy_amount(mut self, amount: f64) -> Self {
-        self.buy_amount = Some(amount);
+    pub fn buy_amount(mut self, amount: impl Into<Amount>) -> Self {
+        self.buy_amount = Some(amount.into());
         self
     }
 
@@ -410,10 +507,10 @@ pub struct RateQuoteRequest {
     pub buy_currency: String,
     /// Amount (in sell currency).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sell_amount: Option<f64>,
+    pub sell_amount: Option<Amount>,
     /// Amount (in buy currency).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub buy_amount: Option<f64>,
+    pub buy_amount: Option<Amount>,
     /// Value date.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conversion_date: Option<String>,
@@ -432,14 +529,112 @@ impl RateQuoteRequest {
     }
 
     /// Set sell amount.
-    pub fn sell_amount(mut self, amount: f64) -> Self {
-        self.sell_amount = Some(amount);
+    pub fn sell_amount(mut self, amount: impl Into<Amount>) -> Self {
+        self.sell_amount = Some(amount.into());
         self
     }
 
     /// Set buy amount.
-    pub fn buy_amount(mut self, amount: f64) -> Self {
-        self.buy_amount = Some(amount);
+    pub fn buy_amount(mut self, amount: impl Into<Amount>) -> Self {
+        self.buy_amount = Some(amount.into());
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query_of<Q: Serialize>(params: &Q) -> String {
+        reqwest::Client::new()
+            .get("http://localhost/")
+            .query(params)
+            .build()
+            .unwrap()
+            .url()
+            .query()
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    #[test]
+    fn test_list_conversions_params_query() {
+        let params = ListConversionsParams::new()
+            .status("SETTLED")
+            .buy_currency("EUR")
+            .sell_currency("USD")
+            .from_created_at("2024-05-01T00:00:00+0000")
+            .to_created_at("2024-05-31T23:59:59+0000")
+            .page_size(50);
+
+        assert_eq!(
+            query_of(&params),
+            "status=SETTLED&buy_currency=EUR&sell_currency=USD\
+             &from_created_at=2024-05-01T00%3A00%3A00%2B0000\
+             &to_created_at=2024-05-31T23%3A59%3A59%2B0000&page_size=50"
+        );
+    }
+
+    #[test]
+    fn test_list_conversions_params_empty_query() {
+        assert_eq!(query_of(&ListConversionsParams::new()), "");
+    }
+
+    #[test]
+    fn test_is_amendable_by_status() {
+        let conversion = |status: &str, cutoff: Option<&str>| -> Conversion {
+            serde_json::from_value(serde_json::json!({
+                "conversion_id": "conv_1",
+                "status": status,
+                "settlement_cutoff_time": cutoff
+            }))
+            .unwrap()
+        };
+        let now = DateTime::parse_from_rfc3339("2024-05-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert!(conversion("AWAITING_FUNDS", None).is_amendable_at(now));
+        assert!(
+            conversion("PENDING_SETTLEMENT", Some("2024-05-11T10:00:00+0000")).is_amendable_at(now)
+        );
+        assert!(
+            !conversion("AWAITING_FUNDS", Some("2024-05-10T10:00:00+0000")).is_amendable_at(now)
+        );
+        assert!(!conversion("SETTLED", None).is_amendable_at(now));
+        assert!(!conversion("CANCELLED", None).is_amendable_at(now));
+    }
+
+    fn fx_rate(dealt_currency: &str) -> FxRate {
+        serde_json::from_value(serde_json::json!({
+            "rate": 0.8,
+            "sell_currency": "USD",
+            "buy_currency": "EUR",
+            "currency_pair": "USDEUR",
+            "dealt_currency": dealt_currency,
+            "conversion_date": "2024-05-02"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_fx_rate_convert_sell_side() {
+        let rate = fx_rate("USD");
+        assert_eq!(rate.conversion_date.as_deref(), Some("2024-05-02"));
+        assert_eq!(rate.inverse_rate(), Some(1.25));
+        assert_eq!(rate.convert(100.0), Some(80.0));
+    }
+
+    #[test]
+    fn test_fx_rate_convert_buy_side() {
+        let rate = fx_rate("EUR");
+        assert_eq!(rate.convert(80.0), Some(100.0));
+
+        let missing = FxRate {
+            rate: None,
+            ..fx_rate("EUR")
+        };
+        assert_eq!(missing.convert(80.0), None);
+        assert_eq!(missing.inverse_rate(), None);
+    }
+}
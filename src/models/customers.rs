@@ -5,6 +5,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::common::{Timestamp, capped_page_size};
+
 /// A customer.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Customer {
@@ -41,12 +43,17 @@ pub struct Customer {
     /// Metadata.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Value>,
+    /// Client secret for browser or app use; only returned on create.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Updated timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// Customer address.
@@ -243,22 +250,28 @@ impl ListCustomersParams {
         self
     }
 
-    /// Set page size.
+    /// Set page size, capped at 1000.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 1000));
         self
     }
 }
 
 /// Response for listing customers.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListCustomersResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of customers.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<Customer>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
 
 /// Client secret response.
@@ -271,3 +284,50 @@ pub struct ClientSecretResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expired_time: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::common::{parse_timestamp, timestamp_to_utc};
+
+    #[test]
+    fn test_customer_full_payload() {
+        let customer: Customer = serde_json::from_value(serde_json::json!({
+            "id": "cus_1",
+            "request_id": "req_1",
+            "merchant_customer_id": "merchant_42",
+            "first_name": "Jane",
+            "last_name": "Doe",
+            "email": "jane@example.com",
+            "phone_number": "+61400000000",
+            "business_name": "Doe Pty Ltd",
+            "address": {
+                "street": "1 George St",
+                "city": "Sydney",
+                "state": "NSW",
+                "postcode": "2000",
+                "country_code": "AU"
+            },
+            "metadata": {"tier": "gold"},
+            "client_secret": "secret_1",
+            "created_at": "2024-05-01T00:00:00+0000",
+            "updated_at": "2024-05-02T00:00:00+0000"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            customer.merchant_customer_id.as_deref(),
+            Some("merchant_42")
+        );
+        assert_eq!(customer.phone_number.as_deref(), Some("+61400000000"));
+        let address = customer.address.unwrap();
+        assert_eq!(address.city.as_deref(), Some("Sydney"));
+        assert_eq!(address.country_code.as_deref(), Some("AU"));
+        assert_eq!(customer.metadata.unwrap()["tier"], "gold");
+        assert_eq!(customer.client_secret.as_deref(), Some("secret_1"));
+        assert_eq!(
+            customer.updated_at.as_ref().and_then(timestamp_to_utc),
+            parse_timestamp("2024-05-02T00:00:00+0000")
+        );
+    }
+}
@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::common::Timestamp;
+
 /// A deposit received on a global account.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Deposit {
@@ -23,7 +25,8 @@ pub struct Deposit {
     pub statement_ref: Option<String>,
     /// When the deposit was created.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
 }
 
 /// Parameters for listing deposits.
@@ -81,12 +84,18 @@ impl ListDepositsParams {
 }
 
 /// Response for listing deposits.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListDepositsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of deposits.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<Deposit>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::common::Timestamp;
+
 /// A financial transaction.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FinancialTransaction {
@@ -51,13 +53,16 @@ pub struct FinancialTransaction {
     pub currency_pair: Option<String>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Settled timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub settled_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub settled_at: Option<Timestamp>,
     /// Estimated settled timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub estimated_settled_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub estimated_settled_at: Option<Timestamp>,
 }
 
 /// Parameters for listing financial transactions.
@@ -145,12 +150,18 @@ impl ListFinancialTransactionsParams {
 }
 
 /// Response for listing financial transactions.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListFinancialTransactionsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of financial transactions.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<FinancialTransaction>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
@@ -5,6 +5,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::common::Timestamp;
+
 /// A global account in the list response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GlobalAccount {
@@ -54,6 +56,128 @@ pub struct GlobalAccount {
     pub alternate_account_identifiers: Option<AlternateAccountIdentifiers>,
 }
 
+impl GlobalAccount {
+    /// The supported clearing systems, parsed into [`ClearingSystem`].
+    pub fn clearing_systems_typed(&self) -> Vec<ClearingSystem> {
+        self.clearing_systems
+            .iter()
+            .map(|system| ClearingSystem::from(system.as_str()))
+            .collect()
+    }
+}
+
+/// A clearing system a global account can receive funds through.
+///
+/// The API reports these with inconsistent spelling (`ACH`, `Faster Payments`,
+/// `Bacs`), so parsing ignores case and treats spaces and hyphens as
+/// underscores. Unrecognised systems are kept as [`ClearingSystem::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum ClearingSystem {
+    /// US Automated Clearing House.
+    Ach,
+    /// UK Bacs.
+    Bacs,
+    /// Bank transfer.
+    Bank,
+    /// UK CHAPS.
+    Chaps,
+    /// Singapore FAST.
+    Fast,
+    /// UK Faster Payments.
+    FasterPayments,
+    /// US Fedwire.
+    Fedwire,
+    /// Hong Kong Faster Payment System.
+    Fps,
+    /// Singapore GIRO.
+    Giro,
+    /// Hong Kong HOFINET.
+    Hofinet,
+    /// Canada Interac e-Transfer.
+    InteracETransfer,
+    /// Singapore MEPS.
+    Meps,
+    /// Real-time gross settlement.
+    Rtgs,
+    /// Single Euro Payments Area.
+    Sepa,
+    /// SWIFT.
+    Swift,
+    /// Japan Zengin.
+    Zengin,
+    /// A clearing system not known to this version of the client.
+    Other(String),
+}
+
+impl ClearingSystem {
+    /// The clearing system as spelled by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ClearingSystem::Ach => "ACH",
+            ClearingSystem::Bacs => "Bacs",
+            ClearingSystem::Bank => "Bank",
+            ClearingSystem::Chaps => "CHAPS",
+            ClearingSystem::Fast => "FAST",
+            ClearingSystem::FasterPayments => "Faster Payments",
+            ClearingSystem::Fedwire => "Fedwire",
+            ClearingSystem::Fps => "FPS",
+            ClearingSystem::Giro => "GIRO",
+            ClearingSystem::Hofinet => "HOFINET",
+            ClearingSystem::InteracETransfer => "Interac e-Transfer",
+            ClearingSystem::Meps => "MEPS",
+            ClearingSystem::Rtgs => "RTGS",
+            ClearingSystem::Sepa => "SEPA",
+            ClearingSystem::Swift => "SWIFT",
+            ClearingSystem::Zengin => "Zengin",
+            ClearingSystem::Other(other) => other,
+        }
+    }
+}
+
+impl From<&str> for ClearingSystem {
+    fn from(value: &str) -> Self {
+        let normalized = value.trim().to_ascii_uppercase().replace([' ', '-'], "_");
+        match normalized.as_str() {
+            "ACH" => ClearingSystem::Ach,
+            "BACS" => ClearingSystem::Bacs,
+            "BANK" => ClearingSystem::Bank,
+            "CHAPS" => ClearingSystem::Chaps,
+            "FAST" => ClearingSystem::Fast,
+            "FASTER_PAYMENTS" => ClearingSystem::FasterPayments,
+            "FEDWIRE" => ClearingSystem::Fedwire,
+            "FPS" => ClearingSystem::Fps,
+            "GIRO" => ClearingSystem::Giro,
+            "HOFINET" => ClearingSystem::Hofinet,
+            "INTERAC_E_TRANSFER" => ClearingSystem::InteracETransfer,
+            "MEPS" => ClearingSystem::Meps,
+            "RTGS" => ClearingSystem::Rtgs,
+            "SEPA" => ClearingSystem::Sepa,
+            "SWIFT" => ClearingSystem::Swift,
+            "ZENGIN" => ClearingSystem::Zengin,
+            _ => ClearingSystem::Other(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for ClearingSystem {
+    fn from(value: String) -> Self {
+        ClearingSystem::from(value.as_str())
+    }
+}
+
+impl From<ClearingSystem> for String {
+    fn from(system: ClearingSystem) -> Self {
+        system.as_str().to_string()
+    }
+}
+
+impl std::fmt::Display for ClearingSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// A detailed global account (returned from create/get endpoints).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActiveGlobalAccount {
@@ -144,13 +268,21 @@ pub struct RoutingCode {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum RoutingCodeType {
+    #[serde(alias = "ACH")]
     Ach,
+    #[serde(alias = "BSB")]
     Bsb,
+    #[serde(alias = "SORT_CODE")]
     SortCode,
+    #[serde(alias = "BANK_CODE")]
     BankCode,
+    #[serde(alias = "BRANCH_CODE")]
     BranchCode,
+    #[serde(alias = "FEDWIRE")]
     Fedwire,
+    #[serde(alias = "TRANSIT_NUMBER")]
     TransitNumber,
+    #[serde(alias = "INSTITUTION_NUMBER")]
     InstitutionNumber,
 }
 
@@ -173,6 +305,24 @@ pub struct AlternateIdentifier {
     pub account_identifier: Option<String>,
 }
 
+impl AlternateIdentifier {
+    /// Create an identifier for a clearing system.
+    pub fn new(
+        clearing_system: impl Into<ClearingSystem>,
+        account_identifier: impl Into<String>,
+    ) -> Self {
+        Self {
+            clearing_system: Some(clearing_system.into().into()),
+            account_identifier: Some(account_identifier.into()),
+        }
+    }
+
+    /// The clearing system, parsed into [`ClearingSystem`].
+    pub fn clearing_system_typed(&self) -> Option<ClearingSystem> {
+        self.clearing_system.as_deref().map(ClearingSystem::from)
+    }
+}
+
 /// Request to create a global account.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateGlobalAccountRequest {
@@ -225,6 +375,23 @@ impl CreateGlobalAccountRequest {
         self.deposit_conversion_currency = Some(currency.into());
         self
     }
+
+    /// Add an alternate account identifier for a clearing system.
+    pub fn alternate_identifier(
+        mut self,
+        clearing_system: impl Into<ClearingSystem>,
+        account_identifier: impl Into<String>,
+    ) -> Self {
+        self.alternate_account_identifiers
+            .get_or_insert(AlternateAccountIdentifiers { identifiers: None })
+            .identifiers
+            .get_or_insert_with(Vec::new)
+            .push(AlternateIdentifier::new(
+                clearing_system,
+                account_identifier,
+            ));
+        self
+    }
 }
 
 /// Request to update a global account.
@@ -321,14 +488,20 @@ impl ListGlobalAccountsParams {
 }
 
 /// Response for listing global accounts.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListGlobalAccountsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of global accounts.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<GlobalAccount>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
 
 /// Parameters for listing transactions.
@@ -384,7 +557,8 @@ pub struct GlobalAccountTransaction {
     pub transaction_type: Option<String>,
     /// Posted timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub post_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub post_at: Option<Timestamp>,
     /// Sender name.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sender_name: Option<String>,
@@ -394,14 +568,20 @@ pub struct GlobalAccountTransaction {
 }
 
 /// Response for listing transactions.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListTransactionsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of transactions.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<GlobalAccountTransaction>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
 
 /// Request to generate a statement letter.
@@ -428,7 +608,8 @@ pub struct StatementLetterResponse {
     pub url: Option<String>,
     /// Expiry time for the URL.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub expires_at: Option<Timestamp>,
 }
 
 // Direct Debit Mandate types for global accounts
@@ -441,16 +622,51 @@ pub struct Mandate {
     /// Global account ID.
     pub global_account_id: String,
     /// Mandate status.
-    pub status: String,
+    pub status: MandateStatus,
+    /// Creditor who collects direct debits under the mandate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creditor_name: Option<String>,
     /// Debtor name.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub debtor_name: Option<String>,
     /// Debtor account number.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub debtor_account_number: Option<String>,
+    /// Currency supported by the mandate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Payment scheme the mandate is lodged with, such as Bacs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_scheme: Option<ClearingSystem>,
+    /// When the mandate was lodged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup_time: Option<String>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
+}
+
+impl Mandate {
+    /// Whether direct debits can currently be collected under the mandate.
+    pub fn is_active(&self) -> bool {
+        self.status == MandateStatus::Active
+    }
+}
+
+/// Status of a direct debit mandate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MandateStatus {
+    /// The mandate is in force.
+    #[serde(alias = "active")]
+    Active,
+    /// The mandate was cancelled.
+    #[serde(alias = "cancelled")]
+    Cancelled,
+    /// A status not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Request to create a mandate.
@@ -488,12 +704,117 @@ impl CreateMandateRequest {
 }
 
 /// Response for listing mandates.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListMandatesResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of mandates.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<Mandate>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clearing_systems_typed() {
+        let account: GlobalAccount = serde_json::from_value(serde_json::json!({
+            "id": "ga_1",
+            "account_name": "Acme Ltd",
+            "clearing_systems": ["ACH", "Faster Payments", "Bacs", "sepa", "Interac e-Transfer", "PIX"],
+            "country_code": "GB",
+            "currency": "GBP",
+            "payment_methods": ["LOCAL"],
+            "status": "ACTIVE"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            account.clearing_systems_typed(),
+            vec![
+                ClearingSystem::Ach,
+                ClearingSystem::FasterPayments,
+                ClearingSystem::Bacs,
+                ClearingSystem::Sepa,
+                ClearingSystem::InteracETransfer,
+                ClearingSystem::Other("PIX".to_string()),
+            ]
+        );
+        assert_eq!(account.clearing_systems[1], "Faster Payments");
+
+        let system: ClearingSystem = serde_json::from_str("\"FASTER_PAYMENTS\"").unwrap();
+        assert_eq!(system, ClearingSystem::FasterPayments);
+        assert_eq!(
+            serde_json::to_string(&system).unwrap(),
+            "\"Faster Payments\""
+        );
+    }
+
+    #[test]
+    fn test_alternate_identifiers_round_trip() {
+        let request = CreateGlobalAccountRequest::new("req_1", "US", "USD", vec!["LOCAL".into()])
+            .alternate_identifier(ClearingSystem::Fedwire, "021000021")
+            .alternate_identifier("PIX", "pix-key-1");
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json["alternate_account_identifiers"],
+            serde_json::json!({
+                "identifiers": [
+                    {"clearing_system": "Fedwire", "account_identifier": "021000021"},
+                    {"clearing_system": "PIX", "account_identifier": "pix-key-1"}
+                ]
+            })
+        );
+
+        let parsed: CreateGlobalAccountRequest = serde_json::from_value(json).unwrap();
+        let identifiers = parsed
+            .alternate_account_identifiers
+            .unwrap()
+            .identifiers
+            .unwrap();
+        assert_eq!(
+            identifiers[0].clearing_system_typed(),
+            Some(ClearingSystem::Fedwire)
+        );
+        assert_eq!(
+            identifiers[1].clearing_system_typed(),
+            Some(ClearingSystem::Other("PIX".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_mandate_serde() {
+        let mandate: Mandate = serde_json::from_value(serde_json::json!({
+            "id": "mdt_1",
+            "global_account_id": "ga_1",
+            "status": "ACTIVE",
+            "creditor_name": "Utility Co",
+            "debtor_name": "Acme Ltd",
+            "currency": "GBP",
+            "payment_scheme": "BACS",
+            "setup_time": "2024-05-01T09:00:00+00:00"
+        }))
+        .unwrap();
+        assert!(mandate.is_active());
+        assert_eq!(mandate.creditor_name.as_deref(), Some("Utility Co"));
+        assert_eq!(mandate.payment_scheme, Some(ClearingSystem::Bacs));
+
+        let json = serde_json::to_value(&mandate).unwrap();
+        assert_eq!(json["status"], "ACTIVE");
+        assert_eq!(json["payment_scheme"], "Bacs");
+
+        let status: MandateStatus = serde_json::from_str("\"cancelled\"").unwrap();
+        assert_eq!(status, MandateStatus::Cancelled);
+        let status: MandateStatus = serde_json::from_str("\"SUSPENDED\"").unwrap();
+        assert_eq!(status, MandateStatus::Unknown("SUSPENDED".to_string()));
+    }
 }
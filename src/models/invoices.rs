@@ -5,15 +5,35 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::common::{Timestamp, capped_page_size};
+
+/// Status of an invoice.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InvoiceStatus {
+    /// The invoice has been sent out for payment.
+    #[serde(alias = "sent")]
+    Sent,
+    /// The invoice has been paid.
+    #[serde(alias = "paid")]
+    Paid,
+    /// The latest payment attempt failed.
+    #[serde(alias = "payment_failed")]
+    PaymentFailed,
+    /// A status not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
 /// An invoice.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Invoice {
     /// Invoice ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
-    /// Invoice status (SENT, PAID, PAYMENT_FAILED).
+    /// Invoice status.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<InvoiceStatus>,
     /// Currency (3-letter ISO-4217).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<String>,
@@ -26,30 +46,56 @@ pub struct Invoice {
     /// Associated payment intent ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_intent_id: Option<String>,
+    /// Total amount of the invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_amount: Option<f64>,
     /// Billing period start.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub period_start_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub period_start_at: Option<Timestamp>,
     /// Billing period end.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub period_end_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub period_end_at: Option<Timestamp>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
+    /// Updated timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
     /// Paid timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub paid_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub paid_at: Option<Timestamp>,
     /// Last payment attempt timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_payment_attempt_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub last_payment_attempt_at: Option<Timestamp>,
     /// Next payment attempt timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_payment_attempt_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub next_payment_attempt_at: Option<Timestamp>,
     /// Number of past payment attempts.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub past_payment_attempt_count: Option<i32>,
     /// Remaining payment attempts.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remaining_payment_attempt_count: Option<i32>,
+    /// Line items, when included in the payload.
+    ///
+    /// The invoice endpoints usually omit these; use
+    /// [`Invoices::list_items`](crate::resources::Invoices::list_items) to fetch them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<InvoiceItem>,
+}
+
+impl Invoice {
+    /// Whether the invoice has been paid.
+    pub fn is_paid(&self) -> bool {
+        self.status == Some(InvoiceStatus::Paid)
+    }
 }
 
 /// An invoice item.
@@ -78,13 +124,18 @@ pub struct InvoiceItem {
     pub unit_amount: Option<f64>,
     /// Period start.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub period_start_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub period_start_at: Option<Timestamp>,
     /// Period end.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub period_end_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub period_end_at: Option<Timestamp>,
     /// Price ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub price_id: Option<String>,
+    /// Price subscribed by the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<Value>,
     /// Subscription item ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription_item_id: Option<String>,
@@ -140,33 +191,45 @@ impl ListInvoicesParams {
         self
     }
 
-    /// Set page size.
+    /// Set page size, capped at 1000.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 1000));
         self
     }
 }
 
 /// Response for listing invoices.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListInvoicesResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of invoices.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<Invoice>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
 
 /// Response for listing invoice items.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListInvoiceItemsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of items.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<InvoiceItem>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
 
 /// Request for invoice preview.
@@ -199,6 +262,7 @@ pub struct InvoicePreviewItem {
 
 /// Invoice preview response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct InvoicePreviewResponse {
     /// Total amount.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -210,6 +274,63 @@ pub struct InvoicePreviewResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<String>,
     /// Line items.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<Value>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::common::{parse_timestamp, timestamp_to_utc};
+
+    #[test]
+    fn test_deserialize_invoice() {
+        let json = r#"{
+            "id": "inv_123",
+            "status": "PAYMENT_FAILED",
+            "currency": "USD",
+            "customer_id": "cus_456",
+            "subscription_id": "sub_789",
+            "payment_intent_id": "int_abc",
+            "total_amount": 49.99,
+            "period_start_at": "2024-01-01T00:00:00+0000",
+            "period_end_at": "2024-02-01T00:00:00+0000",
+            "created_at": "2024-02-01T00:00:05+0000",
+            "updated_at": "2024-02-01T00:10:00+0000",
+            "past_payment_attempt_count": 1,
+            "remaining_payment_attempt_count": 3,
+            "items": [{
+                "id": "ii_1",
+                "invoice_id": "inv_123",
+                "amount": 49.99,
+                "currency": "USD",
+                "quantity": 1,
+                "price": {"id": "pri_1"}
+            }]
+        }"#;
+
+        let invoice: Invoice = serde_json::from_str(json).unwrap();
+        assert_eq!(invoice.status, Some(InvoiceStatus::PaymentFailed));
+        assert!(!invoice.is_paid());
+        assert_eq!(invoice.customer_id.as_deref(), Some("cus_456"));
+        assert_eq!(invoice.subscription_id.as_deref(), Some("sub_789"));
+        assert_eq!(invoice.total_amount, Some(49.99));
+        assert_eq!(
+            invoice.period_start_at.as_ref().and_then(timestamp_to_utc),
+            parse_timestamp("2024-01-01T00:00:00+0000")
+        );
+        assert_eq!(invoice.items.len(), 1);
+        assert_eq!(invoice.items[0].invoice_id.as_deref(), Some("inv_123"));
+    }
+
+    #[test]
+    fn test_invoice_status_unknown() {
+        let status: InvoiceStatus = serde_json::from_str(r#""VOIDED""#).unwrap();
+        assert_eq!(status, InvoiceStatus::Unknown("VOIDED".to_string()));
+    }
 }
@@ -167,12 +167,18 @@ impl ListIssuingAuthorizationsParams {
 }
 
 /// Response for listing issuing authorizations.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListIssuingAuthorizationsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of authorizations.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<IssuingAuthorization>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
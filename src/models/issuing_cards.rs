@@ -5,6 +5,11 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::common::Timestamp;
+use super::issuing_cardholders::CardholderAddress;
+use super::issuing_config::{BlockedTransactionUsage, TransactionScope, UsageScope};
+use crate::error::{Error, Result};
+
 /// An issued card.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IssuingCard {
@@ -44,9 +49,12 @@ pub struct IssuingCard {
     /// Alert settings.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub alert_settings: Option<Value>,
-    /// Delivery details.
+    /// Delivery details (physical cards only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_details: Option<DeliveryDetails>,
+    /// Postal address the physical card is delivered to.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_details: Option<Value>,
+    pub postal_address: Option<CardholderAddress>,
     /// All card versions.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub all_card_versions: Option<Vec<Value>>,
@@ -61,10 +69,84 @@ pub struct IssuingCard {
     pub created_by: Option<String>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Updated timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
+}
+
+/// Delivery status of a physical card.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CardDeliveryStatus {
+    /// The card is waiting to be printed.
+    #[serde(alias = "pending")]
+    Pending,
+    /// The card has been printed.
+    #[serde(alias = "printed")]
+    Printed,
+    /// Printing the card failed.
+    #[serde(alias = "failed_to_print")]
+    FailedToPrint,
+    /// The card has been handed to the carrier.
+    #[serde(alias = "dispatched")]
+    Dispatched,
+    /// The card is in transit.
+    #[serde(alias = "in_transit")]
+    InTransit,
+    /// The card is out for delivery.
+    #[serde(alias = "out_for_delivery")]
+    OutForDelivery,
+    /// The card has been delivered.
+    #[serde(alias = "delivered")]
+    Delivered,
+    /// Delivery failed.
+    #[serde(alias = "delivery_failed")]
+    DeliveryFailed,
+    /// Delivery is delayed.
+    #[serde(alias = "delivery_delayed")]
+    DeliveryDelayed,
+    /// A status not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Shipping and tracking information for a physical card.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryDetails {
+    /// Delivery status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CardDeliveryStatus>,
+    /// Short description of the status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_description: Option<String>,
+    /// Carrier delivering the card (e.g., AU_POST, DHL).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_vendor: Option<String>,
+    /// Delivery mode (MAIL or EXPRESS).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_mode: Option<String>,
+    /// Delivery mode selected when the card was created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_delivery_mode: Option<String>,
+    /// Whether the delivery is tracked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracked: Option<bool>,
+    /// Tracking number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_number: Option<String>,
+    /// Tracking link, provided once the card is dispatched.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_link: Option<String>,
+    /// Mobile number for the delivery.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mobile_number: Option<String>,
+    /// Last update time of the delivery details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// Sensitive card details (PAN, CVV, expiry).
@@ -115,6 +197,191 @@ pub struct CardLimit {
     pub remaining: Option<f64>,
 }
 
+/// Whether a card can be used once or many times.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AllowedTransactionCount {
+    /// The card is closed after its first authorization.
+    #[serde(alias = "single")]
+    Single,
+    /// The card can be used repeatedly.
+    #[serde(alias = "multiple")]
+    Multiple,
+    /// A value not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Interval a spending limit applies to.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LimitInterval {
+    /// Each individual transaction.
+    #[serde(alias = "per_transaction")]
+    PerTransaction,
+    /// Per day.
+    #[serde(alias = "daily")]
+    Daily,
+    /// Per week.
+    #[serde(alias = "weekly")]
+    Weekly,
+    /// Per month.
+    #[serde(alias = "monthly")]
+    Monthly,
+    /// Over the lifetime of the card.
+    #[serde(alias = "all_time")]
+    AllTime,
+    /// An interval not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A spending limit for an interval.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SpendingLimit {
+    /// Limit amount, greater than 0.
+    pub amount: f64,
+    /// Interval the limit applies to.
+    pub interval: LimitInterval,
+}
+
+/// Transaction limits for a card, in a single currency.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TransactionLimits {
+    /// Currency of the limits (USD if not set).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Spending limits.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub limits: Vec<SpendingLimit>,
+    /// Cash withdrawal limits.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cash_withdrawal_limits: Vec<SpendingLimit>,
+}
+
+/// Authorization controls for an issuing card.
+///
+/// Builds the `authorization_controls` object of
+/// [`CreateIssuingCardRequest`] and [`UpdateCardRequest`]. Empty lists are
+/// omitted, which the API treats as "no restriction".
+///
+/// ```
+/// use airwallex_rs::models::{
+///     AllowedTransactionCount, AuthorizationControls, LimitInterval, TransactionScope,
+///     UsageScope,
+/// };
+///
+/// let controls = AuthorizationControls::new(AllowedTransactionCount::Multiple)
+///     .allow_merchant_category("5812")
+///     .block_usage(TransactionScope::CashWithdrawal, UsageScope::All)
+///     .limit_currency("USD")
+///     .spend_limit(500.0, LimitInterval::Daily);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AuthorizationControls {
+    /// Single or multiple use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_transaction_count: Option<AllowedTransactionCount>,
+    /// Authorizations before this time are rejected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_from: Option<String>,
+    /// Authorizations after this time are rejected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_to: Option<String>,
+    /// Allowed transaction currencies.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_currencies: Vec<String>,
+    /// Allowed merchant category codes (MCCs).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_merchant_categories: Vec<String>,
+    /// Disabled combinations of transaction and usage scope.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked_transaction_usages: Vec<BlockedTransactionUsage>,
+    /// Spending limits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_limits: Option<TransactionLimits>,
+}
+
+impl AuthorizationControls {
+    /// Create controls for a single or multiple use card.
+    pub fn new(count: AllowedTransactionCount) -> Self {
+        Self {
+            allowed_transaction_count: Some(count),
+            ..Default::default()
+        }
+    }
+
+    /// Reject authorizations before this time.
+    pub fn active_from(mut self, time: impl Into<String>) -> Self {
+        self.active_from = Some(time.into());
+        self
+    }
+
+    /// Reject authorizations after this time.
+    pub fn active_to(mut self, time: impl Into<String>) -> Self {
+        self.active_to = Some(time.into());
+        self
+    }
+
+    /// Allow transactions in a currency.
+    pub fn allow_currency(mut self, currency: impl Into<String>) -> Self {
+        self.allowed_currencies.push(currency.into());
+        self
+    }
+
+    /// Allow a merchant category code.
+    pub fn allow_merchant_category(mut self, mcc: impl Into<String>) -> Self {
+        self.allowed_merchant_categories.push(mcc.into());
+        self
+    }
+
+    /// Block a transaction scope for the given usage scope.
+    pub fn block_usage(
+        mut self,
+        transaction_scope: TransactionScope,
+        usage_scope: UsageScope,
+    ) -> Self {
+        self.blocked_transaction_usages
+            .push(BlockedTransactionUsage {
+                transaction_scope: Some(transaction_scope),
+                usage_scope: Some(usage_scope),
+            });
+        self
+    }
+
+    /// Set the currency of the spending limits.
+    pub fn limit_currency(mut self, currency: impl Into<String>) -> Self {
+        self.transaction_limits
+            .get_or_insert_with(Default::default)
+            .currency = Some(currency.into());
+        self
+    }
+
+    /// Add a spending limit.
+    pub fn spend_limit(mut self, amount: f64, interval: LimitInterval) -> Self {
+        self.transaction_limits
+            .get_or_insert_with(Default::default)
+            .limits
+            .push(SpendingLimit { amount, interval });
+        self
+    }
+
+    /// Add a cash withdrawal limit.
+    pub fn cash_withdrawal_limit(mut self, amount: f64, interval: LimitInterval) -> Self {
+        self.transaction_limits
+            .get_or_insert_with(Default::default)
+            .cash_withdrawal_limits
+            .push(SpendingLimit { amount, interval });
+        self
+    }
+}
+
+impl From<AuthorizationControls> for Value {
+    fn from(controls: AuthorizationControls) -> Self {
+        serde_json::to_value(controls).expect("authorization controls serialize to JSON")
+    }
+}
+
 /// Request to create an issuing card.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateIssuingCardRequest {
@@ -156,19 +423,22 @@ pub struct CreateIssuingCardRequest {
 
 impl CreateIssuingCardRequest {
     /// Create a new issuing card request.
+    ///
+    /// `authorization_controls` accepts either raw JSON or an
+    /// [`AuthorizationControls`].
     pub fn new(
         cardholder_id: impl Into<String>,
         form_factor: impl Into<String>,
         is_personalized: bool,
         created_by: impl Into<String>,
-        authorization_controls: Value,
+        authorization_controls: impl Into<Value>,
     ) -> Self {
         Self {
             cardholder_id: cardholder_id.into(),
             form_factor: form_factor.into(),
             is_personalized,
             created_by: created_by.into(),
-            authorization_controls,
+            authorization_controls: authorization_controls.into(),
             brand: None,
             nick_name: None,
             activate_on_issue: None,
@@ -193,6 +463,115 @@ impl CreateIssuingCardRequest {
     }
 }
 
+/// Why a card is being reissued.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReissueReason {
+    /// The card was lost.
+    Lost,
+    /// The card was stolen.
+    Stolen,
+    /// The card is expiring or has expired.
+    Expired,
+    /// The physical card is damaged.
+    Damaged,
+}
+
+impl ReissueReason {
+    /// Whether the old card should be closed before the replacement is issued.
+    ///
+    /// Lost and stolen cards may be in someone else's hands, so they are
+    /// closed straight away. Expiring and damaged cards keep working until
+    /// the cardholder switches to the replacement.
+    pub fn closes_card(self) -> bool {
+        matches!(self, ReissueReason::Lost | ReissueReason::Stolen)
+    }
+}
+
+/// Request to replace a card with a new one carrying the same settings.
+#[derive(Debug, Clone)]
+pub struct ReissueRequest {
+    /// Why the card is being replaced.
+    pub reason: ReissueReason,
+    /// Creator name for the new card.
+    pub created_by: String,
+    /// Nickname for the new card, defaulting to the old card's.
+    pub nick_name: Option<String>,
+    /// Activate the new card on issue.
+    pub activate_on_issue: Option<bool>,
+}
+
+impl ReissueRequest {
+    /// Create a new reissue request.
+    pub fn new(reason: ReissueReason, created_by: impl Into<String>) -> Self {
+        Self {
+            reason,
+            created_by: created_by.into(),
+            nick_name: None,
+            activate_on_issue: None,
+        }
+    }
+
+    /// Set the new card's nickname.
+    pub fn nick_name(mut self, name: impl Into<String>) -> Self {
+        self.nick_name = Some(name.into());
+        self
+    }
+
+    /// Set activate on issue.
+    pub fn activate_on_issue(mut self, activate: bool) -> Self {
+        self.activate_on_issue = Some(activate);
+        self
+    }
+
+    /// Build the create request for a replacement of `card`.
+    ///
+    /// Copies the cardholder, form factor, brand, authorization controls,
+    /// alert settings, client data, metadata and postal address.
+    pub fn to_create_request(&self, card: &IssuingCard) -> Result<CreateIssuingCardRequest> {
+        let missing = |field: &str| {
+            Error::Validation(format!(
+                "card {} has no {}; cannot reissue",
+                card.card_id.as_deref().unwrap_or("<unknown>"),
+                field
+            ))
+        };
+        let cardholder_id = card
+            .cardholder_id
+            .clone()
+            .ok_or_else(|| missing("cardholder_id"))?;
+        let form_factor = card
+            .form_factor
+            .clone()
+            .ok_or_else(|| missing("form_factor"))?;
+        let authorization_controls = card
+            .authorization_controls
+            .clone()
+            .ok_or_else(|| missing("authorization_controls"))?;
+        let postal_address = card
+            .postal_address
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()?;
+
+        Ok(CreateIssuingCardRequest {
+            brand: card.brand.clone(),
+            nick_name: self.nick_name.clone().or_else(|| card.nick_name.clone()),
+            activate_on_issue: self.activate_on_issue,
+            postal_address,
+            alert_settings: card.alert_settings.clone(),
+            client_data: card.client_data.clone(),
+            metadata: card.metadata.clone(),
+            ..CreateIssuingCardRequest::new(
+                cardholder_id,
+                form_factor,
+                card.is_personalized.unwrap_or(false),
+                self.created_by.clone(),
+                authorization_controls,
+            )
+        })
+    }
+}
+
 /// Request to update a card.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct UpdateCardRequest {
@@ -233,6 +612,12 @@ impl UpdateCardRequest {
         self.nick_name = Some(name.into());
         self
     }
+
+    /// Set authorization controls.
+    pub fn authorization_controls(mut self, controls: impl Into<Value>) -> Self {
+        self.authorization_controls = Some(controls.into());
+        self
+    }
 }
 
 /// Parameters for listing cards.
@@ -285,6 +670,12 @@ impl ListCardsParams {
         self
     }
 
+    /// Only return cards updated at or after this ISO 8601 time.
+    pub fn from_updated_at(mut self, time: impl Into<String>) -> Self {
+        self.from_updated_at = Some(time.into());
+        self
+    }
+
     /// Set page number.
     pub fn page_num(mut self, num: i32) -> Self {
         self.page_num = Some(num);
@@ -299,12 +690,103 @@ impl ListCardsParams {
 }
 
 /// Response for listing cards.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListCardsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of cards.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<IssuingCard>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorization_controls_json() {
+        let controls = AuthorizationControls::new(AllowedTransactionCount::Multiple)
+            .allow_currency("USD")
+            .allow_merchant_category("5812")
+            .allow_merchant_category("5814")
+            .block_usage(TransactionScope::CashWithdrawal, UsageScope::All)
+            .block_usage(
+                TransactionScope::OnlineTransaction,
+                UsageScope::International,
+            )
+            .limit_currency("USD")
+            .spend_limit(200.0, LimitInterval::PerTransaction)
+            .spend_limit(1000.0, LimitInterval::Monthly)
+            .cash_withdrawal_limit(100.0, LimitInterval::Daily);
+
+        let request =
+            CreateIssuingCardRequest::new("chd_1", "VIRTUAL", false, "Ops Team", controls);
+        assert_eq!(
+            serde_json::to_value(&request).unwrap()["authorization_controls"],
+            serde_json::json!({
+                "allowed_transaction_count": "MULTIPLE",
+                "allowed_currencies": ["USD"],
+                "allowed_merchant_categories": ["5812", "5814"],
+                "blocked_transaction_usages": [
+                    {"transaction_scope": "CASH_WITHDRAWAL", "usage_scope": "ALL"},
+                    {"transaction_scope": "ONLINE_TRANSACTION", "usage_scope": "INTERNATIONAL"}
+                ],
+                "transaction_limits": {
+                    "currency": "USD",
+                    "limits": [
+                        {"amount": 200.0, "interval": "PER_TRANSACTION"},
+                        {"amount": 1000.0, "interval": "MONTHLY"}
+                    ],
+                    "cash_withdrawal_limits": [
+                        {"amount": 100.0, "interval": "DAILY"}
+                    ]
+                }
+            })
+        );
+
+        let single = AuthorizationControls::new(AllowedTransactionCount::Single);
+        assert_eq!(
+            Value::from(single),
+            serde_json::json!({"allowed_transaction_count": "SINGLE"})
+        );
+    }
+
+    #[test]
+    fn test_deserialize_delivery_details() {
+        let json = r#"{
+            "card_id": "card_123",
+            "form_factor": "PHYSICAL",
+            "delivery_details": {
+                "delivery_mode": "EXPRESS",
+                "delivery_vendor": "DHL",
+                "status": "IN_TRANSIT",
+                "status_description": "Departed facility",
+                "tracked": true,
+                "tracking_number": "JD0001",
+                "tracking_link": "https://example.com/track/JD0001",
+                "updated_at": "2024-03-01T10:00:00+0000"
+            },
+            "postal_address": {
+                "city": "Sydney",
+                "country": "AU",
+                "line1": "1 George St",
+                "postcode": "2000"
+            }
+        }"#;
+
+        let card: IssuingCard = serde_json::from_str(json).unwrap();
+        let delivery = card.delivery_details.unwrap();
+        assert_eq!(delivery.status, Some(CardDeliveryStatus::InTransit));
+        assert_eq!(delivery.delivery_vendor.as_deref(), Some("DHL"));
+        assert_eq!(delivery.tracking_number.as_deref(), Some("JD0001"));
+        assert_eq!(delivery.tracked, Some(true));
+        assert_eq!(card.postal_address.unwrap().city.as_deref(), Some("Sydney"));
+    }
 }
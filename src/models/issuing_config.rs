@@ -4,25 +4,35 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::common::Timestamp;
+
 /// Transaction scope types.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TransactionScope {
     /// All transactions.
+    #[serde(alias = "all_transactions")]
     AllTransactions,
     /// Online transactions.
+    #[serde(alias = "online_transaction")]
     OnlineTransaction,
     /// Contactless transactions.
+    #[serde(alias = "contactless_transaction")]
     ContactlessTransaction,
     /// Contact chip transactions.
+    #[serde(alias = "contact_chip_transaction")]
     ContactChipTransaction,
     /// Magstripe transactions.
+    #[serde(alias = "magstripe")]
     Magstripe,
     /// Cash withdrawal transactions.
+    #[serde(alias = "cash_withdrawal")]
     CashWithdrawal,
     /// Bill payment transactions.
+    #[serde(alias = "bill_payment")]
     BillPayment,
     /// Account funding transactions.
+    #[serde(alias = "account_funding")]
     AccountFunding,
 }
 
@@ -31,10 +41,13 @@ pub enum TransactionScope {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum UsageScope {
     /// All (domestic and international).
+    #[serde(alias = "all")]
     All,
     /// International only.
+    #[serde(alias = "international")]
     International,
     /// Domestic only.
+    #[serde(alias = "domestic")]
     Domestic,
 }
 
@@ -43,8 +56,10 @@ pub enum UsageScope {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RemoteAuthDefaultAction {
     /// Authorize the transaction.
+    #[serde(alias = "authorized")]
     Authorized,
     /// Decline the transaction.
+    #[serde(alias = "declined")]
     Declined,
 }
 
@@ -53,10 +68,13 @@ pub enum RemoteAuthDefaultAction {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RemoteProvisioningDefaultAction {
     /// Unknown.
+    #[serde(alias = "unknown")]
     Unknown,
     /// Conditional approval.
+    #[serde(alias = "conditional_approval")]
     ConditionalApproval,
     /// Generic decline.
+    #[serde(alias = "generic_decline")]
     GenericDecline,
 }
 
@@ -88,10 +106,12 @@ pub struct RemoteAuthSettings {
     pub shared_secret: Option<String>,
     /// Creation time.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Last update time.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// Remote call method configuration.
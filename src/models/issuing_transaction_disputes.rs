@@ -4,53 +4,108 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::common::{DisputeReasonCode, Timestamp};
+use crate::error::Error;
+
 /// Reason for raising an issuing transaction dispute.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum IssuingDisputeReason {
     /// Suspected fraud.
+    #[serde(alias = "suspected_fraud")]
     SuspectedFraud,
     /// Unauthorized transaction.
+    #[serde(alias = "unauthorized_transaction")]
     UnauthorizedTransaction,
     /// Duplicated transaction.
+    #[serde(alias = "duplicated_transaction")]
     DuplicatedTransaction,
     /// Paid by other means.
+    #[serde(alias = "paid_by_other_means")]
     PaidByOtherMeans,
     /// Goods/service not as described.
+    #[serde(alias = "goods_service_not_as_described")]
     GoodsServiceNotAsDescribed,
     /// Goods damaged.
+    #[serde(alias = "goods_damaged")]
     GoodsDamaged,
     /// Goods/service not received.
+    #[serde(alias = "goods_service_not_received")]
     GoodsServiceNotReceived,
     /// Refund unprocessed.
+    #[serde(alias = "refund_unprocessed")]
     RefundUnprocessed,
     /// Goods/service canceled.
+    #[serde(alias = "goods_service_canceled")]
     GoodsServiceCanceled,
     /// Recurring canceled.
+    #[serde(alias = "recurring_canceled")]
     RecurringCanceled,
     /// Other reason.
+    #[serde(alias = "other")]
     Other,
 }
 
+impl DisputeReasonCode for IssuingDisputeReason {
+    fn as_str(&self) -> &str {
+        match self {
+            IssuingDisputeReason::SuspectedFraud => "SUSPECTED_FRAUD",
+            IssuingDisputeReason::UnauthorizedTransaction => "UNAUTHORIZED_TRANSACTION",
+            IssuingDisputeReason::DuplicatedTransaction => "DUPLICATED_TRANSACTION",
+            IssuingDisputeReason::PaidByOtherMeans => "PAID_BY_OTHER_MEANS",
+            IssuingDisputeReason::GoodsServiceNotAsDescribed => "GOODS_SERVICE_NOT_AS_DESCRIBED",
+            IssuingDisputeReason::GoodsDamaged => "GOODS_DAMAGED",
+            IssuingDisputeReason::GoodsServiceNotReceived => "GOODS_SERVICE_NOT_RECEIVED",
+            IssuingDisputeReason::RefundUnprocessed => "REFUND_UNPROCESSED",
+            IssuingDisputeReason::GoodsServiceCanceled => "GOODS_SERVICE_CANCELED",
+            IssuingDisputeReason::RecurringCanceled => "RECURRING_CANCELED",
+            IssuingDisputeReason::Other => "OTHER",
+        }
+    }
+}
+
+impl TryFrom<&str> for IssuingDisputeReason {
+    type Error = Error;
+
+    fn try_from(code: &str) -> Result<Self, Self::Error> {
+        serde_json::from_value(serde_json::Value::String(code.to_string()))
+            .map_err(|_| Error::Validation(format!("Unknown issuing dispute reason '{}'", code)))
+    }
+}
+
+impl From<IssuingDisputeReason> for String {
+    fn from(reason: IssuingDisputeReason) -> Self {
+        reason.as_str().to_string()
+    }
+}
+
 /// Status of an issuing transaction dispute.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum IssuingDisputeStatus {
     /// Draft - not yet submitted.
+    #[serde(alias = "draft")]
     Draft,
     /// Submitted to Airwallex.
+    #[serde(alias = "submitted")]
     Submitted,
     /// Rejected by Airwallex.
+    #[serde(alias = "rejected")]
     Rejected,
     /// Canceled by customer.
+    #[serde(alias = "canceled")]
     Canceled,
     /// In progress with card scheme.
+    #[serde(alias = "in_progress")]
     InProgress,
     /// Won the dispute.
+    #[serde(alias = "won")]
     Won,
     /// Lost the dispute.
+    #[serde(alias = "lost")]
     Lost,
     /// Expired.
+    #[serde(alias = "expired")]
     Expired,
 }
 
@@ -59,24 +114,37 @@ pub enum IssuingDisputeStatus {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum IssuingDisputeDetailedStatus {
     /// Dispute filed.
+    #[serde(alias = "dispute_filed")]
     DisputeFiled,
     /// Pre-arbitration received.
+    #[serde(alias = "pre_arb_received")]
     PreArbReceived,
     /// Pre-arbitration declined by issuer.
+    #[serde(alias = "pre_arb_declined_by_issuer")]
     PreArbDeclinedByIssuer,
     /// Arbitration received.
+    #[serde(alias = "arbitration_received")]
     ArbitrationReceived,
     /// Chargeback declined.
+    #[serde(alias = "chargeback_declined")]
     ChargebackDeclined,
+    /// Dispute declined.
+    #[serde(alias = "dispute_declined")]
+    DisputeDeclined,
     /// Pre-arbitration delivered.
+    #[serde(alias = "pre_arb_delivered")]
     PreArbDelivered,
     /// Pre-arbitration declined by acquirer.
+    #[serde(alias = "pre_arb_declined_by_acquirer")]
     PreArbDeclinedByAcquirer,
     /// Arbitration delivered.
+    #[serde(alias = "arb_delivered")]
     ArbDelivered,
     /// Won.
+    #[serde(alias = "won")]
     Won,
     /// Lost.
+    #[serde(alias = "lost")]
     Lost,
 }
 
@@ -85,8 +153,10 @@ pub enum IssuingDisputeDetailedStatus {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum IssuingDisputeUpdatedBy {
     /// Customer.
+    #[serde(alias = "customer")]
     Customer,
     /// Airwallex.
+    #[serde(alias = "airwallex")]
     Airwallex,
 }
 
@@ -101,7 +171,8 @@ pub struct DisputeUpdateHistoryEntry {
     pub note: Option<String>,
     /// Time when the dispute was updated.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
     /// The party who updated the dispute.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_by: Option<IssuingDisputeUpdatedBy>,
@@ -142,10 +213,12 @@ pub struct IssuingTransactionDispute {
     pub updated_by: Option<IssuingDisputeUpdatedBy>,
     /// Time when the dispute was created.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Time when the dispute was last updated.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// Request to create a transaction dispute.
@@ -384,11 +457,17 @@ impl ListIssuingTransactionDisputesParams {
 }
 
 /// Response for listing transaction disputes.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListIssuingTransactionDisputesResponse {
     /// List of disputes.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<IssuingTransactionDispute>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
     /// Pointer to the next page.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_after: Option<String>,
@@ -396,3 +475,94 @@ pub struct ListIssuingTransactionDisputesResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_before: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query_of<Q: Serialize>(params: &Q) -> String {
+        reqwest::Client::new()
+            .get("http://localhost/")
+            .query(params)
+            .build()
+            .unwrap()
+            .url()
+            .query()
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    #[test]
+    fn test_list_params_enum_filters_query() {
+        let params = ListIssuingTransactionDisputesParams::new()
+            .status(IssuingDisputeStatus::InProgress)
+            .detailed_status(IssuingDisputeDetailedStatus::PreArbDeclinedByIssuer)
+            .reason(IssuingDisputeReason::GoodsServiceNotAsDescribed)
+            .updated_by(IssuingDisputeUpdatedBy::Airwallex);
+
+        assert_eq!(
+            query_of(&params),
+            "status=IN_PROGRESS&detailed_status=PRE_ARB_DECLINED_BY_ISSUER\
+             &reason=GOODS_SERVICE_NOT_AS_DESCRIBED&updated_by=AIRWALLEX"
+        );
+    }
+
+    #[test]
+    fn test_enum_query_values_match_api() {
+        let statuses = [
+            (IssuingDisputeStatus::Draft, "DRAFT"),
+            (IssuingDisputeStatus::Submitted, "SUBMITTED"),
+            (IssuingDisputeStatus::Rejected, "REJECTED"),
+            (IssuingDisputeStatus::Canceled, "CANCELED"),
+            (IssuingDisputeStatus::InProgress, "IN_PROGRESS"),
+            (IssuingDisputeStatus::Won, "WON"),
+            (IssuingDisputeStatus::Lost, "LOST"),
+            (IssuingDisputeStatus::Expired, "EXPIRED"),
+        ];
+        for (status, expected) in statuses {
+            let params = ListIssuingTransactionDisputesParams::new().status(status);
+            assert_eq!(query_of(&params), format!("status={}", expected));
+        }
+
+        let detailed = [
+            (IssuingDisputeDetailedStatus::DisputeFiled, "DISPUTE_FILED"),
+            (
+                IssuingDisputeDetailedStatus::PreArbReceived,
+                "PRE_ARB_RECEIVED",
+            ),
+            (
+                IssuingDisputeDetailedStatus::ArbitrationReceived,
+                "ARBITRATION_RECEIVED",
+            ),
+            (
+                IssuingDisputeDetailedStatus::ChargebackDeclined,
+                "CHARGEBACK_DECLINED",
+            ),
+            (
+                IssuingDisputeDetailedStatus::DisputeDeclined,
+                "DISPUTE_DECLINED",
+            ),
+            (
+                IssuingDisputeDetailedStatus::PreArbDelivered,
+                "PRE_ARB_DELIVERED",
+            ),
+            (
+                IssuingDisputeDetailedStatus::PreArbDeclinedByAcquirer,
+                "PRE_ARB_DECLINED_BY_ACQUIRER",
+            ),
+            (IssuingDisputeDetailedStatus::ArbDelivered, "ARB_DELIVERED"),
+        ];
+        for (status, expected) in detailed {
+            let params = ListIssuingTransactionDisputesParams::new().detailed_status(status);
+            assert_eq!(query_of(&params), format!("detailed_status={}", expected));
+        }
+
+        let params = ListIssuingTransactionDisputesParams::new()
+            .reason(IssuingDisputeReason::DuplicatedTransaction)
+            .updated_by(IssuingDisputeUpdatedBy::Customer);
+        assert_eq!(
+            query_of(&params),
+            "reason=DUPLICATED_TRANSACTION&updated_by=CUSTOMER"
+        );
+    }
+}
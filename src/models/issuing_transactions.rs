@@ -240,12 +240,18 @@ impl ListIssuingTransactionsParams {
 }
 
 /// Response for listing issuing transactions.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListIssuingTransactionsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of transactions.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<IssuingTransaction>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
@@ -5,6 +5,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::common::Timestamp;
+
 /// A linked bank account.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LinkedAccount {
@@ -136,14 +138,20 @@ impl ListLinkedAccountsParams {
 }
 
 /// Response for listing linked accounts.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListLinkedAccountsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of linked accounts.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<LinkedAccount>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
 
 /// Auth initiation request.
@@ -213,5 +221,6 @@ pub struct LinkedAccountMandate {
     pub status: Option<String>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
 }
@@ -1,7 +1,12 @@
 //! Data models for the Airwallex API.
+//!
+//! Airwallex is not consistent about enum casing across products, so enums
+//! accept both `SCREAMING_SNAKE_CASE` and `snake_case` values when
+//! deserializing. They always serialize in the casing the API documents.
 
 pub mod account_capabilities;
 pub mod accounts;
+pub mod amount;
 pub mod balances;
 pub mod batch_transfers;
 pub mod beneficiaries;
@@ -32,11 +37,14 @@ pub mod payment_methods;
 pub mod reconciliation;
 pub mod reference_data;
 pub mod refunds;
+pub mod request_id;
 pub mod settlements;
 pub mod transfers;
+pub mod webhooks;
 
 pub use account_capabilities::*;
 pub use accounts::*;
+pub use amount::*;
 pub use balances::*;
 pub use batch_transfers::*;
 pub use beneficiaries::*;
@@ -67,5 +75,7 @@ pub use payment_methods::*;
 pub use reconciliation::*;
 pub use reference_data::*;
 pub use refunds::*;
+pub use request_id::*;
 pub use settlements::*;
 pub use transfers::*;
+pub use webhooks::*;
@@ -4,11 +4,28 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::beneficiaries::BeneficiaryBankDetails;
+
+/// Entity type of a payer.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PayerEntityType {
+    /// An individual.
+    #[serde(alias = "personal")]
+    Personal,
+    /// A company.
+    #[serde(alias = "company")]
+    Company,
+    /// An entity type not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
 /// A payer contact.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PayerContact {
     /// Unique ID for this payer.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "payer_id", skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     /// Nickname of the payer.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -18,12 +35,24 @@ pub struct PayerContact {
     pub payer: Option<Payer>,
 }
 
+impl PayerContact {
+    /// A human-readable name for the payer.
+    ///
+    /// Prefers the nickname, then the company name, then the personal name.
+    pub fn display_name(&self) -> Option<String> {
+        if let Some(nickname) = self.nickname.as_deref().filter(|n| !n.is_empty()) {
+            return Some(nickname.to_string());
+        }
+        self.payer.as_ref().and_then(Payer::name)
+    }
+}
+
 /// Payer details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Payer {
-    /// Entity type (PERSONAL or COMPANY).
+    /// Entity type.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub entity_type: Option<String>,
+    pub entity_type: Option<PayerEntityType>,
     /// First name (for personal payers).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub first_name: Option<String>,
@@ -42,6 +71,24 @@ pub struct Payer {
     /// Additional info.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_info: Option<PayerAdditionalInfo>,
+    /// Bank details, when returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_details: Option<BeneficiaryBankDetails>,
+}
+
+impl Payer {
+    /// The company name, or the personal name for individuals.
+    pub fn name(&self) -> Option<String> {
+        if let Some(company) = self.company_name.as_deref().filter(|n| !n.is_empty()) {
+            return Some(company.to_string());
+        }
+        let parts: Vec<&str> = [self.first_name.as_deref(), self.last_name.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter(|n| !n.is_empty())
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(" "))
+    }
 }
 
 /// Payer address.
@@ -134,8 +181,8 @@ pub struct CreatePayerRequest {
 /// Payer details for create request.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreatePayerDetails {
-    /// Entity type (PERSONAL or COMPANY) - required.
-    pub entity_type: String,
+    /// Entity type - required.
+    pub entity_type: PayerEntityType,
     /// Address - required.
     pub address: PayerAddress,
     /// First name (for personal payers).
@@ -164,7 +211,7 @@ impl CreatePayerRequest {
     ) -> Self {
         Self {
             payer: CreatePayerDetails {
-                entity_type: "PERSONAL".to_string(),
+                entity_type: PayerEntityType::Personal,
                 address,
                 first_name: Some(first_name.into()),
                 last_name: Some(last_name.into()),
@@ -180,7 +227,7 @@ impl CreatePayerRequest {
     pub fn company(company_name: impl Into<String>, address: PayerAddress) -> Self {
         Self {
             payer: CreatePayerDetails {
-                entity_type: "COMPANY".to_string(),
+                entity_type: PayerEntityType::Company,
                 address,
                 first_name: None,
                 last_name: None,
@@ -285,12 +332,75 @@ impl ListPayersParams {
 }
 
 /// Response for listing payers.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListPayersResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of payers.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<PayerContact>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_payer_contact() {
+        let json = r#"{
+            "payer_id": "pay_123",
+            "nickname": "",
+            "payer": {
+                "entity_type": "COMPANY",
+                "company_name": "Acme Pty Ltd",
+                "address": {
+                    "country_code": "AU",
+                    "city": "Melbourne",
+                    "postcode": "3000",
+                    "street_address": "1 Collins St"
+                },
+                "additional_info": {
+                    "business_registration_number": "123456789"
+                },
+                "bank_details": {
+                    "account_name": "Acme Pty Ltd",
+                    "account_number": "12345678",
+                    "bank_country_code": "AU"
+                }
+            }
+        }"#;
+
+        let contact: PayerContact = serde_json::from_str(json).unwrap();
+        assert_eq!(contact.id.as_deref(), Some("pay_123"));
+        assert_eq!(contact.display_name().as_deref(), Some("Acme Pty Ltd"));
+
+        let payer = contact.payer.unwrap();
+        assert_eq!(payer.entity_type, Some(PayerEntityType::Company));
+        assert_eq!(payer.address.unwrap().city.as_deref(), Some("Melbourne"));
+        let bank = payer.bank_details.unwrap();
+        assert_eq!(bank.account_number.as_deref(), Some("12345678"));
+    }
+
+    #[test]
+    fn test_display_name_fallbacks() {
+        let json = r#"{
+            "nickname": "Mum",
+            "payer": {"entity_type": "PERSONAL", "first_name": "Jane", "last_name": "Doe"}
+        }"#;
+        let mut contact: PayerContact = serde_json::from_str(json).unwrap();
+        assert_eq!(contact.display_name().as_deref(), Some("Mum"));
+
+        contact.nickname = None;
+        assert_eq!(contact.display_name().as_deref(), Some("Jane Doe"));
+
+        contact.payer = None;
+        assert_eq!(contact.display_name(), None);
+    }
 }
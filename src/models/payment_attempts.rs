@@ -5,29 +5,41 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::common::{Timestamp, capped_page_size};
+
 /// Status of a payment attempt.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PaymentAttemptStatus {
     /// The PaymentAttempt has been created.
+    #[serde(alias = "received")]
     Received,
     /// Waiting for customer authentication.
+    #[serde(alias = "authentication_redirected")]
     AuthenticationRedirected,
     /// Authorization request pending.
+    #[serde(alias = "pending_authorization")]
     PendingAuthorization,
     /// Successfully authorized.
+    #[serde(alias = "authorized")]
     Authorized,
     /// Capture requested.
+    #[serde(alias = "capture_requested")]
     CaptureRequested,
     /// Expired.
+    #[serde(alias = "expired")]
     Expired,
     /// Cancelled.
+    #[serde(alias = "cancelled")]
     Cancelled,
     /// Failed.
+    #[serde(alias = "failed")]
     Failed,
     /// Settlement received.
+    #[serde(alias = "settled")]
     Settled,
     /// Funds settled.
+    #[serde(alias = "paid")]
     Paid,
 }
 
@@ -36,19 +48,59 @@ pub enum PaymentAttemptStatus {
 #[serde(rename_all = "snake_case")]
 pub enum PaymentAttemptFailureCode {
     /// Authentication failed.
+    #[serde(alias = "AUTHENTICATION_FAILED")]
     AuthenticationFailed,
     /// Capture failed.
+    #[serde(alias = "CAPTURE_FAILED")]
     CaptureFailed,
     /// Authorization failed.
+    #[serde(alias = "AUTHORIZATION_FAILED")]
     AuthorizationFailed,
     /// Provider unavailable.
+    #[serde(alias = "PROVIDER_UNAVAILABLE")]
     ProviderUnavailable,
     /// System unavailable.
+    #[serde(alias = "SYSTEM_UNAVAILABLE")]
     SystemUnavailable,
     /// Fraud rejected.
+    #[serde(alias = "FRAUD_REJECTED")]
     FraudRejected,
 }
 
+/// Whether a failed payment attempt is worth retrying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeclineKind {
+    /// Temporary decline (e.g. insufficient funds, issuer unavailable); retry later.
+    SoftRetriable,
+    /// Permanent decline (e.g. stolen card, closed account); do not retry.
+    HardFinal,
+    /// Not a decline, or the codes are not recognised.
+    Unknown,
+}
+
+impl DeclineKind {
+    /// Classify a Mastercard merchant advice code.
+    pub fn from_merchant_advice_code(code: &str) -> Self {
+        match code.trim() {
+            "02" | "24" | "25" | "26" | "27" | "28" | "29" | "30" => DeclineKind::SoftRetriable,
+            "01" | "03" | "21" | "22" | "40" | "41" | "42" | "43" => DeclineKind::HardFinal,
+            _ => DeclineKind::Unknown,
+        }
+    }
+
+    /// Classify an issuer response code (ISO 8583 field 39).
+    pub fn from_response_code(code: &str) -> Self {
+        match code.trim().to_ascii_uppercase().as_str() {
+            "05" | "19" | "51" | "59" | "61" | "65" | "75" | "91" | "96" | "1A" => {
+                DeclineKind::SoftRetriable
+            }
+            "04" | "07" | "12" | "14" | "15" | "41" | "43" | "46" | "54" | "57" | "62" | "78"
+            | "R0" | "R1" | "R3" => DeclineKind::HardFinal,
+            _ => DeclineKind::Unknown,
+        }
+    }
+}
+
 /// DCC (Dynamic Currency Conversion) data.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DccData {
@@ -168,10 +220,47 @@ pub struct PaymentAttempt {
     pub settle_via: Option<String>,
     /// Creation time.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Last update time.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
+}
+
+impl PaymentAttempt {
+    /// Classify a failed attempt as a soft or hard decline.
+    ///
+    /// The merchant advice code is checked first, then the issuer's response
+    /// code, then the Airwallex failure code. Attempts that have not failed
+    /// are [`DeclineKind::Unknown`].
+    pub fn decline_kind(&self) -> DeclineKind {
+        if self.status != Some(PaymentAttemptStatus::Failed) {
+            return DeclineKind::Unknown;
+        }
+        let advised = self
+            .merchant_advice_code
+            .as_deref()
+            .map(DeclineKind::from_merchant_advice_code)
+            .unwrap_or(DeclineKind::Unknown);
+        if advised != DeclineKind::Unknown {
+            return advised;
+        }
+        let issuer = self
+            .provider_original_response_code
+            .as_deref()
+            .map(DeclineKind::from_response_code)
+            .unwrap_or(DeclineKind::Unknown);
+        if issuer != DeclineKind::Unknown {
+            return issuer;
+        }
+        match self.failure_code {
+            Some(PaymentAttemptFailureCode::ProviderUnavailable)
+            | Some(PaymentAttemptFailureCode::SystemUnavailable) => DeclineKind::SoftRetriable,
+            Some(PaymentAttemptFailureCode::FraudRejected) => DeclineKind::HardFinal,
+            _ => DeclineKind::Unknown,
+        }
+    }
 }
 
 /// Parameters for listing payment attempts.
@@ -242,20 +331,75 @@ impl ListPaymentAttemptsParams {
         self
     }
 
-    /// Set page size.
+    /// Set page size, capped at 1000.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 1000));
         self
     }
 }
 
 /// Response for listing payment attempts.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListPaymentAttemptsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of payment attempts.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<PaymentAttempt>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed_attempt(fields: Value) -> PaymentAttempt {
+        let mut attempt = serde_json::json!({"id": "att_1", "status": "FAILED"});
+        attempt
+            .as_object_mut()
+            .unwrap()
+            .extend(fields.as_object().unwrap().clone());
+        serde_json::from_value(attempt).unwrap()
+    }
+
+    #[test]
+    fn test_decline_kind() {
+        let insufficient_funds =
+            failed_attempt(serde_json::json!({"provider_original_response_code": "51"}));
+        assert_eq!(
+            insufficient_funds.decline_kind(),
+            DeclineKind::SoftRetriable
+        );
+
+        let stolen = failed_attempt(serde_json::json!({"provider_original_response_code": "43"}));
+        assert_eq!(stolen.decline_kind(), DeclineKind::HardFinal);
+
+        // The advice code overrides an otherwise retriable response code.
+        let do_not_retry = failed_attempt(serde_json::json!({
+            "provider_original_response_code": "05",
+            "merchant_advice_code": "03"
+        }));
+        assert_eq!(do_not_retry.decline_kind(), DeclineKind::HardFinal);
+
+        let unavailable =
+            failed_attempt(serde_json::json!({"failure_code": "provider_unavailable"}));
+        assert_eq!(unavailable.decline_kind(), DeclineKind::SoftRetriable);
+
+        let unrecognised =
+            failed_attempt(serde_json::json!({"provider_original_response_code": "ZZ"}));
+        assert_eq!(unrecognised.decline_kind(), DeclineKind::Unknown);
+
+        let authorized: PaymentAttempt = serde_json::from_value(serde_json::json!({
+            "status": "AUTHORIZED",
+            "provider_original_response_code": "43"
+        }))
+        .unwrap();
+        assert_eq!(authorized.decline_kind(), DeclineKind::Unknown);
+    }
 }
@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::common::capped_page_size;
+
 /// Bank resources (logos, etc.).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BankResources {
@@ -26,7 +28,64 @@ pub struct Bank {
     pub resources: Option<BankResources>,
 }
 
+/// How transactions are triggered with a payment method.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum TransactionMode {
+    /// A single payment initiated by the shopper.
+    #[serde(
+        rename = "oneoff",
+        alias = "ONEOFF",
+        alias = "one_off",
+        alias = "ONE_OFF"
+    )]
+    OneOff,
+    /// Repeated payments against a saved consent.
+    #[serde(rename = "recurring", alias = "RECURRING")]
+    Recurring,
+    /// A mode not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl TransactionMode {
+    /// The mode as sent by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TransactionMode::OneOff => "oneoff",
+            TransactionMode::Recurring => "recurring",
+            TransactionMode::Unknown(mode) => mode,
+        }
+    }
+}
+
+impl From<TransactionMode> for String {
+    fn from(mode: TransactionMode) -> Self {
+        mode.as_str().to_string()
+    }
+}
+
+/// Where the shopper completes a payment.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum PaymentFlow {
+    /// Scanning a QR code shown on a desktop page.
+    #[serde(rename = "webqr", alias = "WEBQR", alias = "qrcode")]
+    WebQr,
+    /// A mobile browser.
+    #[serde(rename = "mweb", alias = "MWEB", alias = "mobile_web")]
+    MobileWeb,
+    /// A mobile app.
+    #[serde(rename = "inapp", alias = "INAPP", alias = "mobile_app")]
+    InApp,
+    /// A flow not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
 /// A payment method type configuration.
+///
+/// The configuration does not say which countries a method is available in;
+/// list with [`ListPaymentMethodTypesParams::country_code`] to get the
+/// methods for a shopper's country.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaymentMethodTypeConfig {
     /// The type of payment method.
@@ -35,17 +94,35 @@ pub struct PaymentMethodTypeConfig {
     /// Whether the payment method is active.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
-    /// Transaction mode (oneoff, recurring).
+    /// Transaction mode.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transaction_mode: Option<String>,
-    /// Supported flows (qrcode, mobile_web, mobile_app).
+    pub transaction_mode: Option<TransactionMode>,
+    /// Supported flows.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flows: Option<Vec<String>>,
+    pub flows: Option<Vec<PaymentFlow>>,
     /// Supported transaction currencies.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_currencies: Option<Vec<String>>,
 }
 
+impl PaymentMethodTypeConfig {
+    /// Whether payments in `currency` are supported.
+    ///
+    /// A method without a currency list is assumed to support any currency.
+    pub fn supports_currency(&self, currency: &str) -> bool {
+        self.transaction_currencies
+            .as_ref()
+            .is_none_or(|currencies| currencies.iter().any(|c| c.eq_ignore_ascii_case(currency)))
+    }
+
+    /// Whether the method supports `flow`.
+    pub fn supports_flow(&self, flow: &PaymentFlow) -> bool {
+        self.flows
+            .as_ref()
+            .is_some_and(|flows| flows.contains(flow))
+    }
+}
+
 /// Parameters for listing payment method types.
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct ListPaymentMethodTypesParams {
@@ -58,9 +135,9 @@ pub struct ListPaymentMethodTypesParams {
     /// Filter by transaction currency (required if country_code is given).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_currency: Option<String>,
-    /// Filter by transaction mode (oneoff, recurring).
+    /// Filter by transaction mode.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transaction_mode: Option<String>,
+    pub transaction_mode: Option<TransactionMode>,
     /// Page number (starting from 0).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_num: Option<i32>,
@@ -81,7 +158,10 @@ impl ListPaymentMethodTypesParams {
         self
     }
 
-    /// Filter by country code.
+    /// Only return methods available to shoppers in `code`.
+    ///
+    /// The API requires [`transaction_currency`](Self::transaction_currency)
+    /// to be set as well.
     pub fn country_code(mut self, code: impl Into<String>) -> Self {
         self.country_code = Some(code.into());
         self
@@ -94,8 +174,8 @@ impl ListPaymentMethodTypesParams {
     }
 
     /// Filter by transaction mode.
-    pub fn transaction_mode(mut self, mode: impl Into<String>) -> Self {
-        self.transaction_mode = Some(mode.into());
+    pub fn transaction_mode(mut self, mode: TransactionMode) -> Self {
+        self.transaction_mode = Some(mode);
         self
     }
 
@@ -105,22 +185,28 @@ impl ListPaymentMethodTypesParams {
         self
     }
 
-    /// Set page size.
+    /// Set page size, capped at 1000.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 1000));
         self
     }
 }
 
 /// Response for listing payment method types.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListPaymentMethodTypesResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of payment method types.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<PaymentMethodTypeConfig>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
 
 /// Parameters for listing banks.
@@ -162,20 +248,88 @@ impl ListBanksParams {
         self
     }
 
-    /// Set page size.
+    /// Set page size, capped at 1000.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 1000));
         self
     }
 }
 
 /// Response for listing banks.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListBanksResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of banks.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<Bank>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_payment_method_types() {
+        let response: ListPaymentMethodTypesResponse = serde_json::from_value(serde_json::json!({
+            "has_more": false,
+            "items": [
+                {
+                    "name": "card",
+                    "active": true,
+                    "transaction_mode": "recurring",
+                    "flows": ["webqr", "mweb", "inapp"],
+                    "transaction_currencies": ["USD", "AUD"]
+                },
+                {
+                    "name": "alipayhk",
+                    "active": true,
+                    "transaction_mode": "oneoff",
+                    "flows": ["mweb", "miniprog"],
+                    "transaction_currencies": ["HKD"]
+                }
+            ]
+        }))
+        .unwrap();
+
+        let card = &response.items[0];
+        assert_eq!(card.transaction_mode, Some(TransactionMode::Recurring));
+        assert!(card.supports_flow(&PaymentFlow::InApp));
+        assert!(card.supports_currency("usd"));
+
+        let alipay = &response.items[1];
+        assert_eq!(alipay.transaction_mode, Some(TransactionMode::OneOff));
+        assert_eq!(
+            alipay.flows.as_deref(),
+            Some(
+                &[
+                    PaymentFlow::MobileWeb,
+                    PaymentFlow::Unknown("miniprog".to_string())
+                ][..]
+            )
+        );
+        assert!(!alipay.supports_flow(&PaymentFlow::WebQr));
+        assert!(!alipay.supports_currency("USD"));
+
+        let params = ListPaymentMethodTypesParams::new()
+            .country_code("HK")
+            .transaction_currency("HKD")
+            .transaction_mode(TransactionMode::OneOff);
+        let request = reqwest::Client::new()
+            .get("https://example.com/")
+            .query(&params)
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.url().query(),
+            Some("country_code=HK&transaction_currency=HKD&transaction_mode=oneoff")
+        );
+    }
 }
@@ -5,6 +5,9 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::common::{Timestamp, capped_page_size};
+use super::payment_attempts::DccData;
+
 /// A payment consent.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaymentConsent {
@@ -58,16 +61,19 @@ pub struct PaymentConsent {
     pub metadata: Option<Value>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Updated timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
 }
 
-/// Next action for a payment consent.
+/// Next action required to complete a payment intent or consent.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NextAction {
-    /// Action type (redirect, redirect_iframe, notify_micro_deposits, retry_micro_debit).
+    /// Action type (e.g., redirect, redirect_form, render_qrcode, call_sdk,
+    /// notify_micro_deposits).
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub action_type: Option<String>,
@@ -83,9 +89,15 @@ pub struct NextAction {
     /// Additional data.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<Value>,
+    /// DCC data offered to the shopper.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dcc_data: Option<DccData>,
     /// Fallback URL for mobile.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fallback_url: Option<String>,
+    /// Android package name for in-app flows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_name: Option<String>,
     /// Email for micro deposit verification.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
@@ -297,6 +309,19 @@ impl VerifyPaymentConsentRequest {
         self.verification_options = Some(options);
         self
     }
+
+    /// Set device data, either a [`DeviceData`](super::DeviceData) or raw JSON.
+    pub fn device_data(mut self, data: impl Into<Value>) -> Self {
+        self.device_data = Some(data.into());
+        self
+    }
+
+    /// Set risk control options, either a
+    /// [`RiskControlOptions`](super::RiskControlOptions) or raw JSON.
+    pub fn risk_control_options(mut self, options: impl Into<Value>) -> Self {
+        self.risk_control_options = Some(options.into());
+        self
+    }
 }
 
 /// Request to update a payment consent.
@@ -397,20 +422,26 @@ impl ListPaymentConsentsParams {
         self
     }
 
-    /// Set page size.
+    /// Set page size, capped at 1000.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 1000));
         self
     }
 }
 
 /// Response for listing payment consents.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListPaymentConsentsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of payment consents.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<PaymentConsent>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
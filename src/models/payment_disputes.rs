@@ -5,6 +5,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::common::{DisputeReasonCode, Timestamp};
+
 /// A payment dispute.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaymentDispute {
@@ -58,7 +60,8 @@ pub struct PaymentDispute {
     pub transaction_type: Option<String>,
     /// Due date.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub due_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub due_at: Option<Timestamp>,
     /// Issuer comment.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub issuer_comment: Option<String>,
@@ -76,10 +79,110 @@ pub struct PaymentDispute {
     pub refunds: Option<Vec<DisputeRefund>>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Updated timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
+}
+
+/// Type of a payment dispute reason.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DisputeReasonType {
+    /// Credit not processed.
+    #[serde(alias = "credit_not_processed")]
+    CreditNotProcessed,
+    /// Fraudulent.
+    #[serde(alias = "fraudulent")]
+    Fraudulent,
+    /// Duplicate charge.
+    #[serde(alias = "duplicate_charge")]
+    DuplicateCharge,
+    /// Product not received.
+    #[serde(alias = "product_not_received")]
+    ProductNotReceived,
+    /// Product unacceptable.
+    #[serde(alias = "product_unacceptable")]
+    ProductUnacceptable,
+    /// Unrecognized charge.
+    #[serde(alias = "unrecognized_charge")]
+    UnrecognizedCharge,
+    /// Cancelled product.
+    #[serde(alias = "cancelled_product")]
+    CancelledProduct,
+    /// Misrepresentation.
+    #[serde(alias = "misrepresentation")]
+    Misrepresentation,
+    /// Counterfeit product.
+    #[serde(alias = "counterfeit_product")]
+    CounterfeitProduct,
+    /// Processing errors.
+    #[serde(alias = "processing_errors")]
+    ProcessingErrors,
+    /// Authorization.
+    #[serde(alias = "authorization")]
+    Authorization,
+    /// Not recognized.
+    #[serde(alias = "not_recognized")]
+    NotRecognized,
+    /// Bank rejection.
+    #[serde(alias = "bank_rejection")]
+    BankRejection,
+    /// Fund reversal.
+    #[serde(alias = "fund_reversal")]
+    FundReversal,
+    /// Consumer dispute.
+    #[serde(alias = "consumer_dispute")]
+    ConsumerDispute,
+    /// Point of interaction error.
+    #[serde(alias = "point_of_interaction_error")]
+    PointOfInteractionError,
+    /// Reason reported by Airwallex as unknown.
+    #[serde(alias = "unknown")]
+    Unknown,
+    /// A reason not known to this version of the client.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl DisputeReasonCode for DisputeReasonType {
+    fn as_str(&self) -> &str {
+        match self {
+            DisputeReasonType::CreditNotProcessed => "CREDIT_NOT_PROCESSED",
+            DisputeReasonType::Fraudulent => "FRAUDULENT",
+            DisputeReasonType::DuplicateCharge => "DUPLICATE_CHARGE",
+            DisputeReasonType::ProductNotReceived => "PRODUCT_NOT_RECEIVED",
+            DisputeReasonType::ProductUnacceptable => "PRODUCT_UNACCEPTABLE",
+            DisputeReasonType::UnrecognizedCharge => "UNRECOGNIZED_CHARGE",
+            DisputeReasonType::CancelledProduct => "CANCELLED_PRODUCT",
+            DisputeReasonType::Misrepresentation => "MISREPRESENTATION",
+            DisputeReasonType::CounterfeitProduct => "COUNTERFEIT_PRODUCT",
+            DisputeReasonType::ProcessingErrors => "PROCESSING_ERRORS",
+            DisputeReasonType::Authorization => "AUTHORIZATION",
+            DisputeReasonType::NotRecognized => "NOT_RECOGNIZED",
+            DisputeReasonType::BankRejection => "BANK_REJECTION",
+            DisputeReasonType::FundReversal => "FUND_REVERSAL",
+            DisputeReasonType::ConsumerDispute => "CONSUMER_DISPUTE",
+            DisputeReasonType::PointOfInteractionError => "POINT_OF_INTERACTION_ERROR",
+            DisputeReasonType::Unknown => "UNKNOWN",
+            DisputeReasonType::Other(code) => code,
+        }
+    }
+}
+
+impl From<&str> for DisputeReasonType {
+    fn from(code: &str) -> Self {
+        serde_json::from_value(Value::String(code.to_string()))
+            .unwrap_or_else(|_| DisputeReasonType::Other(code.to_string()))
+    }
+}
+
+impl From<DisputeReasonType> for String {
+    fn from(reason: DisputeReasonType) -> Self {
+        reason.as_str().to_string()
+    }
 }
 
 /// Dispute reason.
@@ -87,7 +190,7 @@ pub struct PaymentDispute {
 pub struct DisputeReason {
     /// Reason type.
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
-    pub reason_type: Option<String>,
+    pub reason_type: Option<DisputeReasonType>,
     /// Reason code.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub original_code: Option<String>,
@@ -113,7 +216,8 @@ pub struct AcceptDetail {
     pub accepted_by: Option<String>,
     /// Accepted at timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub accepted_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub accepted_at: Option<Timestamp>,
     /// Refund details.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub refund: Option<AcceptRefund>,
@@ -144,7 +248,8 @@ pub struct ChallengeDetail {
     pub challenged_by: Option<String>,
     /// Challenged at timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenged_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub challenged_at: Option<Timestamp>,
     /// Product type.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub product_type: Option<String>,
@@ -368,6 +473,12 @@ impl ListPaymentDisputesParams {
         self
     }
 
+    /// Only return disputes updated at or after this ISO 8601 time.
+    pub fn from_updated_at(mut self, time: impl Into<String>) -> Self {
+        self.from_updated_at = Some(time.into());
+        self
+    }
+
     /// Set page size.
     pub fn size(mut self, size: i32) -> Self {
         self.size = Some(size);
@@ -376,11 +487,17 @@ impl ListPaymentDisputesParams {
 }
 
 /// Response for listing payment disputes.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListPaymentDisputesResponse {
     /// List of payment disputes.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<PaymentDispute>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
     /// Cursor for next page.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_after: Option<String>,
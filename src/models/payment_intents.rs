@@ -5,6 +5,11 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::amount::Amount;
+use super::common::{Timestamp, capped_page_size};
+use super::payment_attempts::PaymentAttempt;
+use super::payment_consents::NextAction;
+
 /// A payment intent.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaymentIntent {
@@ -16,16 +21,16 @@ pub struct PaymentIntent {
     pub request_id: Option<String>,
     /// Payment amount.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f64>,
+    pub amount: Option<Amount>,
     /// Currency.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<String>,
     /// Payment status.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PaymentIntentStatus>,
     /// Captured amount.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub captured_amount: Option<f64>,
+    pub captured_amount: Option<Amount>,
     /// Merchant order ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub merchant_order_id: Option<String>,
@@ -45,23 +50,28 @@ pub struct PaymentIntent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_secret: Option<String>,
     /// Latest payment attempt.
+    ///
+    /// Only populated when requested with `expand=latest_payment_attempt`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_payment_attempt: Option<Value>,
-    /// Next action required.
+    pub latest_payment_attempt: Option<PaymentAttempt>,
+    /// Next action required, present when the status is `REQUIRES_CUSTOMER_ACTION`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_action: Option<Value>,
+    pub next_action: Option<NextAction>,
     /// Cancellation reason.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cancellation_reason: Option<String>,
     /// Cancelled timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cancelled_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub cancelled_at: Option<Timestamp>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Updated timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
     /// Metadata.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Value>,
@@ -73,13 +83,83 @@ pub struct PaymentIntent {
     pub conversion_quote_id: Option<String>,
 }
 
+impl PaymentIntent {
+    /// Whether `metadata[key]` is the string `value`.
+    ///
+    /// The list endpoint has no metadata filter, so use this to filter listed
+    /// payment intents client-side, e.g. with
+    /// [`PaymentIntents::stream`](crate::resources::PaymentIntents::stream).
+    pub fn has_metadata(&self, key: &str, value: &str) -> bool {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(key))
+            .and_then(Value::as_str)
+            == Some(value)
+    }
+}
+
+/// Status of a payment intent.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentIntentStatus {
+    /// Waiting for a payment method to be confirmed.
+    #[serde(alias = "requires_payment_method")]
+    RequiresPaymentMethod,
+    /// Waiting for the customer to authenticate or complete a redirect.
+    #[serde(alias = "requires_customer_action")]
+    RequiresCustomerAction,
+    /// Held for a risk review.
+    #[serde(alias = "pending_review")]
+    PendingReview,
+    /// Authorized and waiting to be captured.
+    #[serde(alias = "requires_capture")]
+    RequiresCapture,
+    /// Waiting for the payment to be completed by the provider.
+    #[serde(alias = "pending")]
+    Pending,
+    /// Paid.
+    #[serde(alias = "succeeded")]
+    Succeeded,
+    /// Cancelled.
+    #[serde(alias = "cancelled")]
+    Cancelled,
+    /// A status not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl PaymentIntentStatus {
+    /// The status as sent by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PaymentIntentStatus::RequiresPaymentMethod => "REQUIRES_PAYMENT_METHOD",
+            PaymentIntentStatus::RequiresCustomerAction => "REQUIRES_CUSTOMER_ACTION",
+            PaymentIntentStatus::PendingReview => "PENDING_REVIEW",
+            PaymentIntentStatus::RequiresCapture => "REQUIRES_CAPTURE",
+            PaymentIntentStatus::Pending => "PENDING",
+            PaymentIntentStatus::Succeeded => "SUCCEEDED",
+            PaymentIntentStatus::Cancelled => "CANCELLED",
+            PaymentIntentStatus::Unknown(status) => status,
+        }
+    }
+}
+
+impl From<PaymentIntentStatus> for String {
+    fn from(status: PaymentIntentStatus) -> Self {
+        match status {
+            PaymentIntentStatus::Unknown(status) => status,
+            status => status.as_str().to_string(),
+        }
+    }
+}
+
 /// Request to create a payment intent.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreatePaymentIntentRequest {
     /// Unique request ID.
     pub request_id: String,
     /// Payment amount.
-    pub amount: f64,
+    pub amount: Amount,
     /// Currency (3-letter ISO-4217).
     pub currency: String,
     /// Merchant order ID.
@@ -107,10 +187,14 @@ pub struct CreatePaymentIntentRequest {
 
 impl CreatePaymentIntentRequest {
     /// Create a new request.
-    pub fn new(request_id: impl Into<String>, amount: f64, currency: impl Into<String>) -> Self {
+    pub fn new(
+        request_id: impl Into<String>,
+        amount: impl Into<Amount>,
+        currency: impl Into<String>,
+    ) -> Self {
         Self {
             request_id: request_id.into(),
-            amount,
+            amount: amount.into(),
             currency: currency.into(),
             merchant_order_id: None,
             descriptor: None,
@@ -202,6 +286,12 @@ impl ConfirmPaymentIntentRequest {
         self.return_url = Some(url.into());
         self
     }
+
+    /// Set device data, either a [`DeviceData`](super::DeviceData) or raw JSON.
+    pub fn device_data(mut self, data: impl Into<Value>) -> Self {
+        self.device_data = Some(data.into());
+        self
+    }
 }
 
 impl Default for ConfirmPaymentIntentRequest {
@@ -215,7 +305,7 @@ impl Default for ConfirmPaymentIntentRequest {
 pub struct CapturePaymentIntentRequest {
     /// Amount to capture.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<f64>,
+    pub amount: Option<Amount>,
 }
 
 impl CapturePaymentIntentRequest {
@@ -225,8 +315,8 @@ impl CapturePaymentIntentRequest {
     }
 
     /// Set amount to capture.
-    pub fn amount(mut self, amount: f64) -> Self {
-        self.amount = Some(amount);
+    pub fn amount(mut self, amount: impl Into<Amount>) -> Self {
+        self.amount = Some(amount.into());
         self
     }
 }
@@ -279,6 +369,9 @@ pub struct ListPaymentIntentsParams {
     /// Page size.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_size: Option<i32>,
+    /// Related objects to expand, e.g. `latest_payment_attempt`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expand: Option<String>,
 }
 
 impl ListPaymentIntentsParams {
@@ -293,7 +386,7 @@ impl ListPaymentIntentsParams {
         self
     }
 
-    /// Filter by status.
+    /// Filter by status, given as a [`PaymentIntentStatus`] or a string.
     pub fn status(mut self, status: impl Into<String>) -> Self {
         self.status = Some(status.into());
         self
@@ -311,20 +404,207 @@ impl ListPaymentIntentsParams {
         self
     }
 
-    /// Set page size.
+    /// Set page size, capped at 1000.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 1000));
+        self
+    }
+
+    /// Expand a related object in each result.
+    pub fn expand(mut self, field: impl Into<String>) -> Self {
+        self.expand = Some(field.into());
+        self
+    }
+
+    /// Include the full latest payment attempt in each result.
+    pub fn expand_latest_payment_attempt(self) -> Self {
+        self.expand("latest_payment_attempt")
+    }
+}
+
+/// Parameters for getting a single payment intent.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct GetPaymentIntentParams {
+    /// Related objects to expand, e.g. `latest_payment_attempt`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expand: Option<String>,
+}
+
+impl GetPaymentIntentParams {
+    /// Create new parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expand a related object.
+    pub fn expand(mut self, field: impl Into<String>) -> Self {
+        self.expand = Some(field.into());
         self
     }
+
+    /// Include the full latest payment attempt.
+    pub fn expand_latest_payment_attempt(self) -> Self {
+        self.expand("latest_payment_attempt")
+    }
 }
 
 /// Response for listing payment intents.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListPaymentIntentsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of payment intents.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<PaymentIntent>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{DeviceData, PaymentAttemptStatus};
+
+    #[test]
+    fn test_confirm_request_device_data() {
+        let request = ConfirmPaymentIntentRequest::new().device_data(
+            DeviceData::new()
+                .ip_address("203.0.113.7")
+                .user_agent("Mozilla/5.0")
+                .device_id("dev_1")
+                .os("IOS", "17.4"),
+        );
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "device_data": {
+                    "ip_address": "203.0.113.7",
+                    "device_id": "dev_1",
+                    "browser": {"user_agent": "Mozilla/5.0"},
+                    "mobile": {"os_type": "IOS", "os_version": "17.4"}
+                }
+            })
+        );
+
+        let raw = ConfirmPaymentIntentRequest::new()
+            .device_data(serde_json::json!({"screen_width": 390, "screen_height": 844}));
+        assert_eq!(
+            serde_json::to_value(&raw).unwrap()["device_data"]["screen_width"],
+            390
+        );
+    }
+
+    #[test]
+    fn test_has_metadata() {
+        let intent: PaymentIntent = serde_json::from_value(serde_json::json!({
+            "id": "int_1",
+            "metadata": {"order_ref": "ord 42", "attempt": 2}
+        }))
+        .unwrap();
+        assert!(intent.has_metadata("order_ref", "ord 42"));
+        assert!(!intent.has_metadata("order_ref", "ord 43"));
+        assert!(!intent.has_metadata("attempt", "2"));
+        assert!(!intent.has_metadata("tenant", "acme"));
+    }
+
+    #[test]
+    fn test_deserialize_nested_latest_payment_attempt() {
+        let json = r#"{
+            "id": "int_123",
+            "amount": 100.0,
+            "currency": "USD",
+            "status": "SUCCEEDED",
+            "latest_payment_attempt": {
+                "id": "att_456",
+                "amount": 100.0,
+                "currency": "USD",
+                "status": "AUTHORIZED",
+                "payment_intent_id": "int_123",
+                "authorization_code": "A1B2C3",
+                "payment_method": {"type": "card"}
+            }
+        }"#;
+
+        let intent: PaymentIntent = serde_json::from_str(json).unwrap();
+        let attempt = intent.latest_payment_attempt.unwrap();
+        assert_eq!(attempt.id.as_deref(), Some("att_456"));
+        assert_eq!(attempt.status, Some(PaymentAttemptStatus::Authorized));
+        assert_eq!(attempt.payment_intent_id.as_deref(), Some("int_123"));
+        assert_eq!(attempt.authorization_code.as_deref(), Some("A1B2C3"));
+    }
+
+    #[test]
+    fn test_deserialize_status() {
+        let json = r#"{
+            "id": "int_hkpdskz7vg1xc7uscdj",
+            "request_id": "ed4ed7b4-dbb3-4b3e-9e5f-ea9c9bd1a9e4",
+            "amount": 100,
+            "currency": "USD",
+            "status": "REQUIRES_PAYMENT_METHOD",
+            "merchant_order_id": "D202503210001",
+            "return_url": "https://www.airwallex.com",
+            "descriptor": "Airwallex - Test Descriptor",
+            "metadata": {"foo": "bar"},
+            "created_at": "2025-01-31T06:57:10+0000",
+            "updated_at": "2025-01-31T06:57:10+0000"
+        }"#;
+
+        let intent: PaymentIntent = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            intent.status,
+            Some(PaymentIntentStatus::RequiresPaymentMethod)
+        );
+
+        let status: PaymentIntentStatus = serde_json::from_str(r#""AWAITING_FUNDS""#).unwrap();
+        assert_eq!(
+            status,
+            PaymentIntentStatus::Unknown("AWAITING_FUNDS".to_string())
+        );
+        assert_eq!(
+            serde_json::to_string(&status).unwrap(),
+            r#""AWAITING_FUNDS""#
+        );
+
+        let params = ListPaymentIntentsParams::new().status(PaymentIntentStatus::RequiresCapture);
+        assert_eq!(params.status.as_deref(), Some("REQUIRES_CAPTURE"));
+    }
+
+    #[test]
+    fn test_deserialize_redirect_next_action() {
+        let json = r#"{
+            "id": "int_123",
+            "status": "REQUIRES_CUSTOMER_ACTION",
+            "next_action": {
+                "type": "redirect_form",
+                "method": "POST",
+                "url": "https://acs.example.com/3ds",
+                "content_type": "application/x-www-form-urlencoded",
+                "data": {"creq": "eyJ0aHJlZURT", "threeDSSessionData": "abc"},
+                "stage": "WAITING_USER_INFO_INPUT"
+            }
+        }"#;
+
+        let intent: PaymentIntent = serde_json::from_str(json).unwrap();
+        let action = intent.next_action.unwrap();
+        assert_eq!(action.action_type.as_deref(), Some("redirect_form"));
+        assert_eq!(action.method.as_deref(), Some("POST"));
+        assert_eq!(action.url.as_deref(), Some("https://acs.example.com/3ds"));
+        assert_eq!(action.data.unwrap()["creq"], "eyJ0aHJlZURT");
+    }
+
+    #[test]
+    fn test_expand_params_serialize() {
+        let params = ListPaymentIntentsParams::new().expand_latest_payment_attempt();
+        let value = serde_json::to_value(params).unwrap();
+        assert_eq!(value["expand"], "latest_payment_attempt");
+
+        let params = GetPaymentIntentParams::new();
+        let value = serde_json::to_value(params).unwrap();
+        assert!(value.get("expand").is_none());
+    }
 }
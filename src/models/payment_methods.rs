@@ -5,6 +5,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::common::{Timestamp, capped_page_size};
+
 /// A payment method.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaymentMethod {
@@ -53,10 +55,12 @@ pub struct PaymentMethod {
     pub metadata: Option<Value>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Updated timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// Card details for a payment method.
@@ -174,7 +178,10 @@ pub struct CreatePaymentMethodRequest {
 }
 
 /// Card details for creating a payment method.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// The `Debug` output masks the card number and CVC, so the request can be
+/// logged safely.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct CreateCardRequest {
     /// Card number.
     pub number: String,
@@ -221,6 +228,28 @@ impl CreatePaymentMethodRequest {
     }
 }
 
+impl std::fmt::Debug for CreateCardRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CreateCardRequest")
+            .field("number", &mask_card_number(&self.number))
+            .field("expiry_month", &self.expiry_month)
+            .field("expiry_year", &self.expiry_year)
+            .field("name", &self.name)
+            .field("cvc", &self.cvc.as_ref().map(|_| "***"))
+            .field("number_type", &self.number_type)
+            .field("billing", &self.billing)
+            .finish()
+    }
+}
+
+/// Mask all but the last four digits of a card number.
+pub(crate) fn mask_card_number(number: &str) -> String {
+    let digits: Vec<char> = number.chars().filter(|c| !c.is_whitespace()).collect();
+    let shown = if digits.len() > 8 { 4 } else { 0 };
+    let masked = digits.len() - shown;
+    "*".repeat(masked) + &digits[masked..].iter().collect::<String>()
+}
+
 impl CreateCardRequest {
     /// Create a new card request.
     pub fn new(
@@ -331,20 +360,26 @@ impl ListPaymentMethodsParams {
         self
     }
 
-    /// Set page size.
+    /// Set page size, capped at 1000.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 1000));
         self
     }
 }
 
 /// Response for listing payment methods.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListPaymentMethodsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of payment methods.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<PaymentMethod>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
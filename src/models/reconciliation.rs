@@ -4,13 +4,17 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::common::Timestamp;
+
 /// Debit/Credit flag.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum DebitCreditFlag {
     /// Debit.
+    #[serde(alias = "debit")]
     Debit,
     /// Credit.
+    #[serde(alias = "credit")]
     Credit,
 }
 
@@ -64,10 +68,12 @@ pub struct TreasuryBalance {
     pub remitting_bank: Option<String>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Posted timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub posted_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub posted_at: Option<Timestamp>,
 }
 
 /// Parameters for listing treasury balances.
@@ -146,12 +152,18 @@ impl ListTreasuryBalancesParams {
 }
 
 /// Response for listing treasury balances.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListTreasuryBalancesResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of balance entries.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<TreasuryBalance>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
 }
@@ -1,6 +1,7 @@
 //! Reference Data models.
 //!
-//! Models for retrieving reference data like supported currencies.
+//! Models for retrieving reference data like supported currencies and
+//! invalid conversion dates.
 
 use serde::{Deserialize, Serialize};
 
@@ -22,3 +23,24 @@ pub struct SupportedCurrencies {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conversion: Option<ConversionCurrencies>,
 }
+
+/// A date on which a currency pair cannot be converted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvalidConversionDate {
+    /// The date (`YYYY-MM-DD`).
+    pub date: String,
+    /// Why the date is invalid, e.g. a public holiday.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Invalid conversion dates response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvalidConversionDates {
+    /// Currency pair the dates apply to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency_pair: Option<String>,
+    /// Dates the pair cannot be converted on.
+    #[serde(default)]
+    pub invalid_conversion_dates: Vec<InvalidConversionDate>,
+}
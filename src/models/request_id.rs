@@ -0,0 +1,153 @@
+//! Request IDs of create and update requests.
+//!
+//! Airwallex treats a repeated `request_id` as the same operation and returns
+//! the resource created the first time, so every new operation needs a fresh
+//! ID. With the `uuid` feature, [`generate_request_id`] and
+//! [`HasRequestId::request_id_or_generate`] fill one in.
+
+use super::{
+    AcceptDisputeRequest, ApplyCapabilitiesRequest, ChallengeDisputeRequest,
+    CreateAmendmentQuoteRequest, CreateAmendmentRequest, CreateBatchTransferRequest,
+    CreateBeneficiaryRequest, CreateConnectedAccountTransferRequest, CreateConversionRequest,
+    CreateCustomerRequest, CreateGlobalAccountRequest, CreateLinkedAccountRequest,
+    CreateMandateRequest, CreatePaymentConsentRequest, CreatePaymentIntentRequest,
+    CreatePaymentMethodRequest, CreateRefundRequest, CreateTransferRequest, CreateWebhookRequest,
+    DisablePaymentConsentRequest, DisablePaymentMethodRequest, GenerateStatementLetterRequest,
+    UpdateIssuingTransactionDisputeRequest, UpdatePaymentConsentRequest,
+    VerifyPaymentConsentRequest,
+};
+
+/// A request carrying a `request_id`.
+///
+/// ```
+/// # #[cfg(feature = "uuid")]
+/// # {
+/// use airwallex_rs::models::{CreateTransferRequest, HasRequestId};
+///
+/// let request =
+///     CreateTransferRequest::local("", "ben_123", "USD", 100.0, "Invoice 42")
+///         .request_id_or_generate();
+/// assert!(!request.request_id().is_empty());
+/// # }
+/// ```
+pub trait HasRequestId: Sized {
+    /// The request ID; empty if none was set.
+    fn request_id(&self) -> &str;
+
+    /// Replace the request ID.
+    fn set_request_id(&mut self, request_id: String);
+
+    /// Fill in a fresh UUID if no request ID was set.
+    ///
+    /// The ID is stored in the request, so read it back with
+    /// [`request_id`](Self::request_id) to log it for reconciliation.
+    #[cfg(feature = "uuid")]
+    fn request_id_or_generate(mut self) -> Self {
+        if self.request_id().is_empty() {
+            let request_id = generate_request_id();
+            tracing::debug!(request_id = %request_id, "generated request ID");
+            self.set_request_id(request_id);
+        }
+        self
+    }
+}
+
+/// A fresh request ID, as a random UUID.
+#[cfg(feature = "uuid")]
+pub fn generate_request_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+macro_rules! has_request_id {
+    ($($request:ty),* $(,)?) => {
+        $(
+            impl HasRequestId for $request {
+                fn request_id(&self) -> &str {
+                    &self.request_id
+                }
+
+                fn set_request_id(&mut self, request_id: String) {
+                    self.request_id = request_id;
+                }
+            }
+        )*
+    };
+}
+
+/// Requests whose `new` takes only the request ID.
+macro_rules! with_generated_id {
+    ($($request:ty),* $(,)?) => {
+        $(
+            #[cfg(feature = "uuid")]
+            impl $request {
+                /// Create a new request with a generated request ID.
+                ///
+                /// See [`generate_request_id`].
+                pub fn with_generated_id() -> Self {
+                    Self::new(generate_request_id())
+                }
+            }
+        )*
+    };
+}
+
+has_request_id!(
+    AcceptDisputeRequest,
+    ApplyCapabilitiesRequest,
+    ChallengeDisputeRequest,
+    CreateAmendmentQuoteRequest,
+    CreateAmendmentRequest,
+    CreateBatchTransferRequest,
+    CreateBeneficiaryRequest,
+    CreateConnectedAccountTransferRequest,
+    CreateConversionRequest,
+    CreateCustomerRequest,
+    CreateGlobalAccountRequest,
+    CreateLinkedAccountRequest,
+    CreateMandateRequest,
+    CreatePaymentConsentRequest,
+    CreatePaymentIntentRequest,
+    CreatePaymentMethodRequest,
+    CreateRefundRequest,
+    CreateTransferRequest,
+    CreateWebhookRequest,
+    DisablePaymentConsentRequest,
+    DisablePaymentMethodRequest,
+    GenerateStatementLetterRequest,
+    UpdateIssuingTransactionDisputeRequest,
+    UpdatePaymentConsentRequest,
+    VerifyPaymentConsentRequest,
+);
+
+with_generated_id!(
+    AcceptDisputeRequest,
+    ChallengeDisputeRequest,
+    CreateBatchTransferRequest,
+    CreateCustomerRequest,
+    DisablePaymentConsentRequest,
+    DisablePaymentMethodRequest,
+    GenerateStatementLetterRequest,
+    UpdateIssuingTransactionDisputeRequest,
+    UpdatePaymentConsentRequest,
+    VerifyPaymentConsentRequest,
+);
+
+#[cfg(all(test, feature = "uuid"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_id_or_generate() {
+        let request = CreateCustomerRequest::new("").request_id_or_generate();
+        assert!(uuid::Uuid::parse_str(request.request_id()).is_ok());
+        let again = request.clone().request_id_or_generate();
+        assert_eq!(again.request_id(), request.request_id());
+
+        let request = CreateCustomerRequest::new("req_1").request_id_or_generate();
+        assert_eq!(request.request_id(), "req_1");
+
+        let first = CreateBatchTransferRequest::with_generated_id();
+        let second = CreateBatchTransferRequest::with_generated_id();
+        assert_ne!(first.request_id(), second.request_id());
+    }
+}
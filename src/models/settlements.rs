@@ -2,15 +2,34 @@
 //!
 //! Models for payment acceptance settlements.
 
+use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Serialize};
 
+use super::common::{Timestamp, capped_page_size};
+use crate::error::{Error, Result};
+
+/// Status of a settlement.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SettlementStatus {
+    /// The settlement has not been paid out yet.
+    #[serde(alias = "pending")]
+    Pending,
+    /// The settlement has been paid out.
+    #[serde(alias = "settled")]
+    Settled,
+    /// A status not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
 /// A settlement.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Settlement {
     /// Batch ID of the settlement.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
-    /// Settlement amount.
+    /// Settlement amount Airwallex pays to the merchant, net of fees.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub amount: Option<f64>,
     /// Currency.
@@ -19,18 +38,55 @@ pub struct Settlement {
     /// Fee of the settlement.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fee: Option<f64>,
-    /// Status (PENDING, SETTLED).
+    /// Settlement status.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SettlementStatus>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Estimated settled timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub estimated_settled_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub estimated_settled_at: Option<Timestamp>,
     /// Actual settled timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub settled_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub settled_at: Option<Timestamp>,
+}
+
+impl Settlement {
+    /// Amount paid out to the merchant, after fees.
+    pub fn net_amount(&self) -> Option<f64> {
+        self.amount
+    }
+
+    /// Fees deducted from the settlement.
+    pub fn fee_amount(&self) -> Option<f64> {
+        self.fee
+    }
+
+    /// Amount before fees (`amount + fee`).
+    pub fn gross_amount(&self) -> Option<f64> {
+        Some(self.amount? + self.fee.unwrap_or(0.0))
+    }
+
+    /// Date (`YYYY-MM-DD`) the settlement was, or is expected to be, paid out.
+    #[cfg(not(feature = "chrono"))]
+    pub fn settlement_date(&self) -> Option<&str> {
+        self.settled_at
+            .as_deref()
+            .or(self.estimated_settled_at.as_deref())
+            .and_then(|timestamp| timestamp.get(..10))
+    }
+
+    /// Date (UTC) the settlement was, or is expected to be, paid out.
+    #[cfg(feature = "chrono")]
+    pub fn settlement_date(&self) -> Option<NaiveDate> {
+        self.settled_at
+            .or(self.estimated_settled_at)
+            .map(|timestamp| timestamp.date_naive())
+    }
 }
 
 /// Settlement report.
@@ -44,7 +100,8 @@ pub struct SettlementReport {
     pub report_url: Option<String>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
 }
 
 /// Parameters for listing settlements.
@@ -68,20 +125,48 @@ pub struct ListSettlementsParams {
 
 impl ListSettlementsParams {
     /// Create new parameters with required fields.
+    ///
+    /// The endpoint rejects anything but `YYYY-MM-DD` dates, so both dates
+    /// are checked here, along with `from_settled_at <= to_settled_at`.
+    /// Returns [`Error::Validation`] otherwise.
     pub fn new(
         currency: impl Into<String>,
         status: impl Into<String>,
         from_settled_at: impl Into<String>,
         to_settled_at: impl Into<String>,
-    ) -> Self {
-        Self {
+    ) -> Result<Self> {
+        let from_settled_at = from_settled_at.into();
+        let to_settled_at = to_settled_at.into();
+        check_range(&from_settled_at, &to_settled_at)?;
+
+        Ok(Self {
             currency: currency.into(),
             status: status.into(),
-            from_settled_at: from_settled_at.into(),
-            to_settled_at: to_settled_at.into(),
+            from_settled_at,
+            to_settled_at,
             page_num: None,
             page_size: None,
-        }
+        })
+    }
+
+    /// Set the start date from a date or `DateTime`, in its own time zone.
+    ///
+    /// Returns [`Error::Validation`] if it falls after the end date.
+    pub fn from_settled_date(mut self, date: impl Datelike) -> Result<Self> {
+        let from_settled_at = format_date(&date);
+        check_range(&from_settled_at, &self.to_settled_at)?;
+        self.from_settled_at = from_settled_at;
+        Ok(self)
+    }
+
+    /// Set the end date from a date or `DateTime`, in its own time zone.
+    ///
+    /// Returns [`Error::Validation`] if it falls before the start date.
+    pub fn to_settled_date(mut self, date: impl Datelike) -> Result<Self> {
+        let to_settled_at = format_date(&date);
+        check_range(&self.from_settled_at, &to_settled_at)?;
+        self.to_settled_at = to_settled_at;
+        Ok(self)
     }
 
     /// Set page number.
@@ -90,13 +175,47 @@ impl ListSettlementsParams {
         self
     }
 
-    /// Set page size.
+    /// Set page size, capped at 1000.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 1000));
         self
     }
 }
 
+/// Format a date as `YYYY-MM-DD`.
+fn format_date(date: &impl Datelike) -> String {
+    format!("{:04}-{:02}-{:02}", date.year(), date.month(), date.day())
+}
+
+/// Check that both dates are well formed and `from_settled_at <= to_settled_at`.
+fn check_range(from_settled_at: &str, to_settled_at: &str) -> Result<()> {
+    let from = parse_date("from_settled_at", from_settled_at)?;
+    let to = parse_date("to_settled_at", to_settled_at)?;
+    if from > to {
+        return Err(Error::Validation(format!(
+            "from_settled_at {} is after to_settled_at {}",
+            from_settled_at, to_settled_at
+        )));
+    }
+    Ok(())
+}
+
+/// Parse a `YYYY-MM-DD` date, naming the field in the error.
+fn parse_date(field: &str, value: &str) -> Result<NaiveDate> {
+    // chrono accepts unpadded fields, which the API does not.
+    let well_formed =
+        value.len() == 10 && value.as_bytes()[4] == b'-' && value.as_bytes()[7] == b'-';
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .filter(|_| well_formed)
+        .ok_or_else(|| {
+            Error::Validation(format!(
+                "{} must be a YYYY-MM-DD date, got '{}'",
+                field, value
+            ))
+        })
+}
+
 /// Parameters for getting a settlement report.
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct GetSettlementReportParams {
@@ -128,12 +247,121 @@ impl GetSettlementReportParams {
 }
 
 /// Response for listing settlements.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListSettlementsResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of settlements.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<Settlement>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_settlement() {
+        let json = r#"{
+            "id": "stl_123",
+            "amount": 970.5,
+            "currency": "USD",
+            "fee": 29.5,
+            "status": "SETTLED",
+            "created_at": "2024-03-01T08:00:00+0000",
+            "estimated_settled_at": "2024-03-03T00:00:00+0000",
+            "settled_at": "2024-03-04T02:15:00+0000"
+        }"#;
+
+        let settlement: Settlement = serde_json::from_str(json).unwrap();
+        assert_eq!(settlement.status, Some(SettlementStatus::Settled));
+        assert_eq!(settlement.currency.as_deref(), Some("USD"));
+        assert_eq!(settlement.net_amount(), Some(970.5));
+        assert_eq!(settlement.fee_amount(), Some(29.5));
+        assert_eq!(settlement.gross_amount(), Some(1000.0));
+        assert_eq!(
+            settlement
+                .settlement_date()
+                .map(|date| date.to_string())
+                .as_deref(),
+            Some("2024-03-04")
+        );
+
+        let pending: Settlement = serde_json::from_str(
+            r#"{"status": "ON_HOLD", "estimated_settled_at": "2024-03-05T00:00:00+0000"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            pending.status,
+            Some(SettlementStatus::Unknown("ON_HOLD".to_string()))
+        );
+        assert_eq!(
+            pending
+                .settlement_date()
+                .map(|date| date.to_string())
+                .as_deref(),
+            Some("2024-03-05")
+        );
+        assert_eq!(pending.gross_amount(), None);
+    }
+
+    #[test]
+    fn test_list_params_validates_dates() {
+        let params = ListSettlementsParams::new("USD", "SETTLED", "2024-01-01", "2024-01-01");
+        assert!(params.is_ok());
+
+        for (from, to) in [
+            ("2024-1-01", "2024-02-01"),
+            ("2024-01-01T00:00:00Z", "2024-02-01"),
+            ("2024-01-01", "2024-02-30"),
+        ] {
+            assert!(
+                matches!(
+                    ListSettlementsParams::new("USD", "SETTLED", from, to),
+                    Err(Error::Validation(_))
+                ),
+                "{} .. {}",
+                from,
+                to
+            );
+        }
+    }
+
+    #[test]
+    fn test_list_params_rejects_reversed_range() {
+        let err =
+            ListSettlementsParams::new("USD", "SETTLED", "2025-12-31", "2024-01-01").unwrap_err();
+        assert!(matches!(err, Error::Validation(ref message) if message.contains("after")));
+    }
+
+    #[test]
+    fn test_list_params_date_setters() {
+        use chrono::{TimeZone, Utc};
+
+        let params = ListSettlementsParams::new("USD", "SETTLED", "2024-01-01", "2024-01-01")
+            .unwrap()
+            .to_settled_date(Utc.with_ymd_and_hms(2024, 3, 31, 23, 59, 59).unwrap())
+            .unwrap()
+            .from_settled_date(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap())
+            .unwrap();
+        assert_eq!(params.from_settled_at, "2024-03-01");
+        assert_eq!(params.to_settled_at, "2024-03-31");
+
+        let err = params
+            .clone()
+            .from_settled_date(NaiveDate::from_ymd_opt(2024, 4, 1).unwrap())
+            .unwrap_err();
+        assert!(matches!(err, Error::Validation(ref message) if message.contains("after")));
+        let err = params
+            .to_settled_date(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())
+            .unwrap_err();
+        assert!(matches!(err, Error::Validation(ref message) if message.contains("after")));
+    }
 }
@@ -2,9 +2,15 @@
 //!
 //! Models for managing payout transfers (sending payments to beneficiaries).
 
+use chrono::{Datelike, NaiveDate, Weekday};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::amount::Amount;
+use super::common::{Timestamp, capped_page_size};
+use super::reference_data::InvalidConversionDates;
+use crate::error::{Error, Result};
+
 /// A payout transfer.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Transfer {
@@ -16,28 +22,28 @@ pub struct Transfer {
     pub request_id: Option<String>,
     /// Transfer status.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TransferStatus>,
     /// Short reference ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub short_reference_id: Option<String>,
     /// Source amount.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_amount: Option<f64>,
+    pub source_amount: Option<Amount>,
     /// Source currency.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_currency: Option<String>,
     /// Target amount (amount to beneficiary before fees).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_amount: Option<f64>,
+    pub target_amount: Option<Amount>,
     /// Target currency.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_currency: Option<String>,
     /// Amount beneficiary receives.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount_beneficiary_receives: Option<f64>,
+    pub amount_beneficiary_receives: Option<Amount>,
     /// Fee amount.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fee_amount: Option<f64>,
+    pub fee_amount: Option<Amount>,
     /// Fee currency.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fee_currency: Option<String>,
@@ -64,10 +70,12 @@ pub struct Transfer {
     pub swift_charge_option: Option<String>,
     /// Created timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
     /// Updated timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
     /// Completion date.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub completion_date: Option<String>,
@@ -79,6 +87,152 @@ pub struct Transfer {
     pub metadata: Option<Value>,
 }
 
+/// Status of a payout transfer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransferStatus {
+    /// Created, not yet processed.
+    #[serde(alias = "created")]
+    Created,
+    /// Scheduled for a future transfer date.
+    #[serde(alias = "scheduled")]
+    Scheduled,
+    /// Past its transfer date while waiting for funds.
+    #[serde(alias = "overdue")]
+    Overdue,
+    /// Waiting to be processed.
+    #[serde(alias = "pending")]
+    Pending,
+    /// Being processed.
+    #[serde(alias = "processing")]
+    Processing,
+    /// The currency conversion has been booked.
+    #[serde(alias = "dealt")]
+    Dealt,
+    /// Sent to the beneficiary's bank.
+    #[serde(alias = "sent")]
+    Sent,
+    /// Received by the beneficiary.
+    #[serde(alias = "paid")]
+    Paid,
+    /// Held for review.
+    #[serde(alias = "suspended")]
+    Suspended,
+    /// Cancelled.
+    #[serde(alias = "cancelled")]
+    Cancelled,
+    /// Failed.
+    #[serde(alias = "failed")]
+    Failed,
+    /// A status not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl TransferStatus {
+    /// The status as sent by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TransferStatus::Created => "CREATED",
+            TransferStatus::Scheduled => "SCHEDULED",
+            TransferStatus::Overdue => "OVERDUE",
+            TransferStatus::Pending => "PENDING",
+            TransferStatus::Processing => "PROCESSING",
+            TransferStatus::Dealt => "DEALT",
+            TransferStatus::Sent => "SENT",
+            TransferStatus::Paid => "PAID",
+            TransferStatus::Suspended => "SUSPENDED",
+            TransferStatus::Cancelled => "CANCELLED",
+            TransferStatus::Failed => "FAILED",
+            TransferStatus::Unknown(status) => status,
+        }
+    }
+
+    /// Whether the transfer will not change status again.
+    ///
+    /// Unknown statuses are not terminal, so polling carries on through them.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            TransferStatus::Paid | TransferStatus::Cancelled | TransferStatus::Failed
+        )
+    }
+
+    /// Whether the beneficiary has been paid.
+    pub fn is_successful(&self) -> bool {
+        *self == TransferStatus::Paid
+    }
+}
+
+impl From<TransferStatus> for String {
+    fn from(status: TransferStatus) -> Self {
+        match status {
+            TransferStatus::Unknown(status) => status,
+            status => status.as_str().to_string(),
+        }
+    }
+}
+
+/// How a transfer reaches the beneficiary's bank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransferMethod {
+    /// The local clearing system of the beneficiary's country.
+    #[serde(alias = "local")]
+    Local,
+    /// The SWIFT network.
+    #[serde(alias = "swift")]
+    Swift,
+}
+
+impl TransferMethod {
+    /// The method as sent to the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransferMethod::Local => "LOCAL",
+            TransferMethod::Swift => "SWIFT",
+        }
+    }
+}
+
+impl From<TransferMethod> for String {
+    fn from(method: TransferMethod) -> Self {
+        method.as_str().to_string()
+    }
+}
+
+/// Who bears the correspondent bank charges of a SWIFT transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SwiftChargeOption {
+    /// The payer pays all charges.
+    #[serde(alias = "our")]
+    Our,
+    /// Charges are shared between payer and beneficiary.
+    #[serde(alias = "sha")]
+    Sha,
+    /// The beneficiary pays all charges.
+    #[serde(alias = "ben")]
+    Ben,
+}
+
+impl SwiftChargeOption {
+    /// The option as sent to the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SwiftChargeOption::Our => "OUR",
+            SwiftChargeOption::Sha => "SHA",
+            SwiftChargeOption::Ben => "BEN",
+        }
+    }
+}
+
+impl From<SwiftChargeOption> for String {
+    fn from(option: SwiftChargeOption) -> Self {
+        option.as_str().to_string()
+    }
+}
+
 /// Request to create a transfer.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTransferRequest {
@@ -88,13 +242,13 @@ pub struct CreateTransferRequest {
     pub source_currency: String,
     /// Source amount.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_amount: Option<f64>,
+    pub source_amount: Option<Amount>,
     /// Target currency.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_currency: Option<String>,
     /// Target amount.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_amount: Option<f64>,
+    pub target_amount: Option<Amount>,
     /// Who pays the fee.
     pub fee_paid_by: String,
     /// Payment method.
@@ -124,14 +278,14 @@ impl CreateTransferRequest {
         request_id: impl Into<String>,
         beneficiary_id: impl Into<String>,
         source_currency: impl Into<String>,
-        source_amount: f64,
+        source_amount: impl Into<Amount>,
         payment_method: impl Into<String>,
         reference: impl Into<String>,
     ) -> Self {
         Self {
             request_id: request_id.into(),
             source_currency: source_currency.into(),
-            source_amount: Some(source_amount),
+            source_amount: Some(source_amount.into()),
             target_currency: None,
             target_amount: None,
             fee_paid_by: "PAYER".to_string(),
@@ -150,14 +304,14 @@ impl CreateTransferRequest {
         request_id: impl Into<String>,
         beneficiary: Value,
         source_currency: impl Into<String>,
-        source_amount: f64,
+        source_amount: impl Into<Amount>,
         payment_method: impl Into<String>,
         reference: impl Into<String>,
     ) -> Self {
         Self {
             request_id: request_id.into(),
             source_currency: source_currency.into(),
-            source_amount: Some(source_amount),
+            source_amount: Some(source_amount.into()),
             target_currency: None,
             target_amount: None,
             fee_paid_by: "PAYER".to_string(),
@@ -171,6 +325,70 @@ impl CreateTransferRequest {
         }
     }
 
+    /// Create a `LOCAL` transfer to a saved beneficiary.
+    pub fn local(
+        request_id: impl Into<String>,
+        beneficiary_id: impl Into<String>,
+        source_currency: impl Into<String>,
+        source_amount: impl Into<Amount>,
+        reference: impl Into<String>,
+    ) -> Self {
+        Self::with_beneficiary_id(
+            request_id,
+            beneficiary_id,
+            source_currency,
+            source_amount,
+            "LOCAL",
+            reference,
+        )
+    }
+
+    /// Create a `SWIFT` transfer to a saved beneficiary.
+    pub fn swift(
+        request_id: impl Into<String>,
+        beneficiary_id: impl Into<String>,
+        source_currency: impl Into<String>,
+        source_amount: impl Into<Amount>,
+        reference: impl Into<String>,
+        charge_option: SwiftChargeOption,
+    ) -> Self {
+        Self::with_beneficiary_id(
+            request_id,
+            beneficiary_id,
+            source_currency,
+            source_amount,
+            "SWIFT",
+            reference,
+        )
+        .swift_charge_option(charge_option)
+    }
+
+    /// Check the request for combinations the API rejects.
+    ///
+    /// [`Transfers::create`](crate::resources::Transfers::create) calls this
+    /// before sending.
+    pub fn validate(&self) -> Result<()> {
+        match (&self.source_amount, &self.target_amount) {
+            (Some(_), Some(_)) => {
+                return Err(Error::Validation(
+                    "only one of source_amount and target_amount may be set".to_string(),
+                ));
+            }
+            (None, None) => {
+                return Err(Error::Validation(
+                    "one of source_amount and target_amount is required".to_string(),
+                ));
+            }
+            _ => {}
+        }
+        if self.payment_method.eq_ignore_ascii_case("LOCAL") && self.swift_charge_option.is_some() {
+            return Err(Error::Validation(
+                "swift_charge_option is only valid for SWIFT transfers".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Set who pays the fee.
     pub fn fee_paid_by(mut self, payer: impl Into<String>) -> Self {
         self.fee_paid_by = payer.into();
@@ -184,9 +402,13 @@ impl CreateTransferRequest {
     }
 
     /// Set the target currency and amount.
-    pub fn target(mut self, currency: impl Into<String>, amount: f64) -> Self {
+    ///
+    /// The API accepts either a source or a target amount, so this clears the
+    /// source amount set by the constructor.
+    pub fn target(mut self, currency: impl Into<String>, amount: impl Into<Amount>) -> Self {
         self.target_currency = Some(currency.into());
-        self.target_amount = Some(amount);
+        self.target_amount = Some(amount.into());
+        self.source_amount = None;
         self
     }
 
@@ -238,7 +460,7 @@ impl ListTransfersParams {
         Self::default()
     }
 
-    /// Filter by status.
+    /// Filter by status, given as a [`TransferStatus`] or a string.
     pub fn status(mut self, status: impl Into<String>) -> Self {
         self.status = Some(status.into());
         self
@@ -268,20 +490,194 @@ impl ListTransfersParams {
         self
     }
 
-    /// Set page size.
+    /// Set page size, capped at 2000.
     pub fn page_size(mut self, size: i32) -> Self {
-        self.page_size = Some(size);
+        self.page_size = Some(capped_page_size(size, 2000));
         self
     }
 }
 
 /// Response for listing transfers.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
 pub struct ListTransfersResponse {
     /// Whether there are more results.
     #[serde(default)]
     pub has_more: bool,
     /// List of transfers.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::common::list_items")]
     pub items: Vec<Transfer>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
+
+/// Earliest value date for a transfer, from the pair's invalid conversion dates.
+///
+/// Airwallex does not publish cutoff times or per-method lead times, so this
+/// is the first day on or after the start date that is neither a weekend nor
+/// an invalid conversion date. Funds sent by `SWIFT` can land later.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueDateEstimate {
+    /// Currency pair the estimate is for, e.g. `USDEUR`.
+    pub currency_pair: String,
+    /// Payment method the estimate was requested for.
+    pub payment_method: String,
+    /// Earliest date the transfer can be valued.
+    pub value_date: NaiveDate,
+    /// Days skipped before the value date, with the API's reason if given.
+    pub skipped: Vec<(NaiveDate, Option<String>)>,
+}
+
+impl ValueDateEstimate {
+    /// Walk forward from `from` past weekends and invalid dates.
+    ///
+    /// Dates the API returns in an unexpected format are ignored.
+    pub fn from_invalid_dates(
+        dates: &InvalidConversionDates,
+        payment_method: impl Into<String>,
+        from: NaiveDate,
+    ) -> Self {
+        let invalid: Vec<(NaiveDate, Option<String>)> = dates
+            .invalid_conversion_dates
+            .iter()
+            .filter_map(|d| {
+                NaiveDate::parse_from_str(&d.date, "%Y-%m-%d")
+                    .ok()
+                    .map(|date| (date, d.reason.clone()))
+            })
+            .collect();
+
+        let mut value_date = from;
+        let mut skipped = Vec::new();
+        loop {
+            if let Some((_, reason)) = invalid.iter().find(|(date, _)| *date == value_date) {
+                skipped.push((value_date, reason.clone()));
+            } else if matches!(value_date.weekday(), Weekday::Sat | Weekday::Sun) {
+                skipped.push((value_date, None));
+            } else {
+                break;
+            }
+            match value_date.succ_opt() {
+                Some(next) => value_date = next,
+                None => break,
+            }
+        }
+
+        Self {
+            currency_pair: dates.currency_pair.clone().unwrap_or_default(),
+            payment_method: payment_method.into(),
+            value_date,
+            skipped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_status() {
+        let transfer: Transfer =
+            serde_json::from_str(r#"{"id": "tfr_1", "status": "PAID"}"#).unwrap();
+        let status = transfer.status.unwrap();
+        assert_eq!(status, TransferStatus::Paid);
+        assert!(status.is_terminal() && status.is_successful());
+
+        let status: TransferStatus = serde_json::from_str(r#""IN_APPROVAL""#).unwrap();
+        assert_eq!(status, TransferStatus::Unknown("IN_APPROVAL".to_string()));
+        assert_eq!(status.as_str(), "IN_APPROVAL");
+        assert!(!status.is_terminal());
+        assert!(TransferStatus::Failed.is_terminal() && !TransferStatus::Failed.is_successful());
+        assert!(!TransferStatus::Sent.is_terminal());
+
+        let params = ListTransfersParams::new().status(TransferStatus::Sent);
+        assert_eq!(params.status.as_deref(), Some("SENT"));
+    }
+
+    #[test]
+    fn test_local_and_swift_constructors() {
+        let local = CreateTransferRequest::local("req_1", "ben_1", "USD", 100.0, "Invoice 42");
+        assert_eq!(local.payment_method, "LOCAL");
+        assert_eq!(local.swift_charge_option, None);
+        assert!(local.validate().is_ok());
+
+        let swift = CreateTransferRequest::swift(
+            "req_2",
+            "ben_1",
+            "USD",
+            100.0,
+            "Invoice 43",
+            SwiftChargeOption::Sha,
+        );
+        let json = serde_json::to_value(&swift).unwrap();
+        assert_eq!(json["payment_method"], "SWIFT");
+        assert_eq!(json["swift_charge_option"], "SHA");
+        assert!(swift.validate().is_ok());
+    }
+
+    #[test]
+    fn test_local_rejects_swift_fields() {
+        let request = CreateTransferRequest::local("req_1", "ben_1", "USD", 100.0, "Invoice 42")
+            .swift_charge_option(SwiftChargeOption::Our);
+        assert!(matches!(
+            request.validate(),
+            Err(Error::Validation(message)) if message.contains("swift_charge_option")
+        ));
+    }
+
+    #[test]
+    fn test_source_and_target_amount_exclusive() {
+        let request = CreateTransferRequest::local("req_1", "ben_1", "USD", 100.0, "Invoice 42")
+            .target("EUR", 90.0);
+        assert_eq!(request.source_amount, None);
+        assert_eq!(request.target_amount, Some(Amount::from(90.0)));
+        assert!(request.validate().is_ok());
+
+        let mut both = request.clone();
+        both.source_amount = Some(Amount::from(100.0));
+        assert!(matches!(
+            both.validate(),
+            Err(Error::Validation(message)) if message.contains("only one")
+        ));
+
+        let mut neither = request;
+        neither.target_amount = None;
+        assert!(matches!(
+            neither.validate(),
+            Err(Error::Validation(message)) if message.contains("required")
+        ));
+    }
+
+    #[test]
+    fn test_value_date_skips_weekend_and_holiday() {
+        let dates: InvalidConversionDates = serde_json::from_value(serde_json::json!({
+            "currency_pair": "USDEUR",
+            "invalid_conversion_dates": [
+                {"date": "2024-12-25", "reason": "Christmas Day"},
+                {"date": "2024-12-30", "reason": "Bank holiday"}
+            ]
+        }))
+        .unwrap();
+
+        // Friday 27th is valid.
+        let from = NaiveDate::from_ymd_opt(2024, 12, 27).unwrap();
+        let estimate = ValueDateEstimate::from_invalid_dates(&dates, "LOCAL", from);
+        assert_eq!(estimate.value_date, from);
+        assert!(estimate.skipped.is_empty());
+
+        // Saturday 28th rolls over the weekend and Monday's holiday.
+        let from = NaiveDate::from_ymd_opt(2024, 12, 28).unwrap();
+        let estimate = ValueDateEstimate::from_invalid_dates(&dates, "LOCAL", from);
+        assert_eq!(estimate.currency_pair, "USDEUR");
+        assert_eq!(
+            estimate.value_date,
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()
+        );
+        assert_eq!(estimate.skipped.len(), 3);
+        assert_eq!(estimate.skipped[2].1.as_deref(), Some("Bank holiday"));
+    }
 }
@@ -0,0 +1,245 @@
+//! Webhook models.
+//!
+//! Models for managing webhook subscriptions (notification URLs and the
+//! events sent to them).
+
+use serde::{Deserialize, Serialize};
+
+use super::common::Timestamp;
+
+/// The name of a webhook event.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum EventName {
+    /// A payment intent was created.
+    #[serde(rename = "payment_intent.created")]
+    PaymentIntentCreated,
+    /// A payment intent needs a payment method.
+    #[serde(rename = "payment_intent.requires_payment_method")]
+    PaymentIntentRequiresPaymentMethod,
+    /// A payment intent is waiting for customer action.
+    #[serde(rename = "payment_intent.requires_customer_action")]
+    PaymentIntentRequiresCustomerAction,
+    /// A payment intent is authorized and waiting for capture.
+    #[serde(rename = "payment_intent.requires_capture")]
+    PaymentIntentRequiresCapture,
+    /// A payment intent succeeded.
+    #[serde(rename = "payment_intent.succeeded")]
+    PaymentIntentSucceeded,
+    /// A payment intent was cancelled.
+    #[serde(rename = "payment_intent.cancelled")]
+    PaymentIntentCancelled,
+    /// A payment attempt was authorized.
+    #[serde(rename = "payment_attempt.authorized")]
+    PaymentAttemptAuthorized,
+    /// Capture was requested for a payment attempt.
+    #[serde(rename = "payment_attempt.capture_requested")]
+    PaymentAttemptCaptureRequested,
+    /// A payment attempt was settled.
+    #[serde(rename = "payment_attempt.settled")]
+    PaymentAttemptSettled,
+    /// A payment attempt was paid out.
+    #[serde(rename = "payment_attempt.paid")]
+    PaymentAttemptPaid,
+    /// A payment attempt was cancelled.
+    #[serde(rename = "payment_attempt.cancelled")]
+    PaymentAttemptCancelled,
+    /// A payment attempt expired.
+    #[serde(rename = "payment_attempt.expired")]
+    PaymentAttemptExpired,
+    /// A payment consent was created.
+    #[serde(rename = "payment_consent.created")]
+    PaymentConsentCreated,
+    /// A payment consent was verified.
+    #[serde(rename = "payment_consent.verified")]
+    PaymentConsentVerified,
+    /// A payment consent was disabled.
+    #[serde(rename = "payment_consent.disabled")]
+    PaymentConsentDisabled,
+    /// A refund was received.
+    #[serde(rename = "refund.received")]
+    RefundReceived,
+    /// A refund was accepted.
+    #[serde(rename = "refund.accepted")]
+    RefundAccepted,
+    /// A refund was settled.
+    #[serde(rename = "refund.settled")]
+    RefundSettled,
+    /// A refund failed.
+    #[serde(rename = "refund.failed")]
+    RefundFailed,
+    /// A payment dispute needs a response.
+    #[serde(rename = "payment_dispute.requires_response")]
+    PaymentDisputeRequiresResponse,
+    /// A payment dispute was accepted.
+    #[serde(rename = "payment_dispute.accepted")]
+    PaymentDisputeAccepted,
+    /// A payment dispute was won.
+    #[serde(rename = "payment_dispute.won")]
+    PaymentDisputeWon,
+    /// A payment dispute was lost.
+    #[serde(rename = "payment_dispute.lost")]
+    PaymentDisputeLost,
+    /// A payout transfer was paid.
+    #[serde(rename = "payout.transfer.paid")]
+    PayoutTransferPaid,
+    /// A payout transfer failed.
+    #[serde(rename = "payout.transfer.failed")]
+    PayoutTransferFailed,
+    /// A payout transfer was cancelled.
+    #[serde(rename = "payout.transfer.cancelled")]
+    PayoutTransferCancelled,
+    /// A conversion was settled.
+    #[serde(rename = "conversion.settled")]
+    ConversionSettled,
+    /// A deposit was settled.
+    #[serde(rename = "deposit.settled")]
+    DepositSettled,
+    /// An account became active.
+    #[serde(rename = "account.active")]
+    AccountActive,
+    /// An account was suspended.
+    #[serde(rename = "account.suspended")]
+    AccountSuspended,
+    /// An event not known to this version of the client.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A webhook subscription.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Webhook {
+    /// Webhook ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Request ID the webhook was created with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Endpoint events are sent to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Subscribed events.
+    #[serde(default)]
+    pub events: Vec<EventName>,
+    /// API version controlling the event payload structure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Secret for verifying event signatures; see [`crate::webhooks`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// Creation time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub created_at: Option<Timestamp>,
+    /// Last update time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "chrono", serde(default, with = "super::common::timestamp"))]
+    pub updated_at: Option<Timestamp>,
+}
+
+impl std::fmt::Debug for Webhook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Webhook")
+            .field("id", &self.id)
+            .field("request_id", &self.request_id)
+            .field("url", &self.url)
+            .field("events", &self.events)
+            .field("version", &self.version)
+            .field("secret", &self.secret.as_ref().map(|_| "[REDACTED]"))
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .finish()
+    }
+}
+
+/// Request to create a webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWebhookRequest {
+    /// Unique request ID.
+    pub request_id: String,
+    /// Endpoint to send events to.
+    pub url: String,
+    /// Events to subscribe to.
+    pub events: Vec<EventName>,
+    /// API version for event payloads (`YYYY-MM-DD`).
+    pub version: String,
+}
+
+impl CreateWebhookRequest {
+    /// Create a new request with required fields.
+    ///
+    /// `version` is usually the client's [`api_version`](crate::Client::api_version).
+    pub fn new(
+        request_id: impl Into<String>,
+        url: impl Into<String>,
+        events: impl IntoIterator<Item = EventName>,
+        version: impl Into<String>,
+    ) -> Self {
+        Self {
+            request_id: request_id.into(),
+            url: url.into(),
+            events: events.into_iter().collect(),
+            version: version.into(),
+        }
+    }
+}
+
+/// Request to update a webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateWebhookRequest {
+    /// New endpoint to send events to.
+    pub url: String,
+}
+
+impl UpdateWebhookRequest {
+    /// Create a new update request.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+}
+
+/// Parameters for listing webhooks.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ListWebhooksParams {
+    /// Page number (starting from 0).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_num: Option<i32>,
+    /// Page size.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<i32>,
+}
+
+impl ListWebhooksParams {
+    /// Create new parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set page number.
+    pub fn page_num(mut self, num: i32) -> Self {
+        self.page_num = Some(num);
+        self
+    }
+
+    /// Set page size.
+    pub fn page_size(mut self, size: i32) -> Self {
+        self.page_size = Some(size);
+        self
+    }
+}
+
+/// Response for listing webhooks.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(remote = "Self")]
+pub struct ListWebhooksResponse {
+    /// Whether there are more results.
+    #[serde(default)]
+    pub has_more: bool,
+    /// List of webhooks.
+    #[serde(default, deserialize_with = "super::common::list_items")]
+    pub items: Vec<Webhook>,
+    /// The raw `items` value, if the API sent something other than an array.
+    ///
+    /// `items` is empty in that case; listing helpers report it as an error.
+    #[serde(skip)]
+    pub raw_items: Option<serde_json::Value>,
+}
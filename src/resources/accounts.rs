@@ -1,9 +1,10 @@
 //! Accounts resource for Scale (Connected Accounts).
 
 use crate::client::Client;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::models::{
-    Account, CreateAccountRequest, ListAccountsParams, ListAccountsResponse, UpdateAccountRequest,
+    Account, AccountCapabilitiesSummary, CreateAccountRequest, ListAccountsParams,
+    ListAccountsResponse, UpdateAccountRequest,
 };
 
 /// Accounts resource for managing connected accounts (Scale).
@@ -44,4 +45,91 @@ impl<'a> Accounts<'a> {
             .post(&format!("/api/v1/accounts/{}/update", id), request)
             .await
     }
+
+    /// Check which product areas are enabled for the account.
+    ///
+    /// Airwallex has no single endpoint for this, so each area is probed with
+    /// a minimal read request (issuing config, connected accounts, payment
+    /// intents and transfers), concurrently. An area is reported as disabled
+    /// when the API refuses the request as not permitted or not enabled;
+    /// other errors are returned.
+    pub async fn capabilities(&self) -> Result<AccountCapabilitiesSummary> {
+        let (issuing, scale, payments, transfers) = tokio::join!(
+            self.probe("/api/v1/issuing/config"),
+            self.probe("/api/v1/accounts"),
+            self.probe("/api/v1/pa/payment_intents"),
+            self.probe("/api/v1/transfers"),
+        );
+
+        Ok(AccountCapabilitiesSummary {
+            issuing: issuing?,
+            scale: scale?,
+            payments: payments?,
+            transfers: transfers?,
+        })
+    }
+
+    /// Whether a read request to `path` is allowed.
+    async fn probe(&self, path: &str) -> Result<bool> {
+        let result = self
+            .client
+            .get_with_query::<serde_json::Value, _>(path, &[("page_size", "1")])
+            .await;
+        match result {
+            Ok(_) => Ok(true),
+            Err(e) if is_feature_unavailable(&e) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Whether an error means the product area is not available to the account.
+fn is_feature_unavailable(error: &Error) -> bool {
+    error.is_permission_denied()
+        || matches!(error, Error::Api { message, .. } if message.contains("not enabled"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::mock::mock_client;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_capabilities_probes_each_area() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issuing/config"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "code": "invalid_argument",
+                "message": "Issuing is not enabled for this account"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/accounts"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "code": "forbidden",
+                "message": "Access denied"
+            })))
+            .mount(&server)
+            .await;
+        for area in ["/api/v1/pa/payment_intents", "/api/v1/transfers"] {
+            Mock::given(method("GET"))
+                .and(path(area))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(serde_json::json!({"has_more": false, "items": []})),
+                )
+                .mount(&server)
+                .await;
+        }
+
+        let summary = client.accounts().capabilities().await.unwrap();
+        assert!(!summary.issuing);
+        assert!(!summary.scale);
+        assert!(summary.payments);
+        assert!(summary.transfers);
+    }
 }
@@ -2,11 +2,17 @@
 //!
 //! The Balances API allows you to retrieve your current and historical balances.
 
+use std::collections::HashSet;
+
+use futures_util::{Stream, StreamExt, stream};
+
 use crate::client::Client;
 use crate::error::Result;
 use crate::models::balances::{
-    Balance, BalanceHistoryParams, BalanceHistoryResponse, CurrentBalancesResponse,
+    Balance, BalanceHistoryEntry, BalanceHistoryParams, BalanceHistoryResponse,
+    CurrentBalancesResponse,
 };
+use crate::models::common::ListResponse;
 
 /// The Balances resource.
 ///
@@ -81,4 +87,125 @@ impl<'a> Balances<'a> {
             .get_with_query("/api/v1/balances/history", &params)
             .await
     }
+
+    /// Stream balance history across all pages.
+    ///
+    /// Follows the `page_after` cursor when the API returns one, otherwise
+    /// `page_num`. Set [`BalanceHistoryParams::dedup`] to skip entries seen on
+    /// an earlier page. An error ends the stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/balances/history`
+    pub fn history_stream(
+        &self,
+        params: BalanceHistoryParams,
+    ) -> impl Stream<Item = Result<BalanceHistoryEntry>> + Send + 'static {
+        let client = self.client.clone();
+        let seen = params.dedup.then(HashSet::new);
+        stream::unfold(Some((params, seen)), move |state| {
+            let client = client.clone();
+            async move {
+                let (mut params, mut seen) = state?;
+                let page = client
+                    .get_with_query::<BalanceHistoryResponse, _>(
+                        "/api/v1/balances/history",
+                        &params,
+                    )
+                    .await
+                    .and_then(|page| page.check_items().map(|()| page));
+                let page = match page {
+                    Ok(page) => page,
+                    Err(e) => return Some((vec![Err(e)], None)),
+                };
+
+                let more = !page.items.is_empty();
+                let items: Vec<Result<BalanceHistoryEntry>> = page
+                    .items
+                    .into_iter()
+                    .filter(|entry| match seen.as_mut() {
+                        Some(seen) => seen.insert(entry.id.clone()),
+                        None => true,
+                    })
+                    .map(Ok)
+                    .collect();
+
+                let next = match page.page_after {
+                    Some(cursor) if more => {
+                        params.page = Some(cursor);
+                        Some((params, seen))
+                    }
+                    None if more && page.has_more => {
+                        params.page_num = Some(params.page_num.unwrap_or(0) + 1);
+                        Some((params, seen))
+                    }
+                    _ => None,
+                };
+                Some((items, next))
+            }
+        })
+        .flat_map(stream::iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::StreamExt;
+
+    use crate::models::BalanceHistoryParams;
+    use crate::testing::mock::mock_client;
+    use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+    use wiremock::{Mock, ResponseTemplate};
+
+    fn entry(id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "amount": 10.0,
+            "currency": "USD",
+            "posted_at": "2024-05-01T00:00:00Z",
+            "type": "DEPOSIT"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_history_stream_dedups_across_pages() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/history"))
+            .and(query_param_is_missing("page"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [entry("bh_1"), entry("bh_2")],
+                "has_more": true,
+                "page_after": "cursor_1"
+            })))
+            .mount(&server)
+            .await;
+        // New activity pushed bh_2 onto the second page as well.
+        Mock::given(method("GET"))
+            .and(path("/api/v1/balances/history"))
+            .and(query_param("page", "cursor_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [entry("bh_2"), entry("bh_3")],
+                "has_more": false
+            })))
+            .mount(&server)
+            .await;
+
+        let all: Vec<String> = client
+            .balances()
+            .history_stream(BalanceHistoryParams::new())
+            .map(|entry| entry.unwrap().id)
+            .collect()
+            .await;
+        assert_eq!(all, ["bh_1", "bh_2", "bh_2", "bh_3"]);
+
+        let unique: Vec<String> = client
+            .balances()
+            .history_stream(BalanceHistoryParams::new().dedup(true))
+            .map(|entry| entry.unwrap().id)
+            .collect()
+            .await;
+        assert_eq!(unique, ["bh_1", "bh_2", "bh_3"]);
+    }
 }
@@ -3,12 +3,13 @@
 //! Manage batch transfers (bulk payouts).
 
 use crate::client::Client;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::models::batch_transfers::{
-    AddBatchItemsRequest, BatchTransfer, CreateBatchTransferRequest, DeleteBatchItemsRequest,
-    ListBatchItemsParams, ListBatchItemsResponse, ListBatchTransfersParams,
-    ListBatchTransfersResponse,
+    AddBatchItemsRequest, BatchTransfer, BatchTransferItem, CreateBatchTransferRequest,
+    DeleteBatchItemsRequest, ListBatchItemsParams, ListBatchItemsResponse,
+    ListBatchTransfersParams, ListBatchTransfersResponse,
 };
+use crate::models::common::ListResponse;
 
 /// The Batch Transfers resource.
 pub struct BatchTransfers<'a> {
@@ -130,14 +131,180 @@ impl<'a> BatchTransfers<'a> {
             .await
     }
 
+    /// Refresh the quote for a batch transfer and submit it.
+    ///
+    /// Submitting fails once `quote_summary.expires_at` has passed, so this
+    /// always requests a fresh quote first. Before submitting, the batch items
+    /// are checked and any per-item validation errors are returned as
+    /// [`Error::Validation`] without submitting. Only the first page of items
+    /// (up to 1000) is checked.
+    pub async fn requote_and_submit(&self, id: &str) -> Result<BatchTransfer> {
+        self.quote(id).await?;
+
+        let items = self
+            .list_items(id, ListBatchItemsParams::new().page_size(1000))
+            .await?;
+        items.check_items()?;
+        let invalid: Vec<String> = items
+            .items
+            .iter()
+            .filter(|item| !item.errors.is_empty())
+            .map(describe_item_errors)
+            .collect();
+        if !invalid.is_empty() {
+            return Err(Error::Validation(format!(
+                "Batch transfer {} has invalid items: {}",
+                id,
+                invalid.join("; ")
+            )));
+        }
+
+        self.submit(id).await
+    }
+
     /// Delete a batch transfer.
     ///
+    /// Airwallex has no separate cancel endpoint for batch transfers; deleting
+    /// is how a batch is aborted before it executes. Deleting a batch that has
+    /// already been submitted fails with the API's error.
+    ///
     /// # API Reference
     ///
     /// `POST /api/v1/batch_transfers/{id}/delete`
+    #[doc(alias = "cancel")]
     pub async fn delete(&self, id: &str) -> Result<()> {
         self.client
             .post_empty_no_response(&format!("/api/v1/batch_transfers/{}/delete", id))
             .await
     }
 }
+
+/// Summarize an item's validation errors as `item_id: message, message`.
+fn describe_item_errors(item: &BatchTransferItem) -> String {
+    let messages: Vec<String> = item
+        .errors
+        .iter()
+        .map(
+            |error| match error.get("message").and_then(|m| m.as_str()) {
+                Some(message) => message.to_string(),
+                None => error.to_string(),
+            },
+        )
+        .collect();
+    format!(
+        "{}: {}",
+        item.id.as_deref().unwrap_or("<unknown item>"),
+        messages.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::BatchTransferStatus;
+    use crate::testing::mock::mock_client;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_requote_and_submit_refreshes_expired_quote() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/batch_transfers/batch_1/quote"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "batch_1",
+                "status": "READY_FOR_SUBMISSION",
+                "quote_summary": {"expires_at": "2099-01-01T00:00:00+0000"}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/batch_transfers/batch_1/items"))
+            .and(query_param("page_size", "1000"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "has_more": false,
+                "items": [{"id": "item_1", "errors": []}]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/batch_transfers/batch_1/submit"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "batch_1",
+                "status": "SCHEDULED"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let batch = client
+            .batch_transfers()
+            .requote_and_submit("batch_1")
+            .await
+            .unwrap();
+        assert_eq!(batch.status, Some(BatchTransferStatus::Scheduled));
+
+        let paths: Vec<String> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|r| r.url.path().to_string())
+            .filter(|p| p.starts_with("/api/v1/batch_transfers"))
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "/api/v1/batch_transfers/batch_1/quote",
+                "/api/v1/batch_transfers/batch_1/items",
+                "/api/v1/batch_transfers/batch_1/submit",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_requote_and_submit_surfaces_item_errors() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/batch_transfers/batch_1/quote"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": "batch_1"})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/batch_transfers/batch_1/items"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "has_more": false,
+                "items": [
+                    {"id": "item_1", "errors": []},
+                    {"id": "item_2", "errors": [{"code": "invalid_beneficiary", "message": "Beneficiary is inactive"}]}
+                ]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/batch_transfers/batch_1/submit"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let err = client
+            .batch_transfers()
+            .requote_and_submit("batch_1")
+            .await
+            .unwrap_err();
+        match err {
+            Error::Validation(message) => {
+                assert!(message.contains("item_2: Beneficiary is inactive"));
+                assert!(!message.contains("item_1"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+}
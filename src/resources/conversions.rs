@@ -2,12 +2,18 @@
 //!
 //! Manage foreign exchange conversions.
 
+use futures_util::Stream;
+
+use super::pagination::{pages, paginate};
+use crate::cache::CacheStatus;
 use crate::client::Client;
 use crate::error::Result;
+use crate::models::conversion_amendments::CreateAmendmentRequest;
 use crate::models::conversions::{
     Conversion, CreateConversionRequest, CreateQuoteRequest, FxRate, GetFxRateParams,
     ListConversionsParams, ListConversionsResponse, RateQuote,
 };
+use crate::models::{PageCheckpoint, PageInfo};
 
 /// The Conversions resource.
 pub struct Conversions<'a> {
@@ -31,6 +37,41 @@ impl<'a> Conversions<'a> {
             .await
     }
 
+    /// Stream every conversion matching `params`, fetching pages as needed.
+    ///
+    /// Starts at `params.page_num` (0 if unset), keeps the filters and page
+    /// size, and stops after the last page. A failed page is yielded as an
+    /// `Err` item and ends the stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/fx/conversions`
+    pub fn stream(
+        &self,
+        params: ListConversionsParams,
+    ) -> impl Stream<Item = Result<Conversion>> + Send + 'static {
+        paginate(self.client, "/api/v1/fx/conversions", params)
+    }
+
+    /// Stream pages of conversions, resuming from a saved checkpoint.
+    ///
+    /// Each page comes with its [`PageInfo`]; store
+    /// [`PageInfo::checkpoint`] once a page is processed and pass it back
+    /// here after a restart to continue with the next page. Without a
+    /// checkpoint the listing starts where `params` point. A failed page is
+    /// yielded as an `Err` item and ends the stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/fx/conversions`
+    pub fn resume_from(
+        &self,
+        checkpoint: Option<PageCheckpoint>,
+        params: ListConversionsParams,
+    ) -> impl Stream<Item = Result<(Vec<Conversion>, PageInfo)>> + Send + 'static {
+        pages(self.client, "/api/v1/fx/conversions", params, checkpoint)
+    }
+
     /// Create a conversion.
     ///
     /// # API Reference
@@ -53,15 +94,67 @@ impl<'a> Conversions<'a> {
             .await
     }
 
+    /// Cancel a conversion before settlement and return the updated conversion.
+    ///
+    /// Airwallex has no dedicated cancel endpoint; this creates a `CANCEL`
+    /// conversion amendment and then fetches the conversion. Use
+    /// [`Conversion::is_amendable`] to check beforehand whether the
+    /// conversion can still be cancelled.
+    ///
+    /// # API Reference
+    ///
+    /// `POST /api/v1/fx/conversion_amendments/create`
+    pub async fn cancel(&self, conversion_id: &str) -> Result<Conversion> {
+        let request =
+            CreateAmendmentRequest::cancel(conversion_id, uuid::Uuid::new_v4().to_string());
+        self.client.conversion_amendments().create(&request).await?;
+        self.get(conversion_id).await
+    }
+
     /// Get current FX rate.
     ///
+    /// When the client is configured with
+    /// [`ConfigBuilder::rate_cache`](crate::ConfigBuilder::rate_cache), a rate
+    /// fetched for identical parameters is reused until it expires. Handles
+    /// for other accounts or API versions never share cached rates.
+    ///
     /// # API Reference
     ///
     /// `GET /api/v1/fx/rates/current`
     pub async fn get_rate(&self, params: &GetFxRateParams) -> Result<FxRate> {
-        self.client
+        let (rate, _) = self.get_rate_with_cache_status(params).await?;
+        Ok(rate)
+    }
+
+    /// Get current FX rate, reporting whether it was served from the rate cache.
+    ///
+    /// Returns [`CacheStatus::Bypass`] when the rate cache is not enabled.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/fx/rates/current`
+    pub async fn get_rate_with_cache_status(
+        &self,
+        params: &GetFxRateParams,
+    ) -> Result<(FxRate, CacheStatus)> {
+        let Some(cache) = self.client.rate_cache() else {
+            let rate = self
+                .client
+                .get_with_query("/api/v1/fx/rates/current", params)
+                .await?;
+            return Ok((rate, CacheStatus::Bypass));
+        };
+
+        let key = self.client.rate_cache_key(params)?;
+        if let Some(rate) = cache.get(&key) {
+            return Ok((rate, CacheStatus::Hit));
+        }
+        let rate: FxRate = self
+            .client
             .get_with_query("/api/v1/fx/rates/current", params)
-            .await
+            .await?;
+        cache.insert(key, rate.clone());
+        Ok((rate, CacheStatus::Miss))
     }
 
     /// Create a rate quote with guaranteed rate for an agreed period.
@@ -84,3 +177,204 @@ impl<'a> Conversions<'a> {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::models::conversions::{CreateConversionRequest, GetFxRateParams};
+    use crate::testing::mock::{mock_client, mock_config, mock_login};
+    use crate::{CacheStatus, Client};
+    use wiremock::matchers::{body_partial_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn mount_rate(server: &MockServer, calls: u64) {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/fx/rates/current"))
+            .and(query_param("sell_currency", "USD"))
+            .and(query_param("buy_currency", "EUR"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "rate": 0.92,
+                "sell_currency": "USD",
+                "buy_currency": "EUR"
+            })))
+            .expect(calls)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_get_rate_cached_within_ttl() {
+        let server = MockServer::start().await;
+        mock_login(&server, "test_token").await;
+        mount_rate(&server, 1).await;
+
+        let client = Client::new(mock_config(&server).rate_cache().build().unwrap()).unwrap();
+        let params = GetFxRateParams::new("USD", "EUR").sell_amount(100.0);
+
+        let first = client.conversions().get_rate(&params).await.unwrap();
+        let second = client.conversions().get_rate(&params).await.unwrap();
+        assert_eq!(first.rate, Some(0.92));
+        assert_eq!(second.rate, Some(0.92));
+    }
+
+    #[tokio::test]
+    async fn test_get_rate_cache_status() {
+        let server = MockServer::start().await;
+        mock_login(&server, "test_token").await;
+        mount_rate(&server, 1).await;
+
+        let client = Client::new(mock_config(&server).rate_cache().build().unwrap()).unwrap();
+        let params = GetFxRateParams::new("USD", "EUR");
+
+        let (_, first) = client
+            .conversions()
+            .get_rate_with_cache_status(&params)
+            .await
+            .unwrap();
+        let (rate, second) = client
+            .conversions()
+            .get_rate_with_cache_status(&params)
+            .await
+            .unwrap();
+        assert_eq!(first, CacheStatus::Miss);
+        assert_eq!(second, CacheStatus::Hit);
+        assert_eq!(rate.rate, Some(0.92));
+    }
+
+    #[tokio::test]
+    async fn test_get_rate_cache_status_bypass() {
+        let (server, client) = mock_client().await;
+        mount_rate(&server, 1).await;
+
+        let (_, status) = client
+            .conversions()
+            .get_rate_with_cache_status(&GetFxRateParams::new("USD", "EUR"))
+            .await
+            .unwrap();
+        assert_eq!(status, CacheStatus::Bypass);
+    }
+
+    #[tokio::test]
+    async fn test_get_rate_cache_is_per_account() {
+        let server = MockServer::start().await;
+        mock_login(&server, "test_token").await;
+        mount_rate(&server, 2).await;
+
+        let client = Client::new(mock_config(&server).rate_cache().build().unwrap()).unwrap();
+        let params = GetFxRateParams::new("USD", "EUR");
+        let account_a = client.with_on_behalf_of("acct_a");
+        let account_b = client.with_on_behalf_of("acct_b");
+
+        let (_, first) = account_a
+            .conversions()
+            .get_rate_with_cache_status(&params)
+            .await
+            .unwrap();
+        let (_, other_account) = account_b
+            .conversions()
+            .get_rate_with_cache_status(&params)
+            .await
+            .unwrap();
+        let (_, again) = account_a
+            .conversions()
+            .get_rate_with_cache_status(&params)
+            .await
+            .unwrap();
+        assert_eq!(first, CacheStatus::Miss);
+        assert_eq!(other_account, CacheStatus::Miss);
+        assert_eq!(again, CacheStatus::Hit);
+    }
+
+    #[tokio::test]
+    async fn test_get_rate_refetches_after_expiry() {
+        let server = MockServer::start().await;
+        mock_login(&server, "test_token").await;
+        mount_rate(&server, 3).await;
+
+        let config = mock_config(&server)
+            .rate_cache_ttl(Duration::from_millis(50))
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+        let params = GetFxRateParams::new("USD", "EUR").sell_amount(100.0);
+
+        client.conversions().get_rate(&params).await.unwrap();
+        // A different amount is a different cache entry.
+        client
+            .conversions()
+            .get_rate(&params.clone().sell_amount(200.0))
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        client.conversions().get_rate(&params).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cancel_creates_amendment_and_refetches() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/fx/conversion_amendments/create"))
+            .and(body_partial_json(serde_json::json!({
+                "conversion_id": "conv_1",
+                "type": "CANCEL"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "amendment_id": "amd_1",
+                "conversion_id": "conv_1",
+                "type": "CANCEL"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/fx/conversions/conv_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "conversion_id": "conv_1",
+                "status": "CANCELLED"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let conversion = client.conversions().cancel("conv_1").await.unwrap();
+        assert_eq!(conversion.status.as_deref(), Some("CANCELLED"));
+        assert!(!conversion.is_amendable());
+    }
+
+    #[tokio::test]
+    async fn test_create_on_behalf_of_connected_account() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/fx/conversions/create"))
+            .and(header("x-on-behalf-of", "acct_wallet"))
+            .and(body_partial_json(serde_json::json!({
+                "sell_currency": "USD",
+                "buy_currency": "EUR"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "conversion_id": "conv_1",
+                "status": "SCHEDULED"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request = CreateConversionRequest::sell("req_1", "USD", 100, "EUR");
+        let conversion = client
+            .with_on_behalf_of("acct_wallet")
+            .conversions()
+            .create(request.clone())
+            .await
+            .unwrap();
+        assert_eq!(conversion.conversion_id.as_deref(), Some("conv_1"));
+        assert!(
+            serde_json::to_value(&request)
+                .unwrap()
+                .get("source")
+                .is_none()
+        );
+    }
+}
@@ -2,12 +2,17 @@
 //!
 //! Manage customers for payment acceptance.
 
+use futures_util::Stream;
+
+use super::pagination::{pages, paginate};
 use crate::client::Client;
 use crate::error::Result;
 use crate::models::customers::{
     ClientSecretResponse, CreateCustomerRequest, Customer, ListCustomersParams,
     ListCustomersResponse, UpdateCustomerRequest,
 };
+use crate::models::payment_links::{CreatePaymentLinkRequest, PaymentLink};
+use crate::models::{PageCheckpoint, PageInfo};
 
 /// The Customers resource.
 pub struct Customers<'a> {
@@ -31,6 +36,41 @@ impl<'a> Customers<'a> {
             .await
     }
 
+    /// Stream every customer matching `params`, fetching pages as needed.
+    ///
+    /// Starts at `params.page_num` (0 if unset), keeps the filters and page
+    /// size, and stops after the last page. A failed page is yielded as an
+    /// `Err` item and ends the stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/pa/customers`
+    pub fn stream(
+        &self,
+        params: ListCustomersParams,
+    ) -> impl Stream<Item = Result<Customer>> + Send + 'static {
+        paginate(self.client, "/api/v1/pa/customers", params)
+    }
+
+    /// Stream pages of customers, resuming from a saved checkpoint.
+    ///
+    /// Each page comes with its [`PageInfo`]; store
+    /// [`PageInfo::checkpoint`] once a page is processed and pass it back
+    /// here after a restart to continue with the next page. Without a
+    /// checkpoint the listing starts where `params` point. A failed page is
+    /// yielded as an `Err` item and ends the stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/pa/customers`
+    pub fn resume_from(
+        &self,
+        checkpoint: Option<PageCheckpoint>,
+        params: ListCustomersParams,
+    ) -> impl Stream<Item = Result<(Vec<Customer>, PageInfo)>> + Send + 'static {
+        pages(self.client, "/api/v1/pa/customers", params, checkpoint)
+    }
+
     /// Create a customer.
     ///
     /// # API Reference
@@ -77,4 +117,57 @@ impl<'a> Customers<'a> {
             ))
             .await
     }
+
+    /// Create a payment link tied to a customer.
+    ///
+    /// Sets `customer_id` on the request, replacing any value already set,
+    /// and creates the link.
+    ///
+    /// # API Reference
+    ///
+    /// `POST /api/v1/pa/payment_links/create`
+    pub async fn create_payment_link(
+        &self,
+        customer_id: &str,
+        request: CreatePaymentLinkRequest,
+    ) -> Result<PaymentLink> {
+        let request = request.customer_id(customer_id);
+        self.client.payment_links().create(&request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::CreatePaymentLinkRequest;
+    use crate::testing::mock::mock_client;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_create_payment_link_sets_customer() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/pa/payment_links/create"))
+            .and(body_partial_json(serde_json::json!({
+                "title": "Annual plan",
+                "customer_id": "cus_123"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "pl_1",
+                "customer_id": "cus_123"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request =
+            CreatePaymentLinkRequest::fixed("Annual plan", 120.0, "USD").customer_id("cus_other");
+        let link = client
+            .customers()
+            .create_payment_link("cus_123", request)
+            .await
+            .unwrap();
+        assert_eq!(link.id.as_deref(), Some("pl_1"));
+    }
 }
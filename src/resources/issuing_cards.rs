@@ -1,10 +1,14 @@
 //! Issuing Cards resource.
 
+use futures_util::Stream;
+
+use super::sync::in_update_order;
 use crate::client::Client;
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::models::common::ListResponse;
 use crate::models::{
-    CardLimits, CreateIssuingCardRequest, IssuingCard, IssuingCardDetails, ListCardsParams,
-    ListCardsResponse, UpdateCardRequest,
+    CardLimits, CreateIssuingCardRequest, DeliveryDetails, IssuingCard, IssuingCardDetails,
+    ListCardsParams, ListCardsResponse, ReissueRequest, UpdateCardRequest,
 };
 
 /// Issuing Cards resource for managing Airwallex issued cards.
@@ -33,6 +37,40 @@ impl<'a> IssuingCards<'a> {
             .await
     }
 
+    /// Everything updated since a checkpoint, oldest update first.
+    ///
+    /// Sets `from_updated_at` to `since` and fetches every page before
+    /// yielding, so items can be ordered by `updated_at`; the last item's
+    /// timestamp is the next checkpoint. If any page fails, the stream
+    /// yields only that error.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/issuing/cards`
+    pub fn sync_since(
+        &self,
+        since: impl Into<String>,
+    ) -> impl Stream<Item = Result<IssuingCard>> + Send + 'static {
+        let client = self.client.clone();
+        let mut params = ListCardsParams::new().from_updated_at(since);
+        let fetch = async move {
+            let mut items = Vec::new();
+            loop {
+                let page: ListCardsResponse = client
+                    .get_with_query("/api/v1/issuing/cards", &params)
+                    .await?;
+                page.check_items()?;
+                let done = !page.has_more || page.items.is_empty();
+                items.extend(page.items);
+                if done {
+                    return Ok(items);
+                }
+                params.page_num = Some(params.page_num.unwrap_or(0) + 1);
+            }
+        };
+        in_update_order(fetch, |card: &IssuingCard| card.updated_at.as_ref())
+    }
+
     /// Get a card by ID.
     pub async fn get(&self, id: &str) -> Result<IssuingCard> {
         self.client
@@ -47,6 +85,33 @@ impl<'a> IssuingCards<'a> {
             .await
     }
 
+    /// Replace a card with a new one that keeps its settings.
+    ///
+    /// Airwallex has no reissue endpoint, so this fetches the card, creates a
+    /// new card for the same cardholder with the same authorization controls
+    /// (see [`ReissueRequest::to_create_request`]), and then closes the old
+    /// card if the [reason](crate::models::ReissueReason::closes_card) calls
+    /// for it. Returns the new card.
+    ///
+    /// If the new card cannot be created, the old card is left open. If it is
+    /// created but the old card cannot be closed, returns
+    /// [`Error::ReissueIncomplete`] with the new card's ID.
+    pub async fn reissue(&self, card_id: &str, request: &ReissueRequest) -> Result<IssuingCard> {
+        let card = self.get(card_id).await?;
+        let create = request.to_create_request(&card)?;
+        let new_card = self.create(&create).await?;
+        if request.reason.closes_card() {
+            let close = UpdateCardRequest::new().card_status("CLOSED");
+            if let Err(e) = self.update(card_id, &close).await {
+                return Err(Error::ReissueIncomplete {
+                    new_card_id: new_card.card_id.clone().unwrap_or_default(),
+                    source: Box::new(e),
+                });
+            }
+        }
+        Ok(new_card)
+    }
+
     /// Activate a physical card.
     pub async fn activate(&self, id: &str) -> Result<IssuingCard> {
         self.client
@@ -63,6 +128,14 @@ impl<'a> IssuingCards<'a> {
             .await
     }
 
+    /// Get shipping and tracking information for a physical card.
+    ///
+    /// Returns `None` for virtual cards. The delivery address is available
+    /// as [`IssuingCard::postal_address`] on the card itself.
+    pub async fn delivery_status(&self, id: &str) -> Result<Option<DeliveryDetails>> {
+        Ok(self.get(id).await?.delivery_details)
+    }
+
     /// Get remaining card limits.
     pub async fn get_limits(&self, id: &str) -> Result<CardLimits> {
         self.client
@@ -70,3 +143,257 @@ impl<'a> IssuingCards<'a> {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use futures_util::StreamExt;
+
+    use crate::error::Error;
+    use crate::models::{ReissueReason, ReissueRequest};
+    use crate::testing::mock::mock_client;
+    use wiremock::matchers::{body_partial_json, method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_reissue_lost_card() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issuing/cards/card_old"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "card_id": "card_old",
+                "card_status": "ACTIVE",
+                "cardholder_id": "ch_1",
+                "form_factor": "VIRTUAL",
+                "is_personalized": true,
+                "brand": "VISA",
+                "nick_name": "Travel",
+                "authorization_controls": {
+                    "allowed_transaction_count": "MULTIPLE",
+                    "allowed_currencies": ["USD"]
+                }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/issuing/cards/card_old/update"))
+            .and(body_partial_json(
+                serde_json::json!({"card_status": "CLOSED"}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "card_id": "card_old",
+                "card_status": "CLOSED"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/issuing/cards/create"))
+            .and(body_partial_json(serde_json::json!({
+                "cardholder_id": "ch_1",
+                "form_factor": "VIRTUAL",
+                "is_personalized": true,
+                "created_by": "Ops",
+                "brand": "VISA",
+                "nick_name": "Travel",
+                "authorization_controls": {
+                    "allowed_transaction_count": "MULTIPLE",
+                    "allowed_currencies": ["USD"]
+                }
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "card_id": "card_new",
+                "card_status": "PENDING",
+                "cardholder_id": "ch_1"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let card = client
+            .issuing_cards()
+            .reissue("card_old", &ReissueRequest::new(ReissueReason::Lost, "Ops"))
+            .await
+            .unwrap();
+        assert_eq!(card.card_id.as_deref(), Some("card_new"));
+    }
+
+    #[tokio::test]
+    async fn test_reissue_keeps_old_card_open_when_create_fails() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issuing/cards/card_old"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "card_id": "card_old",
+                "cardholder_id": "ch_1",
+                "form_factor": "VIRTUAL",
+                "authorization_controls": {"allowed_transaction_count": "MULTIPLE"}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/issuing/cards/create"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "code": "validation_error",
+                "message": "Card limit reached"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/issuing/cards/card_old/update"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let result = client
+            .issuing_cards()
+            .reissue(
+                "card_old",
+                &ReissueRequest::new(ReissueReason::Stolen, "Ops"),
+            )
+            .await;
+        assert!(matches!(result, Err(Error::Api { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_reissue_reports_new_card_when_close_fails() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issuing/cards/card_old"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "card_id": "card_old",
+                "cardholder_id": "ch_1",
+                "form_factor": "VIRTUAL",
+                "authorization_controls": {"allowed_transaction_count": "MULTIPLE"}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/issuing/cards/create"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "card_id": "card_new",
+                "cardholder_id": "ch_1"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/issuing/cards/card_old/update"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "code": "validation_error",
+                "message": "Card cannot be closed"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let error = client
+            .issuing_cards()
+            .reissue("card_old", &ReissueRequest::new(ReissueReason::Lost, "Ops"))
+            .await
+            .unwrap_err();
+        match error {
+            Error::ReissueIncomplete {
+                new_card_id,
+                source,
+            } => {
+                assert_eq!(new_card_id, "card_new");
+                assert!(matches!(*source, Error::Api { .. }));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reissue_expired_card_keeps_old_card_open() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issuing/cards/card_old"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "card_id": "card_old",
+                "cardholder_id": "ch_1",
+                "form_factor": "PHYSICAL",
+                "authorization_controls": {"allowed_transaction_count": "MULTIPLE"}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/issuing/cards/card_old/update"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/issuing/cards/create"))
+            .and(body_partial_json(serde_json::json!({
+                "form_factor": "PHYSICAL",
+                "nick_name": "Renewed"
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "card_id": "card_new"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request = ReissueRequest::new(ReissueReason::Expired, "Ops").nick_name("Renewed");
+        let card = client
+            .issuing_cards()
+            .reissue("card_old", &request)
+            .await
+            .unwrap();
+        assert_eq!(card.card_id.as_deref(), Some("card_new"));
+    }
+
+    #[tokio::test]
+    async fn test_sync_since_pages_in_update_order() {
+        let (server, client) = mock_client().await;
+
+        let pages = [
+            (
+                "0",
+                true,
+                serde_json::json!([
+                    {"card_id": "card_b", "updated_at": "2024-05-03T10:00:00+0000"},
+                    {"card_id": "card_a", "updated_at": "2024-05-01T09:00:00+0000"}
+                ]),
+            ),
+            (
+                "1",
+                false,
+                serde_json::json!([
+                    {"card_id": "card_c", "updated_at": "2024-05-02T12:30:00+00:00"}
+                ]),
+            ),
+        ];
+        for (page_num, has_more, items) in pages {
+            let mut mock = Mock::given(method("GET"))
+                .and(path("/api/v1/issuing/cards"))
+                .and(query_param("from_updated_at", "2024-05-01T00:00:00+0000"));
+            if page_num != "0" {
+                mock = mock.and(query_param("page_num", page_num));
+            }
+            mock.respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "has_more": has_more,
+                "items": items
+            })))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        }
+
+        let ids: Vec<String> = client
+            .issuing_cards()
+            .sync_since("2024-05-01T00:00:00+0000")
+            .map(|card| card.unwrap().card_id.unwrap())
+            .collect()
+            .await;
+        assert_eq!(ids, ["card_a", "card_c", "card_b"]);
+    }
+}
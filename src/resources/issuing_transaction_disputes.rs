@@ -1,11 +1,16 @@
 //! Issuing Transaction Disputes resource.
 
+use futures_util::Stream;
+
+use super::pagination::{pages, paginate};
+use super::sync::in_update_order;
 use crate::client::Client;
 use crate::error::Result;
+use crate::models::common::ListResponse;
 use crate::models::{
     CreateIssuingTransactionDisputeRequest, IssuingTransactionDispute,
-    ListIssuingTransactionDisputesParams, ListIssuingTransactionDisputesResponse,
-    UpdateIssuingTransactionDisputeRequest,
+    ListIssuingTransactionDisputesParams, ListIssuingTransactionDisputesResponse, PageCheckpoint,
+    PageInfo, UpdateIssuingTransactionDisputeRequest,
 };
 
 /// Issuing Transaction Disputes resource for managing card transaction disputes.
@@ -48,6 +53,100 @@ impl<'a> IssuingTransactionDisputes<'a> {
             .await
     }
 
+    /// List one page of disputes, with the cursors around it.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/issuing/transaction_disputes`
+    pub async fn list_page(
+        &self,
+        params: &ListIssuingTransactionDisputesParams,
+    ) -> Result<(Vec<IssuingTransactionDispute>, PageInfo)> {
+        let page = self.list(params).await?;
+        page.check_items()?;
+        Ok((
+            page.items,
+            PageInfo::cursors(page.page_after, page.page_before),
+        ))
+    }
+
+    /// Stream every dispute matching `params`, following the `page_after` cursor.
+    ///
+    /// Keeps the filters and page size, and stops when the API returns no
+    /// further cursor. A failed page is yielded as an `Err` item and ends the
+    /// stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/issuing/transaction_disputes`
+    pub fn stream(
+        &self,
+        params: ListIssuingTransactionDisputesParams,
+    ) -> impl Stream<Item = Result<IssuingTransactionDispute>> + Send + 'static {
+        paginate(self.client, "/api/v1/issuing/transaction_disputes", params)
+    }
+
+    /// Stream pages of disputes, resuming from a saved checkpoint.
+    ///
+    /// Each page comes with its [`PageInfo`]; store
+    /// [`PageInfo::checkpoint`] once a page is processed and pass it back
+    /// here after a restart to continue with the next page. Without a
+    /// checkpoint the listing starts where `params` point. A failed page is
+    /// yielded as an `Err` item and ends the stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/issuing/transaction_disputes`
+    pub fn resume_from(
+        &self,
+        checkpoint: Option<PageCheckpoint>,
+        params: ListIssuingTransactionDisputesParams,
+    ) -> impl Stream<Item = Result<(Vec<IssuingTransactionDispute>, PageInfo)>> + Send + 'static
+    {
+        pages(
+            self.client,
+            "/api/v1/issuing/transaction_disputes",
+            params,
+            checkpoint,
+        )
+    }
+
+    /// Everything updated since a checkpoint, oldest update first.
+    ///
+    /// Sets `from_updated_at` to `since` and fetches every page before
+    /// yielding, so items can be ordered by `updated_at`; the last item's
+    /// timestamp is the next checkpoint. If any page fails, the stream
+    /// yields only that error.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/issuing/transaction_disputes`
+    pub fn sync_since(
+        &self,
+        since: impl Into<String>,
+    ) -> impl Stream<Item = Result<IssuingTransactionDispute>> + Send + 'static {
+        let client = self.client.clone();
+        let mut params = ListIssuingTransactionDisputesParams::new().from_updated_at(since);
+        let fetch = async move {
+            let mut items = Vec::new();
+            loop {
+                let page: ListIssuingTransactionDisputesResponse = client
+                    .get_with_query("/api/v1/issuing/transaction_disputes", &params)
+                    .await?;
+                page.check_items()?;
+                let done = page.items.is_empty();
+                items.extend(page.items);
+                match page.page_after {
+                    Some(cursor) if !done => params.page = Some(cursor),
+                    _ => return Ok(items),
+                }
+            }
+        };
+        in_update_order(fetch, |dispute: &IssuingTransactionDispute| {
+            dispute.updated_at.as_ref()
+        })
+    }
+
     /// Get a transaction dispute by ID.
     ///
     /// # API Reference
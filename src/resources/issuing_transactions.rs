@@ -1,5 +1,8 @@
 //! Issuing Transactions resource.
 
+use futures_util::Stream;
+
+use super::pagination::paginate;
 use crate::client::Client;
 use crate::error::Result;
 use crate::models::{
@@ -30,6 +33,33 @@ impl<'a> IssuingTransactions<'a> {
             .await
     }
 
+    /// Stream every transaction matching `params`, fetching pages as needed.
+    ///
+    /// Keeps the filters, such as `from_created_at` and `to_created_at`, and
+    /// the page size, and advances `page_num` while `has_more` is set. A
+    /// failed page is yielded as an `Err` item and ends the stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/issuing/transactions`
+    pub fn stream(
+        &self,
+        params: ListIssuingTransactionsParams,
+    ) -> impl Stream<Item = Result<IssuingTransaction>> + Send + 'static {
+        paginate(self.client, "/api/v1/issuing/transactions", params)
+    }
+
+    /// List issuing transactions for a single card.
+    ///
+    /// Overrides any `card_id` filter already set on `params`.
+    pub async fn for_card(
+        &self,
+        card_id: &str,
+        params: ListIssuingTransactionsParams,
+    ) -> Result<ListIssuingTransactionsResponse> {
+        self.list(&params.card_id(card_id)).await
+    }
+
     /// Get a transaction by ID.
     pub async fn get(&self, id: &str) -> Result<IssuingTransaction> {
         self.client
@@ -37,3 +67,84 @@ impl<'a> IssuingTransactions<'a> {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use futures_util::StreamExt;
+
+    use super::*;
+    use crate::testing::mock::mock_client;
+    use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_for_card_sets_card_filter() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issuing/transactions"))
+            .and(query_param("card_id", "card_123"))
+            .and(query_param("billing_currency", "USD"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "has_more": false,
+                "items": [{"transaction_id": "txn_1", "card_id": "card_123"}]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let params = ListIssuingTransactionsParams::new().billing_currency("USD");
+        let response = client
+            .issuing_transactions()
+            .for_card("card_123", params)
+            .await
+            .unwrap();
+
+        assert_eq!(response.items.len(), 1);
+        assert_eq!(response.items[0].card_id.as_deref(), Some("card_123"));
+    }
+
+    #[tokio::test]
+    async fn test_stream_follows_page_num_within_date_range() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issuing/transactions"))
+            .and(query_param("from_created_at", "2024-03-01T00:00:00Z"))
+            .and(query_param("to_created_at", "2024-03-02T00:00:00Z"))
+            .and(query_param("page_size", "1"))
+            .and(query_param_is_missing("page_num"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "has_more": true,
+                "items": [{"transaction_id": "txn_1"}]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/issuing/transactions"))
+            .and(query_param("from_created_at", "2024-03-01T00:00:00Z"))
+            .and(query_param("to_created_at", "2024-03-02T00:00:00Z"))
+            .and(query_param("page_size", "1"))
+            .and(query_param("page_num", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "has_more": false,
+                "items": [{"transaction_id": "txn_2"}]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let params = ListIssuingTransactionsParams::new()
+            .from_created_at("2024-03-01T00:00:00Z")
+            .to_created_at("2024-03-02T00:00:00Z")
+            .page_size(1);
+        let ids: Vec<_> = client
+            .issuing_transactions()
+            .stream(params)
+            .map(|transaction| transaction.unwrap().transaction_id.unwrap())
+            .collect()
+            .await;
+        assert_eq!(ids, ["txn_1", "txn_2"]);
+    }
+}
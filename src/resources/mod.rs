@@ -20,6 +20,7 @@ mod issuing_config;
 mod issuing_transaction_disputes;
 mod issuing_transactions;
 mod linked_accounts;
+mod pagination;
 mod payers;
 mod payment_attempts;
 mod payment_config;
@@ -32,7 +33,9 @@ mod reconciliation;
 mod reference_data;
 mod refunds;
 mod settlements;
+mod sync;
 mod transfers;
+mod webhooks;
 
 pub use account_capabilities::AccountCapabilities;
 pub use accounts::Accounts;
@@ -67,3 +70,4 @@ pub use reference_data::ReferenceData;
 pub use refunds::Refunds;
 pub use settlements::Settlements;
 pub use transfers::Transfers;
+pub use webhooks::Webhooks;
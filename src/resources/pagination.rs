@@ -0,0 +1,154 @@
+//! Streams over paginated list endpoints.
+
+use futures_util::{Stream, StreamExt, stream};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::models::common::ListResponse;
+use crate::models::{
+    ListConversionsParams, ListConversionsResponse, ListCustomersParams, ListCustomersResponse,
+    ListIssuingTransactionDisputesParams, ListIssuingTransactionDisputesResponse,
+    ListIssuingTransactionsParams, ListIssuingTransactionsResponse, ListPaymentDisputesParams,
+    ListPaymentDisputesResponse, ListPaymentIntentsParams, ListPaymentIntentsResponse,
+    ListTransfersParams, ListTransfersResponse, PageCheckpoint, PageInfo,
+};
+
+/// List params that can be pointed at a page.
+pub(crate) trait Paged: Serialize + Send + Sync + 'static {
+    /// The list response for these params.
+    type Response: DeserializeOwned + ListResponse + IntoIterator<Item: Send + 'static> + Send;
+
+    /// Point the params at a checkpoint.
+    fn seek(&mut self, checkpoint: PageCheckpoint) -> Result<()>;
+
+    /// Where the page fetched with these params leaves off.
+    fn page_info(&self, page: &Self::Response) -> PageInfo;
+}
+
+/// An item of a [`Paged`] listing.
+pub(crate) type Item<P> = <<P as Paged>::Response as IntoIterator>::Item;
+
+macro_rules! numbered_paging {
+    ($($params:ty => $response:ty),* $(,)?) => {
+        $(
+            impl Paged for $params {
+                type Response = $response;
+
+                fn seek(&mut self, checkpoint: PageCheckpoint) -> Result<()> {
+                    match checkpoint {
+                        PageCheckpoint::PageNum(page_num) => {
+                            self.page_num = Some(page_num);
+                            Ok(())
+                        }
+                        PageCheckpoint::Cursor(_) => Err(Error::Validation(
+                            "this listing is paged by page_num, not by cursor".to_string(),
+                        )),
+                    }
+                }
+
+                fn page_info(&self, page: &$response) -> PageInfo {
+                    PageInfo::numbered(page.has_more && !page.items.is_empty(), self.page_num)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! cursor_paging {
+    ($($params:ty => $response:ty),* $(,)?) => {
+        $(
+            impl Paged for $params {
+                type Response = $response;
+
+                fn seek(&mut self, checkpoint: PageCheckpoint) -> Result<()> {
+                    match checkpoint {
+                        PageCheckpoint::Cursor(cursor) => {
+                            self.page = Some(cursor);
+                            Ok(())
+                        }
+                        PageCheckpoint::PageNum(_) => Err(Error::Validation(
+                            "this listing is paged by cursor, not by page_num".to_string(),
+                        )),
+                    }
+                }
+
+                fn page_info(&self, page: &$response) -> PageInfo {
+                    PageInfo::cursors(
+                        page.page_after.clone().filter(|_| !page.items.is_empty()),
+                        page.page_before.clone(),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+numbered_paging!(
+    ListConversionsParams => ListConversionsResponse,
+    ListCustomersParams => ListCustomersResponse,
+    ListIssuingTransactionsParams => ListIssuingTransactionsResponse,
+    ListPaymentIntentsParams => ListPaymentIntentsResponse,
+    ListTransfersParams => ListTransfersResponse,
+);
+
+cursor_paging!(
+    ListIssuingTransactionDisputesParams => ListIssuingTransactionDisputesResponse,
+    ListPaymentDisputesParams => ListPaymentDisputesResponse,
+);
+
+/// Stream the pages of a list endpoint, each with where it leaves off.
+///
+/// Starts at `checkpoint` if given, otherwise where `params` point. A failed
+/// page, a page whose `items` is not an array, or a checkpoint of the wrong
+/// kind is yielded as an `Err` item and ends the stream.
+pub(crate) fn pages<P: Paged>(
+    client: &Client,
+    path: &'static str,
+    mut params: P,
+    checkpoint: Option<PageCheckpoint>,
+) -> impl Stream<Item = Result<(Vec<Item<P>>, PageInfo)>> + Send + 'static {
+    let client = client.clone();
+    let start = match checkpoint {
+        Some(checkpoint) => params.seek(checkpoint).map(|()| params),
+        None => Ok(params),
+    };
+    stream::unfold(Some(start), move |state| {
+        let client = client.clone();
+        async move {
+            let mut params = match state? {
+                Ok(params) => params,
+                Err(e) => return Some((Err(e), None)),
+            };
+            let page = client.get_with_query::<P::Response, _>(path, &params).await;
+            match page.and_then(|page| page.check_items().map(|()| page)) {
+                Ok(page) => {
+                    let info = params.page_info(&page);
+                    let next = info
+                        .checkpoint()
+                        .and_then(|checkpoint| params.seek(checkpoint).ok())
+                        .map(|()| Ok(params));
+                    Some((Ok((page.into_iter().collect(), info)), next))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        }
+    })
+}
+
+/// Stream every item of a list endpoint, one page at a time.
+///
+/// A failed page is yielded as an `Err` item and ends the stream.
+pub(crate) fn paginate<P: Paged>(
+    client: &Client,
+    path: &'static str,
+    params: P,
+) -> impl Stream<Item = Result<Item<P>>> + Send + 'static {
+    pages(client, path, params, None).flat_map(|page| {
+        stream::iter(match page {
+            Ok((items, _)) => items.into_iter().map(Ok).collect(),
+            Err(e) => vec![Err(e)],
+        })
+    })
+}
@@ -50,10 +50,10 @@ impl<'a> Payers<'a> {
     ///
     /// # API Reference
     ///
-    /// `POST /api/v1/payers/{id}/update`
+    /// `POST /api/v1/payers/update/{payer_id}`
     pub async fn update(&self, id: &str, request: UpdatePayerRequest) -> Result<PayerContact> {
         self.client
-            .post(&format!("/api/v1/payers/{}/update", id), &request)
+            .post(&format!("/api/v1/payers/update/{}", id), &request)
             .await
     }
 
@@ -61,10 +61,10 @@ impl<'a> Payers<'a> {
     ///
     /// # API Reference
     ///
-    /// `POST /api/v1/payers/{id}/delete`
+    /// `POST /api/v1/payers/delete/{payer_id}`
     pub async fn delete(&self, id: &str) -> Result<()> {
         self.client
-            .post_empty_no_response(&format!("/api/v1/payers/{}/delete", id))
+            .post_empty_no_response(&format!("/api/v1/payers/delete/{}", id))
             .await
     }
 
@@ -82,3 +82,47 @@ impl<'a> Payers<'a> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::models::payers::{CreatePayerRequest, PayerAddress, UpdatePayerRequest};
+    use crate::testing::mock::mock_client;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_update_payer_path() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/payers/update/payer_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "payer_1",
+                "nickname": "Acme"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request = UpdatePayerRequest {
+            payer: CreatePayerRequest::company("Acme Ltd", PayerAddress::new("GB")).payer,
+            nickname: Some("Acme".to_string()),
+        };
+        let payer = client.payers().update("payer_1", request).await.unwrap();
+        assert_eq!(payer.id.as_deref(), Some("payer_1"));
+    }
+
+    #[tokio::test]
+    async fn test_delete_payer_path() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/payers/delete/payer_1"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        client.payers().delete("payer_1").await.unwrap();
+    }
+}
@@ -1,10 +1,15 @@
 //! Payment Disputes resource.
 
+use futures_util::Stream;
+
+use super::pagination::{pages, paginate};
+use super::sync::in_update_order;
 use crate::client::Client;
 use crate::error::Result;
+use crate::models::common::ListResponse;
 use crate::models::{
     AcceptDisputeRequest, ChallengeDisputeRequest, ListPaymentDisputesParams,
-    ListPaymentDisputesResponse, PaymentDispute,
+    ListPaymentDisputesResponse, PageCheckpoint, PageInfo, PaymentDispute,
 };
 
 /// Payment Disputes resource for managing chargebacks and RFIs.
@@ -29,6 +34,99 @@ impl<'a> PaymentDisputes<'a> {
             .await
     }
 
+    /// List one page of disputes, with the cursors around it.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/pa/payment_disputes`
+    pub async fn list_page(
+        &self,
+        params: &ListPaymentDisputesParams,
+    ) -> Result<(Vec<PaymentDispute>, PageInfo)> {
+        let page = self.list(params).await?;
+        page.check_items()?;
+        Ok((
+            page.items,
+            PageInfo::cursors(page.page_after, page.page_before),
+        ))
+    }
+
+    /// Stream every dispute matching `params`, following the `page_after` cursor.
+    ///
+    /// Keeps the filters and page size, and stops when the API returns no
+    /// further cursor. A failed page is yielded as an `Err` item and ends the
+    /// stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/pa/payment_disputes`
+    pub fn stream(
+        &self,
+        params: ListPaymentDisputesParams,
+    ) -> impl Stream<Item = Result<PaymentDispute>> + Send + 'static {
+        paginate(self.client, "/api/v1/pa/payment_disputes", params)
+    }
+
+    /// Stream pages of disputes, resuming from a saved checkpoint.
+    ///
+    /// Each page comes with its [`PageInfo`]; store
+    /// [`PageInfo::checkpoint`] once a page is processed and pass it back
+    /// here after a restart to continue with the next page. Without a
+    /// checkpoint the listing starts where `params` point. A failed page is
+    /// yielded as an `Err` item and ends the stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/pa/payment_disputes`
+    pub fn resume_from(
+        &self,
+        checkpoint: Option<PageCheckpoint>,
+        params: ListPaymentDisputesParams,
+    ) -> impl Stream<Item = Result<(Vec<PaymentDispute>, PageInfo)>> + Send + 'static {
+        pages(
+            self.client,
+            "/api/v1/pa/payment_disputes",
+            params,
+            checkpoint,
+        )
+    }
+
+    /// Everything updated since a checkpoint, oldest update first.
+    ///
+    /// Sets `from_updated_at` to `since` and fetches every page before
+    /// yielding, so items can be ordered by `updated_at`; the last item's
+    /// timestamp is the next checkpoint. If any page fails, the stream
+    /// yields only that error.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/pa/payment_disputes`
+    pub fn sync_since(
+        &self,
+        since: impl Into<String>,
+    ) -> impl Stream<Item = Result<PaymentDispute>> + Send + 'static {
+        let client = self.client.clone();
+        let mut params = ListPaymentDisputesParams::new().from_updated_at(since);
+        let fetch = async move {
+            let mut items = Vec::new();
+            loop {
+                let page: ListPaymentDisputesResponse = client
+                    .get_with_query("/api/v1/pa/payment_disputes", &params)
+                    .await?;
+                page.check_items()?;
+                let done = page.items.is_empty();
+                items.extend(page.items);
+                match page.page_after {
+                    Some(cursor) if !done => params.page = Some(cursor),
+                    _ => return Ok(items),
+                }
+            }
+        };
+        in_update_order(fetch, |dispute: &PaymentDispute| {
+            dispute.updated_at.as_ref()
+        })
+    }
+
     /// Get a payment dispute by ID.
     pub async fn get(&self, id: &str) -> Result<PaymentDispute> {
         self.client
@@ -60,3 +158,108 @@ impl<'a> PaymentDisputes<'a> {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use futures_util::StreamExt;
+
+    use crate::testing::mock::mock_client;
+    use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_sync_since_follows_cursor() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/pa/payment_disputes"))
+            .and(query_param("from_updated_at", "2024-05-01T00:00:00+0000"))
+            .and(query_param_is_missing("page"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [{"id": "dsp_2", "updated_at": "2024-05-04T00:00:00+0000"}],
+                "page_after": "cursor_1"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/pa/payment_disputes"))
+            .and(query_param("page", "cursor_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [{"id": "dsp_1", "updated_at": "2024-05-02T00:00:00+0000"}]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let ids: Vec<String> = client
+            .payment_disputes()
+            .sync_since("2024-05-01T00:00:00+0000")
+            .map(|dispute| dispute.unwrap().id.unwrap())
+            .collect()
+            .await;
+        assert_eq!(ids, ["dsp_1", "dsp_2"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_page_reports_cursors() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/pa/payment_disputes"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [{"id": "dsp_1"}],
+                "page_after": "cursor_2",
+                "page_before": "cursor_0"
+            })))
+            .mount(&server)
+            .await;
+
+        let (items, page) = client
+            .payment_disputes()
+            .list_page(&crate::models::ListPaymentDisputesParams::new())
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 1);
+        assert!(page.has_more);
+        assert_eq!(page.next_cursor.as_deref(), Some("cursor_2"));
+        assert_eq!(page.prev_cursor.as_deref(), Some("cursor_0"));
+        assert_eq!(page.next_page_num, None);
+    }
+
+    #[tokio::test]
+    async fn test_stream_follows_page_after() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/pa/payment_disputes"))
+            .and(query_param("size", "1"))
+            .and(query_param_is_missing("page"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [{"id": "dsp_1"}],
+                "page_after": "cursor_2"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/pa/payment_disputes"))
+            .and(query_param("size", "1"))
+            .and(query_param("page", "cursor_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [{"id": "dsp_2"}],
+                "page_before": "cursor_1"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let ids: Vec<_> = client
+            .payment_disputes()
+            .stream(crate::models::ListPaymentDisputesParams::new().size(1))
+            .map(|dispute| dispute.unwrap().id.unwrap())
+            .collect()
+            .await;
+        assert_eq!(ids, ["dsp_1", "dsp_2"]);
+    }
+}
@@ -2,13 +2,20 @@
 //!
 //! Manage payment intents for accepting payments.
 
+use futures_util::Stream;
+use serde::Serialize;
+
+use super::pagination::{pages, paginate};
 use crate::client::Client;
 use crate::error::Result;
+use crate::models::common::ListResponse;
 use crate::models::payment_intents::{
     CancelPaymentIntentRequest, CapturePaymentIntentRequest, ConfirmPaymentIntentRequest,
-    CreatePaymentIntentRequest, ListPaymentIntentsParams, ListPaymentIntentsResponse,
-    PaymentIntent,
+    CreatePaymentIntentRequest, GetPaymentIntentParams, ListPaymentIntentsParams,
+    ListPaymentIntentsResponse, PaymentIntent,
 };
+use crate::models::payment_methods::CreateCardRequest;
+use crate::models::{PageCheckpoint, PageInfo};
 
 /// The Payment Intents resource.
 pub struct PaymentIntents<'a> {
@@ -35,6 +42,61 @@ impl<'a> PaymentIntents<'a> {
             .await
     }
 
+    /// List one page of payment intents, with where to find the next.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/pa/payment_intents`
+    pub async fn list_page(
+        &self,
+        params: ListPaymentIntentsParams,
+    ) -> Result<(Vec<PaymentIntent>, PageInfo)> {
+        let page_num = params.page_num;
+        let page = self.list(params).await?;
+        page.check_items()?;
+        Ok((page.items, PageInfo::numbered(page.has_more, page_num)))
+    }
+
+    /// Stream every payment intent matching `params`, fetching pages as needed.
+    ///
+    /// Starts at `params.page_num` (0 if unset), keeps the filters and page
+    /// size, and stops after the last page. A failed page is yielded as an
+    /// `Err` item and ends the stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/pa/payment_intents`
+    pub fn stream(
+        &self,
+        params: ListPaymentIntentsParams,
+    ) -> impl Stream<Item = Result<PaymentIntent>> + Send + 'static {
+        paginate(self.client, "/api/v1/pa/payment_intents", params)
+    }
+
+    /// Stream pages of payment intents, resuming from a saved checkpoint.
+    ///
+    /// Each page comes with its [`PageInfo`]; store
+    /// [`PageInfo::checkpoint`] once a page is processed and pass it back
+    /// here after a restart to continue with the next page. Without a
+    /// checkpoint the listing starts where `params` point. A failed page is
+    /// yielded as an `Err` item and ends the stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/pa/payment_intents`
+    pub fn resume_from(
+        &self,
+        checkpoint: Option<PageCheckpoint>,
+        params: ListPaymentIntentsParams,
+    ) -> impl Stream<Item = Result<(Vec<PaymentIntent>, PageInfo)>> + Send + 'static {
+        pages(
+            self.client,
+            "/api/v1/pa/payment_intents",
+            params,
+            checkpoint,
+        )
+    }
+
     /// Create a payment intent.
     ///
     /// # API Reference
@@ -57,6 +119,24 @@ impl<'a> PaymentIntents<'a> {
             .await
     }
 
+    /// Get a payment intent by ID with additional parameters.
+    ///
+    /// Use [`GetPaymentIntentParams::expand_latest_payment_attempt`] to have
+    /// `latest_payment_attempt` populated with the full attempt.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/pa/payment_intents/{id}`
+    pub async fn get_with_params(
+        &self,
+        id: &str,
+        params: &GetPaymentIntentParams,
+    ) -> Result<PaymentIntent> {
+        self.client
+            .get_with_query(&format!("/api/v1/pa/payment_intents/{}", id), params)
+            .await
+    }
+
     /// Confirm a payment intent.
     ///
     /// # API Reference
@@ -75,6 +155,38 @@ impl<'a> PaymentIntents<'a> {
             .await
     }
 
+    /// Confirm a payment intent with raw card details.
+    ///
+    /// Only for merchants in PCI DSS scope; everyone else should confirm
+    /// with a tokenized payment method. The card is sent as a `card` payment
+    /// method and never converted into a loggable form; its `Debug` output
+    /// masks the card number and CVC. A fresh `request_id` is generated.
+    ///
+    /// # API Reference
+    ///
+    /// `POST /api/v1/pa/payment_intents/{id}/confirm`
+    pub async fn confirm_with_card(
+        &self,
+        id: &str,
+        card: CreateCardRequest,
+        return_url: impl Into<String>,
+    ) -> Result<PaymentIntent> {
+        let request = ConfirmWithCard {
+            request_id: uuid::Uuid::new_v4().to_string(),
+            payment_method: CardPaymentMethod {
+                method_type: "card",
+                card,
+            },
+            return_url: return_url.into(),
+        };
+        self.client
+            .post(
+                &format!("/api/v1/pa/payment_intents/{}/confirm", id),
+                &request,
+            )
+            .await
+    }
+
     /// Capture a payment intent.
     ///
     /// # API Reference
@@ -111,3 +223,100 @@ impl<'a> PaymentIntents<'a> {
             .await
     }
 }
+
+/// Body of a confirm request carrying raw card details.
+///
+/// Kept private and without `Debug` so the card number only ever leaves
+/// the process serialized into the request.
+#[derive(Serialize)]
+struct ConfirmWithCard {
+    request_id: String,
+    payment_method: CardPaymentMethod,
+    return_url: String,
+}
+
+/// A `card` payment method object.
+#[derive(Serialize)]
+struct CardPaymentMethod {
+    #[serde(rename = "type")]
+    method_type: &'static str,
+    card: CreateCardRequest,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::payment_methods::CreateCardRequest;
+    use crate::testing::mock::mock_client;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, ResponseTemplate};
+
+    const PAN: &str = "4111111111111111";
+
+    #[tokio::test]
+    async fn test_confirm_with_card_body() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/pa/payment_intents/int_1/confirm"))
+            .and(body_partial_json(serde_json::json!({
+                "payment_method": {
+                    "type": "card",
+                    "card": {
+                        "number": PAN,
+                        "expiry_month": "12",
+                        "expiry_year": "2030",
+                        "cvc": "123"
+                    }
+                },
+                "return_url": "https://shop.example.com/return"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "int_1",
+                "status": "SUCCEEDED"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let card = CreateCardRequest::new(PAN, "12", "2030").cvc("123");
+        let intent = client
+            .payment_intents()
+            .confirm_with_card("int_1", card, "https://shop.example.com/return")
+            .await
+            .unwrap();
+        assert_eq!(intent.id.as_deref(), Some("int_1"));
+
+        let requests = server.received_requests().await.unwrap();
+        let body: serde_json::Value =
+            serde_json::from_slice(&requests.last().unwrap().body).unwrap();
+        assert!(body["request_id"].as_str().is_some_and(|id| !id.is_empty()));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_with_card_masks_pan() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/pa/payment_intents/int_1/confirm"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "code": "validation_error",
+                "message": "Card declined"
+            })))
+            .mount(&server)
+            .await;
+
+        let card = CreateCardRequest::new(PAN, "12", "2030").cvc("123");
+        let debug = format!("{:?}", card);
+        assert!(!debug.contains(PAN), "{}", debug);
+        assert!(debug.contains("************1111"), "{}", debug);
+        assert!(!debug.contains("123"), "{}", debug);
+
+        let err = client
+            .payment_intents()
+            .confirm_with_card("int_1", card, "https://shop.example.com/return")
+            .await
+            .unwrap_err();
+        assert!(!format!("{:?}", err).contains(PAN));
+        assert!(!err.to_string().contains(PAN));
+    }
+}
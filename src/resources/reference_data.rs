@@ -2,7 +2,7 @@
 
 use crate::client::Client;
 use crate::error::Result;
-use crate::models::SupportedCurrencies;
+use crate::models::{InvalidConversionDates, SupportedCurrencies};
 
 /// Reference Data resource for retrieving reference information.
 #[derive(Debug)]
@@ -28,4 +28,21 @@ impl<'a> ReferenceData<'a> {
             .get("/api/v1/reference/supported_currencies")
             .await
     }
+
+    /// Get the dates a currency pair cannot be converted on.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/reference/invalid_conversion_dates`
+    pub async fn invalid_conversion_dates(
+        &self,
+        currency_pair: &str,
+    ) -> Result<InvalidConversionDates> {
+        self.client
+            .get_with_query(
+                "/api/v1/reference/invalid_conversion_dates",
+                &[("currency_pair", currency_pair)],
+            )
+            .await
+    }
 }
@@ -0,0 +1,33 @@
+//! Incremental sync support shared by resources.
+
+use std::future::Future;
+
+use futures_util::{Stream, StreamExt, stream};
+
+use crate::error::Result;
+use crate::models::common::{Timestamp, timestamp_to_utc};
+
+/// Run `fetch`, then yield its items ordered by `updated_at`, oldest first.
+///
+/// Items without a parseable timestamp come first. A fetch error is
+/// yielded as the only item, so a partial result is never mistaken for a
+/// complete one.
+pub(super) fn in_update_order<T, F>(
+    fetch: F,
+    updated_at: fn(&T) -> Option<&Timestamp>,
+) -> impl Stream<Item = Result<T>> + Send + 'static
+where
+    T: Send + 'static,
+    F: Future<Output = Result<Vec<T>>> + Send + 'static,
+{
+    stream::once(fetch).flat_map(move |fetched| {
+        let items: Vec<Result<T>> = match fetched {
+            Ok(mut items) => {
+                items.sort_by_cached_key(|item| updated_at(item).and_then(timestamp_to_utc));
+                items.into_iter().map(Ok).collect()
+            }
+            Err(e) => vec![Err(e)],
+        };
+        stream::iter(items)
+    })
+}
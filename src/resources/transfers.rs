@@ -2,21 +2,58 @@
 //!
 //! Manage payout transfers (sending payments to beneficiaries).
 
-use crate::client::Client;
+use std::sync::Arc;
+
+use futures_util::{Stream, StreamExt, stream};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use super::pagination::{pages, paginate};
+use crate::client::{Client, RequestOptions};
 use crate::error::Result;
+use crate::models::common::ListResponse;
 use crate::models::transfers::{
-    CreateTransferRequest, ListTransfersParams, ListTransfersResponse, Transfer,
+    CreateTransferRequest, ListTransfersParams, ListTransfersResponse, Transfer, ValueDateEstimate,
 };
+use crate::models::{PageCheckpoint, PageInfo};
 
 /// The Transfers resource.
 pub struct Transfers<'a> {
     client: &'a Client,
+    options: RequestOptions,
 }
 
 impl<'a> Transfers<'a> {
     /// Create a new Transfers resource.
     pub(crate) fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Send `key` as the idempotency key of [`create`](Self::create).
+    ///
+    /// Retries reuse the key, so a transfer is created at most once. See
+    /// [`RequestOptions::idempotency_key`].
+    ///
+    /// ```no_run
+    /// # use airwallex_rs::models::CreateTransferRequest;
+    /// # async fn example(
+    /// #     client: &airwallex_rs::Client,
+    /// #     request: CreateTransferRequest,
+    /// # ) -> airwallex_rs::Result<()> {
+    /// let transfer = client
+    ///     .transfers()
+    ///     .idempotency_key("payout-2024-06-01-42")
+    ///     .create(request)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.options = self.options.idempotency_key(key);
+        self
     }
 
     /// List transfers.
@@ -30,13 +67,98 @@ impl<'a> Transfers<'a> {
             .await
     }
 
+    /// List one page of transfers, with where to find the next.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/transfers`
+    pub async fn list_page(
+        &self,
+        params: ListTransfersParams,
+    ) -> Result<(Vec<Transfer>, PageInfo)> {
+        let page_num = params.page_num;
+        let page = self.list(params).await?;
+        page.check_items()?;
+        Ok((page.items, PageInfo::numbered(page.has_more, page_num)))
+    }
+
+    /// Stream every transfer matching `params`, fetching pages as needed.
+    ///
+    /// Starts at `params.page_num` (0 if unset), keeps the filters and page
+    /// size, and stops after the last page. A failed page is yielded as an
+    /// `Err` item and ends the stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/transfers`
+    pub fn stream(
+        &self,
+        params: ListTransfersParams,
+    ) -> impl Stream<Item = Result<Transfer>> + Send + 'static {
+        paginate(self.client, "/api/v1/transfers", params)
+    }
+
+    /// Stream pages of transfers, resuming from a saved checkpoint.
+    ///
+    /// Each page comes with its [`PageInfo`]; store
+    /// [`PageInfo::checkpoint`] once a page is processed and pass it back
+    /// here after a restart to continue with the next page. Without a
+    /// checkpoint the listing starts where `params` point. A failed page is
+    /// yielded as an `Err` item and ends the stream.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/transfers`
+    pub fn resume_from(
+        &self,
+        checkpoint: Option<PageCheckpoint>,
+        params: ListTransfersParams,
+    ) -> impl Stream<Item = Result<(Vec<Transfer>, PageInfo)>> + Send + 'static {
+        pages(self.client, "/api/v1/transfers", params, checkpoint)
+    }
+
+    /// Estimate the earliest value date for a transfer starting today (UTC).
+    ///
+    /// Returns `None` for same-currency transfers, which have no conversion
+    /// calendar. See [`ValueDateEstimate`] for what the estimate covers.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/reference/invalid_conversion_dates`
+    pub async fn estimate_arrival(
+        &self,
+        source_currency: &str,
+        target_currency: &str,
+        payment_method: &str,
+    ) -> Result<Option<ValueDateEstimate>> {
+        if source_currency.eq_ignore_ascii_case(target_currency) {
+            return Ok(None);
+        }
+        let pair = format!("{}{}", source_currency, target_currency).to_uppercase();
+        let dates = self
+            .client
+            .reference_data()
+            .invalid_conversion_dates(&pair)
+            .await?;
+        Ok(Some(ValueDateEstimate::from_invalid_dates(
+            &dates,
+            payment_method,
+            chrono::Utc::now().date_naive(),
+        )))
+    }
+
     /// Create a transfer.
     ///
+    /// The request is checked with [`CreateTransferRequest::validate`] first.
+    ///
     /// # API Reference
     ///
     /// `POST /api/v1/transfers/create`
     pub async fn create(&self, request: CreateTransferRequest) -> Result<Transfer> {
-        self.client.post("/api/v1/transfers/create", &request).await
+        request.validate()?;
+        self.client
+            .post_with_options("/api/v1/transfers/create", &request, &self.options)
+            .await
     }
 
     /// Get a transfer by ID.
@@ -47,4 +169,418 @@ impl<'a> Transfers<'a> {
     pub async fn get(&self, id: &str) -> Result<Transfer> {
         self.client.get(&format!("/api/v1/transfers/{}", id)).await
     }
+
+    /// Get several transfers concurrently.
+    ///
+    /// At most `concurrency` requests are in flight at once (a value of 0 is
+    /// treated as 1). Results are returned in the same order as `ids`, and a
+    /// failure for one ID, such as [`Error::NotFound`](crate::Error::NotFound),
+    /// does not affect the others.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/transfers/{id}`
+    pub async fn get_many<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        concurrency: usize,
+    ) -> Vec<Result<Transfer>> {
+        let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+        let mut tasks = JoinSet::new();
+
+        for (index, id) in ids.iter().enumerate() {
+            let client = self.client.clone();
+            let semaphore = Arc::clone(&semaphore);
+            let path = format!("/api/v1/transfers/{}", id.as_ref());
+            tasks.spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                (index, client.get::<Transfer>(&path).await)
+            });
+        }
+
+        let mut results: Vec<Option<Result<Transfer>>> = ids.iter().map(|_| None).collect();
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok((index, result)) => results[index] = Some(result),
+                Err(e) => std::panic::resume_unwind(e.into_panic()),
+            }
+        }
+        results
+            .into_iter()
+            .map(|result| result.expect("every task reports a result"))
+            .collect()
+    }
+
+    /// List transfers across several connected accounts.
+    ///
+    /// Each account's transfers are listed with `x-on-behalf-of` set to the
+    /// account and every page is fetched in turn, starting from
+    /// `params.page_num`. Items are tagged with their account ID. Up to
+    /// `concurrency` accounts are listed at once (0 is treated as 1), so
+    /// items from different accounts may interleave. An error ends that
+    /// account's listing without affecting the others.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/transfers`
+    pub fn list_all_accounts<I, S>(
+        &self,
+        accounts: I,
+        params: ListTransfersParams,
+        concurrency: usize,
+    ) -> impl Stream<Item = (String, Result<Transfer>)> + Send + 'static
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let per_account: Vec<_> = accounts
+            .into_iter()
+            .map(|account| account_transfers(self.client, account.into(), params.clone()).boxed())
+            .collect();
+        stream::iter(per_account).flatten_unordered(concurrency.max(1))
+    }
+}
+
+/// Stream every transfer of one connected account, page by page.
+fn account_transfers(
+    client: &Client,
+    account_id: String,
+    params: ListTransfersParams,
+) -> impl Stream<Item = (String, Result<Transfer>)> + Send + 'static {
+    let client = client.with_on_behalf_of(account_id.clone());
+    stream::unfold(Some(params), move |params| {
+        let client = client.clone();
+        async move {
+            let mut params = params?;
+            let page = client
+                .get_with_query::<ListTransfersResponse, _>("/api/v1/transfers", &params)
+                .await
+                .and_then(|page| page.check_items().map(|()| page));
+            match page {
+                Ok(page) => {
+                    let next = (page.has_more && !page.items.is_empty()).then(|| {
+                        params.page_num = Some(params.page_num.unwrap_or(0) + 1);
+                        params
+                    });
+                    Some((page.items.into_iter().map(Ok).collect::<Vec<_>>(), next))
+                }
+                Err(e) => Some((vec![Err(e)], None)),
+            }
+        }
+    })
+    .flat_map(stream::iter)
+    .map(move |result| (account_id.clone(), result))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::StreamExt;
+
+    use crate::error::Error;
+    use crate::models::transfers::{CreateTransferRequest, ListTransfersParams};
+    use crate::testing::mock::mock_client;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_get_many_collects_per_id_results() {
+        let (server, client) = mock_client().await;
+
+        for id in ["tfr_1", "tfr_3"] {
+            Mock::given(method("GET"))
+                .and(path(format!("/api/v1/transfers/{}", id)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "id": id,
+                    "status": "PAID"
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/api/v1/transfers/tfr_2"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let results = client
+            .transfers()
+            .get_many(&["tfr_1", "tfr_2", "tfr_3"], 2)
+            .await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().id.as_deref(), Some("tfr_1"));
+        assert!(matches!(results[1], Err(Error::NotFound)));
+        assert_eq!(results[2].as_ref().unwrap().id.as_deref(), Some("tfr_3"));
+    }
+
+    #[tokio::test]
+    async fn test_list_all_accounts_tags_items() {
+        let (server, client) = mock_client().await;
+
+        let pages = [
+            (
+                "acct_a",
+                "0",
+                serde_json::json!([{"id": "tfr_a1"}, {"id": "tfr_a2"}]),
+                true,
+            ),
+            ("acct_a", "1", serde_json::json!([{"id": "tfr_a3"}]), false),
+            ("acct_b", "0", serde_json::json!([{"id": "tfr_b1"}]), false),
+        ];
+        for (account, page_num, items, has_more) in pages {
+            Mock::given(method("GET"))
+                .and(path("/api/v1/transfers"))
+                .and(header("x-on-behalf-of", account))
+                .and(query_param("page_num", page_num))
+                .and(query_param("status", "PAID"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "has_more": has_more,
+                    "items": items
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/api/v1/transfers"))
+            .and(header("x-on-behalf-of", "acct_c"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "code": "forbidden",
+                "message": "Access denied"
+            })))
+            .mount(&server)
+            .await;
+
+        let params = ListTransfersParams::new().status("PAID").page_num(0);
+        let mut results: Vec<(String, Option<String>)> = client
+            .transfers()
+            .list_all_accounts(["acct_a", "acct_b", "acct_c"], params, 2)
+            .map(|(account, result)| (account, result.ok().and_then(|t| t.id)))
+            .collect()
+            .await;
+        results.sort();
+
+        let owned = |account: &str, id: Option<&str>| (account.to_string(), id.map(String::from));
+        assert_eq!(
+            results,
+            vec![
+                owned("acct_a", Some("tfr_a1")),
+                owned("acct_a", Some("tfr_a2")),
+                owned("acct_a", Some("tfr_a3")),
+                owned("acct_b", Some("tfr_b1")),
+                owned("acct_c", None),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_page_reports_next_page_num() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/transfers"))
+            .and(query_param("page_num", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "has_more": true,
+                "items": [{"id": "tfr_1"}]
+            })))
+            .mount(&server)
+            .await;
+
+        let (items, page) = client
+            .transfers()
+            .list_page(ListTransfersParams::new().page_num(2))
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 1);
+        assert!(page.has_more);
+        assert_eq!(page.next_page_num, Some(3));
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn test_stream_reports_non_array_items() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/transfers"))
+            .and(query_param("page_num", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "has_more": true,
+                "items": {"code": "internal_error", "message": "upstream timeout"}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let params = ListTransfersParams::new().page_num(0);
+        let results: Vec<_> = client.transfers().stream(params).collect().await;
+
+        assert_eq!(results.len(), 1);
+        match &results[0] {
+            Err(Error::Serialization(e)) => assert!(e.to_string().contains("upstream timeout")),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_walks_pages_and_surfaces_errors() {
+        let (server, client) = mock_client().await;
+
+        for (page_num, id) in [("0", "tfr_1"), ("1", "tfr_2")] {
+            Mock::given(method("GET"))
+                .and(path("/api/v1/transfers"))
+                .and(query_param("status", "PAID"))
+                .and(query_param("page_size", "1"))
+                .and(query_param("page_num", page_num))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "has_more": true,
+                    "items": [{"id": id}]
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/api/v1/transfers"))
+            .and(query_param("page_num", "2"))
+            .respond_with(ResponseTemplate::new(500).set_body_json(serde_json::json!({
+                "code": "internal_error",
+                "message": "boom"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let params = ListTransfersParams::new()
+            .status("PAID")
+            .page_size(1)
+            .page_num(0);
+        let results: Vec<_> = client.transfers().stream(params).collect().await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().id.as_deref(), Some("tfr_1"));
+        assert_eq!(results[1].as_ref().unwrap().id.as_deref(), Some("tfr_2"));
+        assert!(matches!(results[2], Err(Error::Api { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_resume_from_checkpoint_has_no_gaps_or_duplicates() {
+        use crate::models::PageCheckpoint;
+
+        let (server, client) = mock_client().await;
+
+        for (page_num, ids, has_more) in [
+            ("0", ["tfr_1", "tfr_2"], true),
+            ("1", ["tfr_3", "tfr_4"], true),
+            ("2", ["tfr_5", "tfr_6"], false),
+        ] {
+            Mock::given(method("GET"))
+                .and(path("/api/v1/transfers"))
+                .and(query_param("page_size", "2"))
+                .and(query_param("page_num", page_num))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "has_more": has_more,
+                    "items": [{"id": ids[0]}, {"id": ids[1]}]
+                })))
+                .mount(&server)
+                .await;
+        }
+
+        let params = ListTransfersParams::new().page_size(2).page_num(0);
+        let ids = |items: Vec<crate::models::Transfer>| {
+            items.into_iter().map(|t| t.id.unwrap()).collect::<Vec<_>>()
+        };
+
+        // Process one page, save the checkpoint, and stop.
+        let mut seen = Vec::new();
+        let saved = {
+            let mut pages = Box::pin(client.transfers().resume_from(None, params.clone()));
+            let (items, info) = pages.next().await.unwrap().unwrap();
+            seen.extend(ids(items));
+            serde_json::to_string(&info.checkpoint().unwrap()).unwrap()
+        };
+        assert_eq!(saved, r#"{"page_num":1}"#);
+
+        // Resume after a "restart".
+        let checkpoint: PageCheckpoint = serde_json::from_str(&saved).unwrap();
+        let pages: Vec<_> = client
+            .transfers()
+            .resume_from(Some(checkpoint), params.clone())
+            .collect()
+            .await;
+        assert_eq!(pages.len(), 2);
+        for page in pages {
+            let (items, _) = page.unwrap();
+            seen.extend(ids(items));
+        }
+        assert_eq!(seen, ["tfr_1", "tfr_2", "tfr_3", "tfr_4", "tfr_5", "tfr_6"]);
+
+        let wrong_kind: Vec<_> = client
+            .transfers()
+            .resume_from(Some(PageCheckpoint::Cursor("c".to_string())), params)
+            .collect()
+            .await;
+        assert!(matches!(wrong_kind[..], [Err(Error::Validation(_))]));
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_reused_on_retry() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/transfers/create"))
+            .and(header("idempotency-key", "key_1"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/transfers/create"))
+            .and(header("idempotency-key", "key_1"))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(serde_json::json!({"id": "tfr_1"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request = CreateTransferRequest::local("req_1", "ben_1", "USD", 100.0, "Invoice 42");
+        let transfer = client
+            .transfers()
+            .idempotency_key("key_1")
+            .create(request)
+            .await
+            .unwrap();
+        assert_eq!(transfer.id.as_deref(), Some("tfr_1"));
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_conflict() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/transfers/create"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "code": "idempotency_key_conflict",
+                "message": "Idempotency key was used with a different request",
+                "trace_id": "trace_1"
+            })))
+            .mount(&server)
+            .await;
+
+        let request = CreateTransferRequest::local("req_2", "ben_1", "USD", 50.0, "Invoice 43");
+        let result = client
+            .transfers()
+            .idempotency_key("key_1")
+            .create(request)
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::IdempotencyConflict { trace_id: Some(ref id), .. }) if id == "trace_1"
+        ));
+    }
 }
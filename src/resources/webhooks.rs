@@ -0,0 +1,187 @@
+//! Webhooks resource for the Airwallex API.
+//!
+//! Manage webhook subscriptions. To verify the events themselves, see
+//! [`crate::webhooks`].
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::models::webhooks::{
+    CreateWebhookRequest, ListWebhooksParams, ListWebhooksResponse, UpdateWebhookRequest, Webhook,
+};
+
+/// The Webhooks resource.
+pub struct Webhooks<'a> {
+    client: &'a Client,
+}
+
+impl<'a> Webhooks<'a> {
+    /// Create a new Webhooks resource.
+    pub(crate) fn new(client: &'a Client) -> Self {
+        Self { client }
+    }
+
+    /// List webhooks.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/webhooks`
+    pub async fn list(&self, params: ListWebhooksParams) -> Result<ListWebhooksResponse> {
+        self.client
+            .get_with_query("/api/v1/webhooks", &params)
+            .await
+    }
+
+    /// Create a webhook.
+    ///
+    /// # API Reference
+    ///
+    /// `POST /api/v1/webhooks/create`
+    pub async fn create(&self, request: CreateWebhookRequest) -> Result<Webhook> {
+        self.client.post("/api/v1/webhooks/create", &request).await
+    }
+
+    /// Get a webhook by ID.
+    ///
+    /// # API Reference
+    ///
+    /// `GET /api/v1/webhooks/{id}`
+    pub async fn get(&self, id: &str) -> Result<Webhook> {
+        self.client.get(&format!("/api/v1/webhooks/{}", id)).await
+    }
+
+    /// Update a webhook.
+    ///
+    /// # API Reference
+    ///
+    /// `POST /api/v1/webhooks/{id}/update`
+    pub async fn update(&self, id: &str, request: UpdateWebhookRequest) -> Result<Webhook> {
+        self.client
+            .post(&format!("/api/v1/webhooks/{}/update", id), &request)
+            .await
+    }
+
+    /// Delete a webhook.
+    ///
+    /// # API Reference
+    ///
+    /// `POST /api/v1/webhooks/{id}/delete`
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        self.client
+            .post_empty_no_response(&format!("/api/v1/webhooks/{}/delete", id))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{
+        CreateWebhookRequest, EventName, ListWebhooksParams, UpdateWebhookRequest,
+    };
+    use crate::testing::mock::mock_client;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_create_webhook() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/webhooks/create"))
+            .and(body_json(serde_json::json!({
+                "request_id": "req_1",
+                "url": "https://example.com/hooks",
+                "events": ["payment_intent.succeeded", "payout.transfer.paid"],
+                "version": client.api_version()
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "wh_1",
+                "url": "https://example.com/hooks",
+                "events": ["payment_intent.succeeded", "payout.transfer.paid"],
+                "secret": "whsec_1"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let webhook = client
+            .webhooks()
+            .create(CreateWebhookRequest::new(
+                "req_1",
+                "https://example.com/hooks",
+                [
+                    EventName::PaymentIntentSucceeded,
+                    EventName::PayoutTransferPaid,
+                ],
+                client.api_version(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(webhook.id.as_deref(), Some("wh_1"));
+        assert_eq!(webhook.events[1], EventName::PayoutTransferPaid);
+        assert!(!format!("{:?}", webhook).contains("whsec_1"));
+    }
+
+    #[tokio::test]
+    async fn test_update_webhook_sends_url() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/webhooks/wh_1/update"))
+            .and(body_json(serde_json::json!({
+                "url": "https://example.com/new-hooks"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "wh_1",
+                "url": "https://example.com/new-hooks",
+                "events": []
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let webhook = client
+            .webhooks()
+            .update(
+                "wh_1",
+                UpdateWebhookRequest::new("https://example.com/new-hooks"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            webhook.url.as_deref(),
+            Some("https://example.com/new-hooks")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_webhooks() {
+        let (server, client) = mock_client().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/webhooks"))
+            .and(query_param("page_size", "20"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "has_more": false,
+                "items": [{
+                    "id": "wh_1",
+                    "url": "https://example.com/hooks",
+                    "events": ["refund.settled", "issuing.card.created"]
+                }]
+            })))
+            .mount(&server)
+            .await;
+
+        let response = client
+            .webhooks()
+            .list(ListWebhooksParams::new().page_size(20))
+            .await
+            .unwrap();
+        assert_eq!(
+            response.items[0].events,
+            [
+                EventName::RefundSettled,
+                EventName::Unknown("issuing.card.created".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! Automatic retries for transient failures.
+//!
+//! Requests that fail with a rate limit (429), a gateway error (502, 503,
+//! 504) or a connection error are retried with exponential backoff, up to
+//! [`ConfigBuilder::max_retries`](crate::ConfigBuilder::max_retries) times.
+//! A `Retry-After` header on a 429 response takes precedence over the backoff.
+//!
+//! A request body is serialized once and resent byte for byte, so a retried
+//! create carries the same `request_id` and Airwallex treats it as the same
+//! operation.
+//!
+//! Each retry emits a `tracing` event named `retry` and is reported to the
+//! [`RetryObserver`] configured with
+//! [`ConfigBuilder::retry_observer`](crate::ConfigBuilder::retry_observer).
+
+use std::fmt;
+use std::time::Duration;
+
+/// Upper bound for a single backoff delay.
+pub const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Why a request is being retried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetryReason {
+    /// The API responded with a retryable HTTP status.
+    Status(u16),
+    /// The request failed before a response was received.
+    Transport(String),
+}
+
+impl fmt::Display for RetryReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RetryReason::Status(status) => write!(f, "HTTP {}", status),
+            RetryReason::Transport(error) => write!(f, "transport error: {}", error),
+        }
+    }
+}
+
+/// A retry about to be made.
+#[derive(Debug, Clone)]
+pub struct RetryEvent {
+    /// The retry number, starting at 1 for the first retry.
+    pub attempt: u32,
+    /// How long the client waits before retrying.
+    pub delay: Duration,
+    /// HTTP method of the request.
+    pub method: reqwest::Method,
+    /// Request path, without the base URL.
+    pub path: String,
+    /// What triggered the retry.
+    pub reason: RetryReason,
+}
+
+/// Callback notified before each retry.
+///
+/// Implemented for any `Fn(&RetryEvent) + Send + Sync` closure, so a closure
+/// can be passed directly to
+/// [`ConfigBuilder::retry_observer`](crate::ConfigBuilder::retry_observer).
+///
+/// ```
+/// use airwallex_rs::Config;
+/// use airwallex_rs::retry::RetryEvent;
+///
+/// let config = Config::builder()
+///     .client_id("id")
+///     .api_key("key")
+///     .retry_observer(|event: &RetryEvent| {
+///         eprintln!("retry #{} in {:?}: {}", event.attempt, event.delay, event.reason);
+///     })
+///     .build();
+/// ```
+pub trait RetryObserver: Send + Sync {
+    /// Called before the client sleeps and retries a request.
+    fn on_retry(&self, event: &RetryEvent);
+}
+
+impl<F> RetryObserver for F
+where
+    F: Fn(&RetryEvent) + Send + Sync,
+{
+    fn on_retry(&self, event: &RetryEvent) {
+        self(event)
+    }
+}
+
+/// Whether a response status should be retried.
+pub(crate) fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    matches!(status.as_u16(), 429 | 502 | 503 | 504)
+}
+
+/// Exponential backoff for the given retry number (starting at 1).
+pub(crate) fn backoff(base: Duration, attempt: u32) -> Duration {
+    let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+    base.saturating_mul(factor).min(MAX_RETRY_DELAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let base = Duration::from_millis(500);
+        assert_eq!(backoff(base, 1), Duration::from_millis(500));
+        assert_eq!(backoff(base, 2), Duration::from_secs(1));
+        assert_eq!(backoff(base, 3), Duration::from_secs(2));
+        assert_eq!(backoff(base, 40), MAX_RETRY_DELAY);
+    }
+
+    #[test]
+    fn test_retryable_status() {
+        assert!(is_retryable_status(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(
+            reqwest::StatusCode::SERVICE_UNAVAILABLE
+        ));
+        assert!(!is_retryable_status(
+            reqwest::StatusCode::INTERNAL_SERVER_ERROR
+        ));
+        assert!(!is_retryable_status(reqwest::StatusCode::BAD_REQUEST));
+    }
+}
@@ -0,0 +1,163 @@
+//! An in-memory HTTP backend with scripted responses.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+
+use crate::error::Result;
+use crate::http::{HttpBackend, RawResponse};
+
+/// A request received by a [`MockBackend`].
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// HTTP method.
+    pub method: Method,
+    /// Full request URL, including the query string.
+    pub url: reqwest::Url,
+    /// Request headers.
+    pub headers: HeaderMap,
+    /// Request body, if any.
+    pub body: Option<Vec<u8>>,
+    /// Request timeout, if set.
+    pub timeout: Option<Duration>,
+}
+
+/// An [`HttpBackend`] that answers from a script instead of the network.
+///
+/// Responses are matched by method and URL path; the first matching entry
+/// wins. Unmatched requests get a 404. Pass the backend to
+/// [`Client::with_backend`](crate::Client::with_backend), wrapped in an `Arc`
+/// if the recorded requests should be inspected afterwards.
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use airwallex_rs::testing::{MockBackend, RawResponse};
+/// use airwallex_rs::{Client, Config};
+/// use reqwest::{Method, StatusCode};
+///
+/// # async fn example() -> airwallex_rs::Result<()> {
+/// let backend = Arc::new(MockBackend::new().respond(
+///     Method::GET,
+///     "/api/v1/transfers/tfr_1",
+///     RawResponse::json(StatusCode::OK, &serde_json::json!({"id": "tfr_1"})),
+/// ));
+/// let config = Config::builder().static_token("token").build()?;
+/// let client = Client::with_backend(config, Arc::clone(&backend));
+///
+/// let transfer = client.transfers().get("tfr_1").await?;
+/// assert_eq!(backend.requests().len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct MockBackend {
+    routes: Vec<(Method, String, RawResponse)>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl MockBackend {
+    /// Create a backend with no scripted responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer requests for `method` and `path` with `response`.
+    pub fn respond(
+        mut self,
+        method: Method,
+        path: impl Into<String>,
+        response: RawResponse,
+    ) -> Self {
+        self.routes.push((method, path.into(), response));
+        self
+    }
+
+    /// The requests received so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+#[async_trait]
+impl HttpBackend for MockBackend {
+    async fn execute(&self, request: reqwest::Request) -> Result<RawResponse> {
+        let recorded = RecordedRequest {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(<[u8]>::to_vec),
+            timeout: request.timeout().copied(),
+        };
+
+        let response = self
+            .routes
+            .iter()
+            .find(|(method, path, _)| *method == recorded.method && path == recorded.url.path())
+            .map(|(_, _, response)| response.clone())
+            .unwrap_or_else(|| {
+                RawResponse::json(
+                    StatusCode::NOT_FOUND,
+                    &serde_json::json!({
+                        "code": "not_found",
+                        "message": format!("no mock response for {} {}", recorded.method, recorded.url.path())
+                    }),
+                )
+            });
+
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(recorded);
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::models::TransferStatus;
+    use crate::{Client, Config, Error};
+
+    #[tokio::test]
+    async fn test_resource_parses_scripted_response() {
+        let backend = Arc::new(MockBackend::new().respond(
+            Method::GET,
+            "/api/v1/transfers/tfr_1",
+            RawResponse::json(
+                StatusCode::OK,
+                &serde_json::json!({
+                    "id": "tfr_1",
+                    "status": "PAID",
+                    "source_currency": "USD"
+                }),
+            ),
+        ));
+        let config = Config::builder().static_token("scripted").build().unwrap();
+        let client = Client::with_backend(config, Arc::clone(&backend));
+
+        let transfer = client.transfers().get("tfr_1").await.unwrap();
+        assert_eq!(transfer.id.as_deref(), Some("tfr_1"));
+        assert_eq!(transfer.status, Some(TransferStatus::Paid));
+
+        assert!(matches!(
+            client.transfers().get("tfr_missing").await,
+            Err(Error::NotFound)
+        ));
+
+        let requests = backend.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].headers["authorization"], "Bearer scripted");
+    }
+}
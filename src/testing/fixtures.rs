@@ -0,0 +1,136 @@
+//! Sample API payloads and the models they deserialize into.
+//!
+//! The JSON mirrors the shape of real Airwallex responses so downstream code
+//! can be exercised without calling the API.
+
+use crate::models::{Beneficiary, Conversion, PaymentIntent, Transfer};
+
+/// Sample payload for `GET /api/v1/transfers/{id}`.
+pub const TRANSFER_JSON: &str = r#"{
+    "id": "7f687fe6-dcf4-4462-92fa-80335301d9d2",
+    "request_id": "e4c7ad18-6a8c-4b4f-9d2b-2a3c4f5e6a7b",
+    "status": "PAID",
+    "short_reference_id": "P220202-ABCDEF",
+    "source_amount": 1000.0,
+    "source_currency": "USD",
+    "target_amount": 920.45,
+    "target_currency": "EUR",
+    "amount_beneficiary_receives": 920.45,
+    "fee_amount": 0.0,
+    "fee_currency": "USD",
+    "fee_paid_by": "PAYER",
+    "payment_method": "LOCAL",
+    "reference": "Invoice 1024",
+    "reason": "professional_business_services",
+    "beneficiary_id": "bd1a6c4e-5f3b-4a8e-9c2d-1e0f2a3b4c5d",
+    "created_at": "2024-02-02T08:15:30+0000",
+    "updated_at": "2024-02-02T09:01:12+0000",
+    "completion_date": "2024-02-02",
+    "metadata": {"order_id": "1024"}
+}"#;
+
+/// Sample payload for `GET /api/v1/pa/payment_intents/{id}`.
+pub const PAYMENT_INTENT_JSON: &str = r#"{
+    "id": "int_hkdmr7v9rg1j58ky8re",
+    "request_id": "b01737e1-c5a0-4a54-a7b8-bd1da8e9a0ad",
+    "amount": 100.0,
+    "currency": "USD",
+    "status": "SUCCEEDED",
+    "captured_amount": 100.0,
+    "merchant_order_id": "order-8a2f",
+    "descriptor": "Airwallex - Test Descriptor",
+    "customer_id": "cus_ps8e0ZgQzd2QnCxVpzJrHD6KOVu",
+    "client_secret": "eyJhbGciOiJIUzI1NiJ9.eyJpYXQiOjE2MDYyOTQ",
+    "created_at": "2024-03-01T10:00:00+0000",
+    "updated_at": "2024-03-01T10:02:41+0000",
+    "metadata": {"channel": "web"}
+}"#;
+
+/// Sample payload for `GET /api/v1/conversions/{id}`.
+pub const CONVERSION_JSON: &str = r#"{
+    "conversion_id": "6c2dc266-09ad-4235-b61a-767c7cd6d6ea",
+    "request_id": "3d1b2c4e-8f7a-4b6c-9d0e-1f2a3b4c5d6e",
+    "status": "SCHEDULED",
+    "buy_amount": 10000.0,
+    "buy_currency": "EUR",
+    "sell_amount": 10864.11,
+    "sell_currency": "USD",
+    "client_rate": 1.086411,
+    "currency_pair": "EURUSD",
+    "dealt_currency": "EUR",
+    "conversion_date": "2024-04-03",
+    "settlement_cutoff_time": "2024-04-03T14:00:00+0000",
+    "short_reference_id": "C240401-XYZ123",
+    "reason": "Settle supplier invoice",
+    "created_at": "2024-04-01T07:30:00+0000",
+    "last_updated_at": "2024-04-01T07:30:00+0000"
+}"#;
+
+/// Sample payload for `GET /api/v1/beneficiaries/{id}`.
+pub const BENEFICIARY_JSON: &str = r#"{
+    "id": "bd1a6c4e-5f3b-4a8e-9c2d-1e0f2a3b4c5d",
+    "type": "BANK_ACCOUNT",
+    "company_name": "Acme GmbH",
+    "entity_type": "COMPANY",
+    "bank_details": {
+        "account_name": "Acme GmbH",
+        "account_currency": "EUR",
+        "bank_country_code": "DE",
+        "bank_name": "Deutsche Bank",
+        "swift_code": "DEUTDEFF",
+        "iban": "DE89370400440532013000",
+        "local_clearing_system": "SEPA"
+    },
+    "address": {
+        "street_address": "Friedrichstrasse 1",
+        "city": "Berlin",
+        "postcode": "10117",
+        "country_code": "DE"
+    },
+    "created_at": "2024-01-15T12:00:00+0000",
+    "updated_at": "2024-01-15T12:00:00+0000"
+}"#;
+
+/// A paid USD to EUR transfer.
+pub fn transfer() -> Transfer {
+    serde_json::from_str(TRANSFER_JSON).expect("transfer fixture is valid")
+}
+
+/// A succeeded USD payment intent.
+pub fn payment_intent() -> PaymentIntent {
+    serde_json::from_str(PAYMENT_INTENT_JSON).expect("payment intent fixture is valid")
+}
+
+/// A scheduled USD to EUR conversion.
+pub fn conversion() -> Conversion {
+    serde_json::from_str(CONVERSION_JSON).expect("conversion fixture is valid")
+}
+
+/// A company beneficiary with a German SEPA account.
+pub fn beneficiary() -> Beneficiary {
+    serde_json::from_str(BENEFICIARY_JSON).expect("beneficiary fixture is valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Amount;
+
+    #[test]
+    fn test_fixtures_deserialize() {
+        let transfer = transfer();
+        assert_eq!(transfer.source_currency.as_deref(), Some("USD"));
+        assert_eq!(transfer.target_amount, Some(Amount::from(920.45)));
+
+        let intent = payment_intent();
+        assert_eq!(intent.id.as_deref(), Some("int_hkdmr7v9rg1j58ky8re"));
+        assert_eq!(intent.amount, Some(Amount::from(100.0)));
+
+        let conversion = conversion();
+        assert_eq!(conversion.currency_pair.as_deref(), Some("EURUSD"));
+
+        let beneficiary = beneficiary();
+        let bank = beneficiary.bank_details.unwrap();
+        assert_eq!(bank.iban.as_deref(), Some("DE89370400440532013000"));
+    }
+}
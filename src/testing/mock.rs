@@ -0,0 +1,40 @@
+//! Mock server helpers for the crate's own tests.
+
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::config::ConfigBuilder;
+use crate::{Client, Config};
+
+/// Start a mock server that accepts logins and a client pointed at it.
+///
+/// The client authenticates with the bearer token `test_token`.
+pub(crate) async fn mock_client() -> (MockServer, Client) {
+    let server = MockServer::start().await;
+    mock_login(&server, "test_token").await;
+
+    let client = Client::new(mock_config(&server).build().expect("valid test config"))
+        .expect("valid test client");
+
+    (server, client)
+}
+
+/// Mount a login endpoint on the server that issues the given token.
+pub(crate) async fn mock_login(server: &MockServer, token: &str) {
+    Mock::given(method("POST"))
+        .and(path("/api/v1/authentication/login"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "token": token,
+            "expires_at": "2099-01-01T00:00:00Z"
+        })))
+        .mount(server)
+        .await;
+}
+
+/// A config builder with test credentials pointed at the server.
+pub(crate) fn mock_config(server: &MockServer) -> ConfigBuilder {
+    Config::builder()
+        .client_id("test_client")
+        .api_key("test_key")
+        .base_url(server.uri())
+}
@@ -0,0 +1,15 @@
+//! Test helpers for code built on top of this crate.
+//!
+//! Enabled with the `testing` feature.
+
+mod backend;
+pub mod fixtures;
+mod skip;
+
+pub use crate::http::{HttpBackend, RawResponse};
+pub use crate::skip_if_unavailable;
+pub use backend::{MockBackend, RecordedRequest};
+pub use skip::{SkipReason, skip_reason};
+
+#[cfg(test)]
+pub(crate) mod mock;
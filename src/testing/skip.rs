@@ -0,0 +1,141 @@
+//! Skipping integration tests against endpoints the account cannot use.
+
+use std::fmt;
+
+use crate::error::Error;
+
+/// Why an endpoint is unavailable to the account under test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The API key lacks the scope for the endpoint.
+    PermissionDenied,
+    /// The product is not enabled for the account.
+    FeatureNotEnabled,
+    /// The endpoint is not available in the configured API version.
+    UnsupportedApiVersion,
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SkipReason::PermissionDenied => "permission not available",
+            SkipReason::FeatureNotEnabled => "feature not enabled for this account",
+            SkipReason::UnsupportedApiVersion => "endpoint requires a different API version",
+        })
+    }
+}
+
+/// Classify an error as a reason to skip a test, or `None` for a real failure.
+pub fn skip_reason(error: &Error) -> Option<SkipReason> {
+    if error.is_unsupported_api_version() {
+        return Some(SkipReason::UnsupportedApiVersion);
+    }
+    let (code, message) = match error {
+        Error::Api { code, message, .. } => (code.as_str(), message.as_str()),
+        Error::Authentication(message) => ("", message.as_str()),
+        _ => return None,
+    };
+    if message.to_ascii_lowercase().contains("not enabled") {
+        Some(SkipReason::FeatureNotEnabled)
+    } else if error.is_permission_denied() || code.eq_ignore_ascii_case("unauthorized") {
+        Some(SkipReason::PermissionDenied)
+    } else {
+        None
+    }
+}
+
+/// Unwrap a result, or return from the test if the endpoint is unavailable.
+///
+/// Prints `SKIPPED` with the reason and returns early on errors classified by
+/// [`skip_reason`](crate::testing::skip_reason); panics on any other error.
+///
+/// ```no_run
+/// use airwallex_rs::Client;
+/// use airwallex_rs::testing::skip_if_unavailable;
+///
+/// # async fn example(client: Client) {
+/// let balances = skip_if_unavailable!(client.balances().current().await);
+/// assert!(balances.items.iter().all(|b| !b.currency.is_empty()));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! skip_if_unavailable {
+    ($result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(error) => match $crate::testing::skip_reason(&error) {
+                Some(reason) => {
+                    println!("SKIPPED: {}", reason);
+                    return;
+                }
+                None => panic!("Unexpected error: {:?}", error),
+            },
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_error(code: &str, message: &str) -> Error {
+        Error::Api {
+            code: code.to_string(),
+            message: message.to_string(),
+            trace_id: None,
+            details: None,
+        }
+    }
+
+    #[test]
+    fn test_skip_reason() {
+        let cases = [
+            (
+                Error::Authentication("Insufficient permissions".to_string()),
+                Some(SkipReason::PermissionDenied),
+            ),
+            (
+                api_error("unauthorized", "Access denied"),
+                Some(SkipReason::PermissionDenied),
+            ),
+            (
+                api_error("forbidden", "Issuing is not enabled for this account"),
+                Some(SkipReason::FeatureNotEnabled),
+            ),
+            (
+                api_error("unsupported_api_version", "Not available"),
+                Some(SkipReason::UnsupportedApiVersion),
+            ),
+            (api_error("validation_error", "amount is required"), None),
+            (
+                Error::Authentication("credentials_invalid".to_string()),
+                None,
+            ),
+            (Error::NotFound, None),
+        ];
+        for (error, expected) in cases {
+            assert_eq!(skip_reason(&error), expected, "{:?}", error);
+        }
+    }
+
+    fn skipped(result: crate::Result<()>) -> bool {
+        let mut ran = false;
+        (|| {
+            skip_if_unavailable!(result);
+            ran = true;
+        })();
+        !ran
+    }
+
+    #[test]
+    fn test_skip_if_unavailable() {
+        assert!(!skipped(Ok(())));
+        assert!(skipped(Err(api_error("forbidden", "Forbidden"))));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected error")]
+    fn test_skip_if_unavailable_panics_on_failure() {
+        skipped(Err(Error::NotFound));
+    }
+}
@@ -40,8 +40,13 @@
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use hmac::{Hmac, Mac};
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
 use sha2::Sha256;
 
+use crate::models::{EventName, PaymentDispute, PaymentIntent, Refund, Timestamp, Transfer};
+
 type HmacSha256 = Hmac<Sha256>;
 
 /// Errors that can occur during webhook signature verification.
@@ -64,6 +69,17 @@ pub enum WebhookError {
     InvalidNonce,
     /// HMAC computation failed.
     HmacError,
+    /// A required header was missing or not valid UTF-8.
+    MissingHeader(&'static str),
+    /// The payload exceeds the configured size limit.
+    PayloadTooLarge {
+        /// The payload size in bytes.
+        size: usize,
+        /// The maximum allowed size in bytes.
+        limit: usize,
+    },
+    /// The signature is valid but the payload is not a webhook event.
+    InvalidPayload(String),
 }
 
 impl std::fmt::Display for WebhookError {
@@ -82,6 +98,15 @@ impl std::fmt::Display for WebhookError {
             WebhookError::InvalidTimestamp => write!(f, "Invalid timestamp format"),
             WebhookError::InvalidNonce => write!(f, "Invalid nonce format"),
             WebhookError::HmacError => write!(f, "HMAC computation failed"),
+            WebhookError::MissingHeader(name) => write!(f, "Missing webhook header: {}", name),
+            WebhookError::PayloadTooLarge { size, limit } => write!(
+                f,
+                "Webhook payload too large: {} bytes (limit: {} bytes)",
+                size, limit
+            ),
+            WebhookError::InvalidPayload(message) => {
+                write!(f, "Invalid webhook payload: {}", message)
+            }
         }
     }
 }
@@ -91,6 +116,15 @@ impl std::error::Error for WebhookError {}
 /// Default timestamp tolerance (5 minutes).
 pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
 
+/// Default allowance for timestamps ahead of the local clock (30 seconds).
+pub const DEFAULT_FUTURE_GRACE: Duration = Duration::from_secs(30);
+
+/// Header carrying the webhook timestamp.
+pub const TIMESTAMP_HEADER: &str = "x-timestamp";
+
+/// Header carrying the webhook signature.
+pub const SIGNATURE_HEADER: &str = "x-signature";
+
 /// Verify a standard webhook signature.
 ///
 /// This verifies the signature of a webhook event using the `x-timestamp` and `x-signature` headers.
@@ -149,10 +183,287 @@ pub fn verify_signature_with_tolerance(
     payload: &str,
     signature: &str,
     tolerance: Duration,
+) -> Result<(), WebhookError> {
+    verify_signature_with_grace(
+        secret,
+        timestamp,
+        payload,
+        signature,
+        tolerance,
+        DEFAULT_FUTURE_GRACE,
+    )
+}
+
+/// Verify a standard webhook signature with a custom tolerance and future grace period.
+///
+/// Use this when the sender's clock may run ahead of yours by more than
+/// [`DEFAULT_FUTURE_GRACE`].
+///
+/// # Arguments
+///
+/// * `secret` - The webhook secret key for your notification URL
+/// * `timestamp` - The `x-timestamp` header value (Unix timestamp in milliseconds)
+/// * `payload` - The raw JSON payload body
+/// * `signature` - The `x-signature` header value
+/// * `tolerance` - Maximum age allowed for the webhook timestamp
+/// * `future_grace` - How far ahead of the local clock the timestamp may be
+///
+/// # Returns
+///
+/// `Ok(())` if the signature is valid, `Err(WebhookError)` otherwise.
+pub fn verify_signature_with_grace(
+    secret: &str,
+    timestamp: &str,
+    payload: &str,
+    signature: &str,
+    tolerance: Duration,
+    future_grace: Duration,
+) -> Result<(), WebhookError> {
+    verify_timestamp(timestamp, SystemTime::now(), tolerance, future_grace)?;
+    verify_hex_signature(secret, timestamp, payload, signature)
+}
+
+/// Verify a standard webhook signature against an explicit current time.
+///
+/// [`verify_signature`] and [`verify_signature_with_tolerance`] delegate to
+/// this with [`SystemTime::now`]. Passing `now` explicitly makes verification
+/// deterministic in tests and lets you check events against the time they
+/// were received.
+///
+/// # Arguments
+///
+/// * `secret` - The webhook secret key for your notification URL
+/// * `timestamp` - The `x-timestamp` header value (Unix timestamp in milliseconds)
+/// * `payload` - The raw JSON payload body
+/// * `signature` - The `x-signature` header value
+/// * `now` - The time to check the timestamp against
+/// * `tolerance` - Maximum age allowed for the webhook timestamp
+///
+/// # Returns
+///
+/// `Ok(())` if the signature is valid, `Err(WebhookError)` otherwise.
+pub fn verify_signature_at(
+    secret: &str,
+    timestamp: &str,
+    payload: &str,
+    signature: &str,
+    now: SystemTime,
+    tolerance: Duration,
 ) -> Result<(), WebhookError> {
     // Verify timestamp is within tolerance
-    verify_timestamp(timestamp, tolerance)?;
+    verify_timestamp(timestamp, now, tolerance, DEFAULT_FUTURE_GRACE)?;
+    verify_hex_signature(secret, timestamp, payload, signature)
+}
+
+/// Options for [`verify`].
+///
+/// The defaults match [`verify_signature`]: a 5 minute tolerance, a 30 second
+/// future grace period, no payload size limit, and the system clock.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use airwallex_rs::webhooks::VerifyOptions;
+///
+/// let options = VerifyOptions::new()
+///     .tolerance(Duration::from_secs(600))
+///     .future_grace(Duration::from_secs(120))
+///     .max_payload_bytes(256 * 1024);
+/// ```
+#[derive(Debug, Clone)]
+pub struct VerifyOptions {
+    tolerance: Duration,
+    future_grace: Duration,
+    max_payload_bytes: Option<usize>,
+    now: Option<SystemTime>,
+}
+
+impl Default for VerifyOptions {
+    fn default() -> Self {
+        Self {
+            tolerance: DEFAULT_TOLERANCE,
+            future_grace: DEFAULT_FUTURE_GRACE,
+            max_payload_bytes: None,
+            now: None,
+        }
+    }
+}
+
+impl VerifyOptions {
+    /// Create options with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum age allowed for the webhook timestamp.
+    pub fn tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set how far ahead of the local clock the timestamp may be.
+    pub fn future_grace(mut self, future_grace: Duration) -> Self {
+        self.future_grace = future_grace;
+        self
+    }
+
+    /// Reject payloads larger than `limit` bytes before computing the signature.
+    pub fn max_payload_bytes(mut self, limit: usize) -> Self {
+        self.max_payload_bytes = Some(limit);
+        self
+    }
+
+    /// Check the timestamp against a fixed time instead of the system clock.
+    pub fn now(mut self, now: SystemTime) -> Self {
+        self.now = Some(now);
+        self
+    }
+}
+
+/// One or more webhook secrets accepted by [`verify`].
+///
+/// Passing several secrets lets you rotate a secret without dropping events
+/// signed with the old one. Build it from a `&str`, a `&String`, or a slice,
+/// array or `Vec` of either.
+#[derive(Debug, Clone, Copy)]
+pub struct WebhookSecrets<'a>(SecretsInner<'a>);
+
+#[derive(Debug, Clone, Copy)]
+enum SecretsInner<'a> {
+    Single(&'a str),
+    Strs(&'a [&'a str]),
+    Strings(&'a [String]),
+}
+
+impl<'a> WebhookSecrets<'a> {
+    fn iter(&self) -> impl Iterator<Item = &'a str> {
+        let (single, strs, strings): (Option<&'a str>, &'a [&'a str], &'a [String]) = match self.0 {
+            SecretsInner::Single(secret) => (Some(secret), &[], &[]),
+            SecretsInner::Strs(secrets) => (None, secrets, &[]),
+            SecretsInner::Strings(secrets) => (None, &[], secrets),
+        };
+        single
+            .into_iter()
+            .chain(strs.iter().copied())
+            .chain(strings.iter().map(String::as_str))
+    }
+}
+
+impl<'a> From<&'a str> for WebhookSecrets<'a> {
+    fn from(secret: &'a str) -> Self {
+        Self(SecretsInner::Single(secret))
+    }
+}
+
+impl<'a> From<&'a String> for WebhookSecrets<'a> {
+    fn from(secret: &'a String) -> Self {
+        Self(SecretsInner::Single(secret))
+    }
+}
+
+impl<'a> From<&'a [&'a str]> for WebhookSecrets<'a> {
+    fn from(secrets: &'a [&'a str]) -> Self {
+        Self(SecretsInner::Strs(secrets))
+    }
+}
+
+impl<'a, const N: usize> From<&'a [&'a str; N]> for WebhookSecrets<'a> {
+    fn from(secrets: &'a [&'a str; N]) -> Self {
+        Self(SecretsInner::Strs(secrets))
+    }
+}
+
+impl<'a> From<&'a Vec<&'a str>> for WebhookSecrets<'a> {
+    fn from(secrets: &'a Vec<&'a str>) -> Self {
+        Self(SecretsInner::Strs(secrets))
+    }
+}
 
+impl<'a> From<&'a [String]> for WebhookSecrets<'a> {
+    fn from(secrets: &'a [String]) -> Self {
+        Self(SecretsInner::Strings(secrets))
+    }
+}
+
+impl<'a> From<&'a Vec<String>> for WebhookSecrets<'a> {
+    fn from(secrets: &'a Vec<String>) -> Self {
+        Self(SecretsInner::Strings(secrets))
+    }
+}
+
+/// Verify a standard webhook from its request headers and body.
+///
+/// Reads the `x-timestamp` and `x-signature` headers, applies the checks
+/// configured in `options`, and accepts the event if any of the given secrets
+/// produced the signature. For the common single-secret case without headers
+/// at hand, [`verify_signature`] is simpler.
+///
+/// # Example
+///
+/// ```no_run
+/// use airwallex_rs::webhooks::{self, VerifyOptions};
+/// use reqwest::header::HeaderMap;
+///
+/// # fn handle(headers: &HeaderMap, body: &str) {
+/// let options = VerifyOptions::new().max_payload_bytes(1 << 20);
+/// let secrets = ["whsec_current", "whsec_previous"];
+///
+/// match webhooks::verify(&secrets, headers, body, &options) {
+///     Ok(()) => println!("Webhook verified!"),
+///     Err(e) => println!("Verification failed: {}", e),
+/// }
+/// # }
+/// ```
+pub fn verify<'a>(
+    secrets: impl Into<WebhookSecrets<'a>>,
+    headers: &HeaderMap,
+    body: &str,
+    options: &VerifyOptions,
+) -> Result<(), WebhookError> {
+    if let Some(limit) = options
+        .max_payload_bytes
+        .filter(|&limit| body.len() > limit)
+    {
+        return Err(WebhookError::PayloadTooLarge {
+            size: body.len(),
+            limit,
+        });
+    }
+
+    let header = |name: &'static str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(WebhookError::MissingHeader(name))
+    };
+    let timestamp = header(TIMESTAMP_HEADER)?;
+    let signature = header(SIGNATURE_HEADER)?;
+
+    verify_timestamp(
+        timestamp,
+        options.now.unwrap_or_else(SystemTime::now),
+        options.tolerance,
+        options.future_grace,
+    )?;
+
+    let mut outcome = Err(WebhookError::InvalidSignature);
+    for secret in secrets.into().iter() {
+        outcome = verify_hex_signature(secret, timestamp, body, signature);
+        if outcome.is_ok() {
+            break;
+        }
+    }
+    outcome
+}
+
+/// Check a standard signature without looking at the timestamp's age.
+fn verify_hex_signature(
+    secret: &str,
+    timestamp: &str,
+    payload: &str,
+    signature: &str,
+) -> Result<(), WebhookError> {
     // Compute expected signature
     let expected = compute_signature(secret, timestamp, payload)?;
 
@@ -193,6 +504,68 @@ pub fn compute_signature(
     Ok(hex::encode(result.into_bytes()))
 }
 
+/// Verify the signatures of many stored webhook events.
+///
+/// Intended for auditing a log of previously received webhooks. Each item is a
+/// `(timestamp, payload, signature)` triple as received in the `x-timestamp`
+/// header, the raw body, and the `x-signature` header. Only signatures are
+/// checked: stored events are expected to be old, so the timestamp tolerance
+/// applied by [`verify_signature`] is skipped. The HMAC key is prepared once
+/// and reused for every item.
+///
+/// # Returns
+///
+/// One result per input item, in order.
+///
+/// # Example
+///
+/// ```no_run
+/// use airwallex_rs::webhooks;
+///
+/// let stored = vec![
+///     ("1357872222592", r#"{"name":"payment_intent.succeeded"}"#, "abc123..."),
+/// ];
+///
+/// for (i, outcome) in webhooks::verify_batch("whsec_secret", stored).iter().enumerate() {
+///     if let Err(e) = outcome {
+///         println!("event {} failed verification: {}", i, e);
+///     }
+/// }
+/// ```
+pub fn verify_batch<I, T, P, S>(secret: &str, events: I) -> Vec<Result<(), WebhookError>>
+where
+    I: IntoIterator<Item = (T, P, S)>,
+    T: AsRef<str>,
+    P: AsRef<str>,
+    S: AsRef<str>,
+{
+    let keyed = match HmacSha256::new_from_slice(secret.as_bytes()) {
+        Ok(mac) => mac,
+        Err(_) => {
+            return events
+                .into_iter()
+                .map(|_| Err(WebhookError::HmacError))
+                .collect();
+        }
+    };
+
+    events
+        .into_iter()
+        .map(|(timestamp, payload, signature)| {
+            let mut mac = keyed.clone();
+            mac.update(timestamp.as_ref().as_bytes());
+            mac.update(payload.as_ref().as_bytes());
+            let expected = hex::encode(mac.finalize().into_bytes());
+
+            if constant_time_compare(&expected, signature.as_ref()) {
+                Ok(())
+            } else {
+                Err(WebhookError::InvalidSignature)
+            }
+        })
+        .collect()
+}
+
 /// Verify a remote authorization webhook signature (for Issuing).
 ///
 /// Remote authorization uses a different signature scheme with the `x-nonce` and `x-signature` headers.
@@ -247,12 +620,41 @@ pub fn verify_remote_auth_signature_with_tolerance(
     nonce: &str,
     signature: &str,
     tolerance: Duration,
+) -> Result<(), WebhookError> {
+    verify_remote_auth_signature_with_grace(
+        shared_secret,
+        nonce,
+        signature,
+        tolerance,
+        DEFAULT_FUTURE_GRACE,
+    )
+}
+
+/// Verify a remote authorization webhook signature with a custom tolerance and future grace period.
+///
+/// # Arguments
+///
+/// * `shared_secret` - Your configured shared secret for remote authorization
+/// * `nonce` - The `x-nonce` header value (format: `{timestamp}.{random_string}`)
+/// * `signature` - The `x-signature` header value (base64-encoded)
+/// * `tolerance` - Maximum age allowed for the timestamp in the nonce
+/// * `future_grace` - How far ahead of the local clock the timestamp may be
+///
+/// # Returns
+///
+/// `Ok(())` if the signature is valid, `Err(WebhookError)` otherwise.
+pub fn verify_remote_auth_signature_with_grace(
+    shared_secret: &str,
+    nonce: &str,
+    signature: &str,
+    tolerance: Duration,
+    future_grace: Duration,
 ) -> Result<(), WebhookError> {
     // Extract timestamp from nonce (format: {timestamp}.{random_string})
     let timestamp_str = nonce.split('.').next().ok_or(WebhookError::InvalidNonce)?;
 
     // Verify timestamp is within tolerance
-    verify_timestamp(timestamp_str, tolerance)?;
+    verify_timestamp(timestamp_str, SystemTime::now(), tolerance, future_grace)?;
 
     // Compute expected signature
     let expected = compute_remote_auth_signature(shared_secret, nonce)?;
@@ -290,20 +692,23 @@ pub fn compute_remote_auth_signature(
     Ok(STANDARD.encode(result.into_bytes()))
 }
 
-/// Verify that a timestamp is within the allowed tolerance.
+/// Verify that a timestamp is within the allowed tolerance of `now`.
 #[allow(clippy::collapsible_if)]
-fn verify_timestamp(timestamp_ms_str: &str, tolerance: Duration) -> Result<(), WebhookError> {
+fn verify_timestamp(
+    timestamp_ms_str: &str,
+    now: SystemTime,
+    tolerance: Duration,
+    future_grace: Duration,
+) -> Result<(), WebhookError> {
     let timestamp_ms: u64 = timestamp_ms_str
         .parse()
         .map_err(|_| WebhookError::InvalidTimestamp)?;
 
     let webhook_time = UNIX_EPOCH + Duration::from_millis(timestamp_ms);
-    let now = SystemTime::now();
 
     if webhook_time > now {
-        // Allow a small grace period for clock skew (30 seconds)
-        let grace_period = Duration::from_secs(30);
-        if webhook_time > now + grace_period {
+        // Allow a grace period for clock skew
+        if webhook_time > now + future_grace {
             return Err(WebhookError::TimestampInFuture);
         }
     }
@@ -341,7 +746,7 @@ pub struct WebhookEvent<T> {
     /// The account ID associated with the event.
     pub account_id: Option<String>,
     /// The timestamp when the event occurred.
-    pub created_at: Option<String>,
+    pub created_at: Option<Timestamp>,
     /// The event data payload.
     pub data: T,
 }
@@ -366,7 +771,11 @@ impl RawWebhookEvent {
         struct RawEvent {
             name: String,
             account_id: Option<String>,
-            created_at: Option<String>,
+            #[cfg_attr(
+                feature = "chrono",
+                serde(default, with = "crate::models::common::timestamp")
+            )]
+            created_at: Option<Timestamp>,
             data: serde_json::Value,
         }
 
@@ -378,6 +787,194 @@ impl RawWebhookEvent {
             data: raw.data,
         })
     }
+
+    /// Deserialize the event data into a model.
+    ///
+    /// Airwallex wraps the resource in `data.object`; when that wrapper is
+    /// present the object inside it is used, otherwise `data` itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use airwallex_rs::models::PaymentIntent;
+    /// use airwallex_rs::webhooks::RawWebhookEvent;
+    ///
+    /// let payload = r#"{"name":"payment_intent.succeeded","data":{"object":{"id":"int_1"}}}"#;
+    /// let event = RawWebhookEvent::from_payload(payload).unwrap();
+    /// let intent: PaymentIntent = event.data_as().unwrap();
+    /// assert_eq!(intent.id.as_deref(), Some("int_1"));
+    /// ```
+    pub fn data_as<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        let data = self.data.get("object").unwrap_or(&self.data);
+        T::deserialize(data)
+    }
+}
+
+/// The type of a webhook event; see [`EventName`].
+pub type WebhookEventType = EventName;
+
+/// A webhook event's data, deserialized into the model for its event type.
+///
+/// Events this version of the client doesn't map are kept as
+/// [`ParsedEvent::Unknown`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ParsedEvent {
+    /// `payment_intent.created`
+    PaymentIntentCreated(PaymentIntent),
+    /// `payment_intent.requires_payment_method`
+    PaymentIntentRequiresPaymentMethod(PaymentIntent),
+    /// `payment_intent.requires_customer_action`
+    PaymentIntentRequiresCustomerAction(PaymentIntent),
+    /// `payment_intent.requires_capture`
+    PaymentIntentRequiresCapture(PaymentIntent),
+    /// `payment_intent.succeeded`
+    PaymentIntentSucceeded(PaymentIntent),
+    /// `payment_intent.cancelled`
+    PaymentIntentCancelled(PaymentIntent),
+    /// `refund.received`
+    RefundReceived(Refund),
+    /// `refund.accepted`
+    RefundAccepted(Refund),
+    /// `refund.settled`
+    RefundSettled(Refund),
+    /// `refund.failed`
+    RefundFailed(Refund),
+    /// `payment_dispute.requires_response`
+    DisputeRequiresResponse(PaymentDispute),
+    /// `payment_dispute.accepted`
+    DisputeAccepted(PaymentDispute),
+    /// `payment_dispute.won`
+    DisputeWon(PaymentDispute),
+    /// `payment_dispute.lost`
+    DisputeLost(PaymentDispute),
+    /// `payout.transfer.paid`
+    TransferPaid(Transfer),
+    /// `payout.transfer.failed`
+    TransferFailed(Transfer),
+    /// `payout.transfer.cancelled`
+    TransferCancelled(Transfer),
+    /// Any other event, with its data as sent.
+    Unknown {
+        /// The event name.
+        name: String,
+        /// The event data payload.
+        data: serde_json::Value,
+    },
+}
+
+impl ParsedEvent {
+    /// Deserialize a raw event's data into the model for its event type.
+    ///
+    /// Fails if the data of a mapped event doesn't fit its model.
+    pub fn from_raw(event: &RawWebhookEvent) -> Result<Self, serde_json::Error> {
+        let event_type =
+            WebhookEventType::deserialize(serde_json::Value::from(event.name.as_str()))?;
+        Ok(match event_type {
+            EventName::PaymentIntentCreated => ParsedEvent::PaymentIntentCreated(event.data_as()?),
+            EventName::PaymentIntentRequiresPaymentMethod => {
+                ParsedEvent::PaymentIntentRequiresPaymentMethod(event.data_as()?)
+            }
+            EventName::PaymentIntentRequiresCustomerAction => {
+                ParsedEvent::PaymentIntentRequiresCustomerAction(event.data_as()?)
+            }
+            EventName::PaymentIntentRequiresCapture => {
+                ParsedEvent::PaymentIntentRequiresCapture(event.data_as()?)
+            }
+            EventName::PaymentIntentSucceeded => {
+                ParsedEvent::PaymentIntentSucceeded(event.data_as()?)
+            }
+            EventName::PaymentIntentCancelled => {
+                ParsedEvent::PaymentIntentCancelled(event.data_as()?)
+            }
+            EventName::RefundReceived => ParsedEvent::RefundReceived(event.data_as()?),
+            EventName::RefundAccepted => ParsedEvent::RefundAccepted(event.data_as()?),
+            EventName::RefundSettled => ParsedEvent::RefundSettled(event.data_as()?),
+            EventName::RefundFailed => ParsedEvent::RefundFailed(event.data_as()?),
+            EventName::PaymentDisputeRequiresResponse => {
+                ParsedEvent::DisputeRequiresResponse(event.data_as()?)
+            }
+            EventName::PaymentDisputeAccepted => ParsedEvent::DisputeAccepted(event.data_as()?),
+            EventName::PaymentDisputeWon => ParsedEvent::DisputeWon(event.data_as()?),
+            EventName::PaymentDisputeLost => ParsedEvent::DisputeLost(event.data_as()?),
+            EventName::PayoutTransferPaid => ParsedEvent::TransferPaid(event.data_as()?),
+            EventName::PayoutTransferFailed => ParsedEvent::TransferFailed(event.data_as()?),
+            EventName::PayoutTransferCancelled => ParsedEvent::TransferCancelled(event.data_as()?),
+            _ => ParsedEvent::Unknown {
+                name: event.name.clone(),
+                data: event.data.clone(),
+            },
+        })
+    }
+}
+
+impl WebhookEvent<ParsedEvent> {
+    /// Parse a webhook payload, deserializing its data by event type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use airwallex_rs::webhooks::{ParsedEvent, WebhookEvent};
+    ///
+    /// let payload = r#"{"name":"payout.transfer.paid","data":{"object":{"id":"tfr_1"}}}"#;
+    /// match WebhookEvent::parse_typed(payload).unwrap().data {
+    ///     ParsedEvent::TransferPaid(transfer) => assert_eq!(transfer.id.as_deref(), Some("tfr_1")),
+    ///     other => panic!("unexpected event: {:?}", other),
+    /// }
+    /// ```
+    pub fn parse_typed(payload: &str) -> Result<Self, serde_json::Error> {
+        let raw = RawWebhookEvent::from_payload(payload)?;
+        let data = ParsedEvent::from_raw(&raw)?;
+        Ok(Self {
+            name: raw.name,
+            account_id: raw.account_id,
+            created_at: raw.created_at,
+            data,
+        })
+    }
+}
+
+/// Verify a standard webhook signature, then parse the payload.
+///
+/// The payload is only deserialized once the signature and timestamp check
+/// out, as with [`verify_signature`]. A verified payload that isn't a webhook
+/// event is reported as [`WebhookError::InvalidPayload`].
+///
+/// # Example
+///
+/// ```no_run
+/// use airwallex_rs::webhooks;
+///
+/// # fn handle(secret: &str, timestamp: &str, body: &str, signature: &str) {
+/// match webhooks::verify_and_parse(secret, timestamp, body, signature) {
+///     Ok(event) => println!("Verified {}", event.name),
+///     Err(e) => println!("Rejected webhook: {}", e),
+/// }
+/// # }
+/// ```
+pub fn verify_and_parse(
+    secret: &str,
+    timestamp: &str,
+    payload: &str,
+    signature: &str,
+) -> Result<RawWebhookEvent, WebhookError> {
+    verify_signature(secret, timestamp, payload, signature)?;
+    RawWebhookEvent::from_payload(payload).map_err(|e| WebhookError::InvalidPayload(e.to_string()))
+}
+
+/// Verify a standard webhook signature, then parse the payload into a
+/// [`ParsedEvent`].
+///
+/// Like [`verify_and_parse`], with the data deserialized by event type as in
+/// [`WebhookEvent::parse_typed`].
+pub fn verify_and_parse_typed(
+    secret: &str,
+    timestamp: &str,
+    payload: &str,
+    signature: &str,
+) -> Result<WebhookEvent<ParsedEvent>, WebhookError> {
+    verify_signature(secret, timestamp, payload, signature)?;
+    WebhookEvent::parse_typed(payload).map_err(|e| WebhookError::InvalidPayload(e.to_string()))
 }
 
 #[cfg(test)]
@@ -499,6 +1096,211 @@ mod tests {
         assert!(matches!(result, Err(WebhookError::TimestampTooOld { .. })));
     }
 
+    #[test]
+    fn test_verify_signature_at_fixed_now() {
+        let secret = "whsec_test_secret";
+        let payload = r#"{"name":"test.event","data":{}}"#;
+        let now = UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+        let at = |offset_ms: i64| (1_700_000_000_000i64 + offset_ms).to_string();
+
+        // Valid: one minute old
+        let timestamp = at(-60_000);
+        let signature = compute_signature(secret, &timestamp, payload).unwrap();
+        assert_eq!(
+            verify_signature_at(
+                secret,
+                &timestamp,
+                payload,
+                &signature,
+                now,
+                DEFAULT_TOLERANCE
+            ),
+            Ok(())
+        );
+
+        // Too old: ten minutes old
+        let timestamp = at(-600_000);
+        let signature = compute_signature(secret, &timestamp, payload).unwrap();
+        assert_eq!(
+            verify_signature_at(
+                secret,
+                &timestamp,
+                payload,
+                &signature,
+                now,
+                DEFAULT_TOLERANCE
+            ),
+            Err(WebhookError::TimestampTooOld {
+                age_seconds: 600,
+                tolerance_seconds: 300,
+            })
+        );
+
+        // Future: two minutes ahead, beyond the grace period
+        let timestamp = at(120_000);
+        let signature = compute_signature(secret, &timestamp, payload).unwrap();
+        assert_eq!(
+            verify_signature_at(
+                secret,
+                &timestamp,
+                payload,
+                &signature,
+                now,
+                DEFAULT_TOLERANCE
+            ),
+            Err(WebhookError::TimestampInFuture)
+        );
+
+        // Slightly ahead, within the grace period
+        let timestamp = at(10_000);
+        let signature = compute_signature(secret, &timestamp, payload).unwrap();
+        assert_eq!(
+            verify_signature_at(
+                secret,
+                &timestamp,
+                payload,
+                &signature,
+                now,
+                DEFAULT_TOLERANCE
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_verify_signature_future_grace() {
+        let secret = "whsec_test_secret";
+        let payload = r#"{"name":"test.event","data":{}}"#;
+
+        // Timestamp 60 seconds in the future
+        let future_timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+            .saturating_add(60_000)
+            .to_string();
+        let signature = compute_signature(secret, &future_timestamp, payload).unwrap();
+
+        assert_eq!(
+            verify_signature(secret, &future_timestamp, payload, &signature),
+            Err(WebhookError::TimestampInFuture)
+        );
+        assert_eq!(
+            verify_signature_with_grace(
+                secret,
+                &future_timestamp,
+                payload,
+                &signature,
+                DEFAULT_TOLERANCE,
+                Duration::from_secs(120),
+            ),
+            Ok(())
+        );
+    }
+
+    fn webhook_headers(timestamp: &str, signature: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(TIMESTAMP_HEADER, timestamp.parse().unwrap());
+        headers.insert(SIGNATURE_HEADER, signature.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_verify_options_builder() {
+        let defaults = VerifyOptions::new();
+        assert_eq!(defaults.tolerance, DEFAULT_TOLERANCE);
+        assert_eq!(defaults.future_grace, DEFAULT_FUTURE_GRACE);
+        assert_eq!(defaults.max_payload_bytes, None);
+        assert_eq!(defaults.now, None);
+
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let options = VerifyOptions::new()
+            .tolerance(Duration::from_secs(60))
+            .future_grace(Duration::from_secs(5))
+            .max_payload_bytes(1024)
+            .now(now);
+        assert_eq!(options.tolerance, Duration::from_secs(60));
+        assert_eq!(options.future_grace, Duration::from_secs(5));
+        assert_eq!(options.max_payload_bytes, Some(1024));
+        assert_eq!(options.now, Some(now));
+    }
+
+    #[test]
+    fn test_verify_with_headers_and_options() {
+        let payload = r#"{"name":"test.event","data":{}}"#;
+        let now = UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+        let timestamp = "1699999990000";
+        let signature = compute_signature("whsec_old", timestamp, payload).unwrap();
+        let headers = webhook_headers(timestamp, &signature);
+        let options = VerifyOptions::new().now(now);
+
+        // Signed with the previous secret during rotation
+        assert_eq!(
+            verify(&["whsec_new", "whsec_old"], &headers, payload, &options),
+            Ok(())
+        );
+        assert_eq!(
+            verify("whsec_new", &headers, payload, &options),
+            Err(WebhookError::InvalidSignature)
+        );
+
+        // Tighter tolerance rejects the 10 second old event
+        let strict = options.clone().tolerance(Duration::from_secs(5));
+        assert!(matches!(
+            verify("whsec_old", &headers, payload, &strict),
+            Err(WebhookError::TimestampTooOld { .. })
+        ));
+
+        let small = options.clone().max_payload_bytes(8);
+        assert_eq!(
+            verify("whsec_old", &headers, payload, &small),
+            Err(WebhookError::PayloadTooLarge {
+                size: payload.len(),
+                limit: 8,
+            })
+        );
+
+        let mut missing = headers.clone();
+        missing.remove(SIGNATURE_HEADER);
+        assert_eq!(
+            verify("whsec_old", &missing, payload, &options),
+            Err(WebhookError::MissingHeader(SIGNATURE_HEADER))
+        );
+    }
+
+    #[test]
+    fn test_verify_batch() {
+        let secret = "whsec_test_secret";
+        let payload = r#"{"name":"test.event","data":{}}"#;
+
+        // Stored events are old; only the signature matters.
+        let old_timestamp = "1357872222592";
+        let valid = compute_signature(secret, old_timestamp, payload).unwrap();
+        let other_payload = r#"{"name":"other.event","data":{}}"#;
+        let valid_other = compute_signature(secret, "1357872222999", other_payload).unwrap();
+        let tampered_payload = r#"{"name":"test.event","data":{"amount":1000}}"#;
+
+        let events = vec![
+            (old_timestamp, payload, valid.as_str()),
+            (old_timestamp, tampered_payload, valid.as_str()),
+            ("1357872222999", other_payload, valid_other.as_str()),
+            ("1357872222000", payload, valid.as_str()),
+            (old_timestamp, payload, "not_a_signature"),
+        ];
+
+        let results = verify_batch(secret, events);
+        assert_eq!(
+            results,
+            vec![
+                Ok(()),
+                Err(WebhookError::InvalidSignature),
+                Ok(()),
+                Err(WebhookError::InvalidSignature),
+                Err(WebhookError::InvalidSignature),
+            ]
+        );
+    }
+
     #[test]
     fn test_verify_remote_auth_signature_fresh() {
         let shared_secret = "test_shared_secret";
@@ -549,4 +1351,115 @@ mod tests {
         assert_eq!(event.account_id, Some("acct_123".to_string()));
         assert_eq!(event.data["id"], "pi_456");
     }
+
+    #[test]
+    fn test_data_as_payment_intent() {
+        use crate::models::Amount;
+
+        let payload = r#"{
+            "id": "evt_1",
+            "name": "payment_intent.succeeded",
+            "account_id": "acct_123",
+            "data": {"object": {"id": "int_1", "amount": 25.5, "currency": "EUR", "status": "SUCCEEDED"}}
+        }"#;
+        let event = RawWebhookEvent::from_payload(payload).unwrap();
+        let intent: PaymentIntent = event.data_as().unwrap();
+        assert_eq!(intent.id.as_deref(), Some("int_1"));
+        assert_eq!(intent.amount, Some(Amount::from(25.5)));
+
+        // Payloads without the `object` wrapper are read directly.
+        let unwrapped = RawWebhookEvent::from_payload(
+            r#"{"name":"payment_intent.succeeded","data":{"id":"int_2"}}"#,
+        )
+        .unwrap();
+        let intent: PaymentIntent = unwrapped.data_as().unwrap();
+        assert_eq!(intent.id.as_deref(), Some("int_2"));
+
+        assert!(event.data_as::<Vec<String>>().is_err());
+    }
+
+    #[test]
+    fn test_parse_typed_dispatches_by_event_name() {
+        use crate::models::PaymentIntentStatus;
+
+        let event = WebhookEvent::parse_typed(
+            r#"{
+                "name": "payment_intent.succeeded",
+                "account_id": "acct_123",
+                "data": {"object": {"id": "int_1", "status": "SUCCEEDED"}}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(event.account_id.as_deref(), Some("acct_123"));
+        match event.data {
+            ParsedEvent::PaymentIntentSucceeded(intent) => {
+                assert_eq!(intent.status, Some(PaymentIntentStatus::Succeeded))
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let event = WebhookEvent::parse_typed(
+            r#"{"name": "refund.settled", "data": {"object": {"id": "rfd_1"}}}"#,
+        )
+        .unwrap();
+        assert!(
+            matches!(event.data, ParsedEvent::RefundSettled(ref refund) if refund.id.as_deref() == Some("rfd_1"))
+        );
+
+        let event = WebhookEvent::parse_typed(
+            r#"{"name": "card.created", "data": {"object": {"card_id": "c_1"}}}"#,
+        )
+        .unwrap();
+        match event.data {
+            ParsedEvent::Unknown { name, data } => {
+                assert_eq!(name, "card.created");
+                assert_eq!(data["object"]["card_id"], "c_1");
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        assert!(
+            WebhookEvent::parse_typed(r#"{"name": "payment_intent.created", "data": []}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_verify_and_parse_checks_signature_before_parsing() {
+        let secret = "whsec_test_secret";
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+            .to_string();
+        let payload = r#"{"name":"payout.transfer.paid","data":{"object":{"id":"tfr_1"}}}"#;
+        let signature = compute_signature(secret, &timestamp, payload).unwrap();
+
+        let event = verify_and_parse(secret, &timestamp, payload, &signature).unwrap();
+        assert_eq!(event.name, "payout.transfer.paid");
+        let event = verify_and_parse_typed(secret, &timestamp, payload, &signature).unwrap();
+        assert!(matches!(event.data, ParsedEvent::TransferPaid(_)));
+
+        // A tampered body is rejected on its signature, even when it would
+        // also fail to parse.
+        for tampered in [
+            r#"{"name":"payout.transfer.paid","data":{"object":{"id":"tfr_2"}}}"#,
+            "not json",
+        ] {
+            assert_eq!(
+                verify_and_parse(secret, &timestamp, tampered, &signature).unwrap_err(),
+                WebhookError::InvalidSignature
+            );
+            assert_eq!(
+                verify_and_parse_typed(secret, &timestamp, tampered, &signature).unwrap_err(),
+                WebhookError::InvalidSignature
+            );
+        }
+
+        let garbage = "not json";
+        let signature = compute_signature(secret, &timestamp, garbage).unwrap();
+        assert!(matches!(
+            verify_and_parse(secret, &timestamp, garbage, &signature),
+            Err(WebhookError::InvalidPayload(_))
+        ));
+    }
 }